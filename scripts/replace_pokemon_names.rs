@@ -45,6 +45,7 @@ fn main() {
         Source::new("moves.csv", "move_names"),
         Source::new("types.csv", "type_names"),
         Source::new("move_damage_classes.csv", "move_damage_class_names"),
+        Source::new("move_learn_methods.csv", "move_learn_method_names"),
         Source::new("abilities.csv", "ability_names"),
         Source::new("items.csv", "item_names"),
         Source::new("generations.csv", "generation_names").index(2),
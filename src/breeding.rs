@@ -0,0 +1,183 @@
+use rustemon::model::pokemon::PokemonSpecies;
+
+pub const DITTO_EGG_GROUP: &str = "ditto";
+pub const UNDISCOVERED_EGG_GROUP: &str = "no-eggs";
+
+// `gender_rate` is the chance of being female in eighths, or -1 for genderless species
+const GENDERLESS: i64 = -1;
+const ALWAYS_MALE: i64 = 0;
+const ALWAYS_FEMALE: i64 = 8;
+
+/// The parts of a species that decide who it can breed with
+pub struct Parent<'a> {
+    pub name: &'a str,
+    pub egg_groups: Vec<&'a str>,
+    pub gender_rate: i64,
+}
+
+impl<'a> Parent<'a> {
+    pub fn new(name: &'a str, species: &'a PokemonSpecies) -> Self {
+        Parent {
+            name,
+            egg_groups: species
+                .egg_groups
+                .iter()
+                .map(|egg_group| egg_group.name.as_str())
+                .collect(),
+            gender_rate: species.gender_rate,
+        }
+    }
+
+    fn in_egg_group(&self, egg_group: &str) -> bool {
+        self.egg_groups.contains(&egg_group)
+    }
+
+    fn is_genderless(&self) -> bool {
+        self.gender_rate == GENDERLESS
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Compatibility<'a> {
+    SharedEggGroups(Vec<&'a str>),
+    WithDitto,
+    Undiscovered(&'a str),
+    BothDitto,
+    Genderless(&'a str),
+    SameGender(&'static str),
+    NoSharedEggGroup,
+}
+
+impl Compatibility<'_> {
+    pub fn can_breed(&self) -> bool {
+        matches!(
+            self,
+            Compatibility::SharedEggGroups(_) | Compatibility::WithDitto
+        )
+    }
+
+    /// A short identifier for the outcome, used in JSON output
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Compatibility::SharedEggGroups(_) => "shared-egg-group",
+            Compatibility::WithDitto => "ditto",
+            Compatibility::Undiscovered(_) => "undiscovered",
+            Compatibility::BothDitto => "both-ditto",
+            Compatibility::Genderless(_) => "genderless",
+            Compatibility::SameGender(_) => "same-gender",
+            Compatibility::NoSharedEggGroup => "no-shared-egg-group",
+        }
+    }
+}
+
+/// Whether two species can produce an egg together. Ditto breeds with anything outside of the
+/// Undiscovered group, genderless species only breed with Ditto, and everything else needs a
+/// possible male and female pair which share an egg group
+pub fn compatibility<'a>(first: &Parent<'a>, second: &Parent<'a>) -> Compatibility<'a> {
+    if let Some(parent) = [first, second]
+        .into_iter()
+        .find(|parent| parent.in_egg_group(UNDISCOVERED_EGG_GROUP))
+    {
+        return Compatibility::Undiscovered(parent.name);
+    }
+
+    match (
+        first.in_egg_group(DITTO_EGG_GROUP),
+        second.in_egg_group(DITTO_EGG_GROUP),
+    ) {
+        (true, true) => return Compatibility::BothDitto,
+        (true, false) | (false, true) => return Compatibility::WithDitto,
+        (false, false) => {}
+    }
+
+    if let Some(parent) = [first, second]
+        .into_iter()
+        .find(|parent| parent.is_genderless())
+    {
+        return Compatibility::Genderless(parent.name);
+    }
+
+    match (first.gender_rate, second.gender_rate) {
+        (ALWAYS_MALE, ALWAYS_MALE) => return Compatibility::SameGender("male"),
+        (ALWAYS_FEMALE, ALWAYS_FEMALE) => return Compatibility::SameGender("female"),
+        _ => {}
+    }
+
+    let shared_egg_groups = first
+        .egg_groups
+        .iter()
+        .copied()
+        .filter(|egg_group| second.in_egg_group(egg_group))
+        .collect::<Vec<_>>();
+
+    if shared_egg_groups.is_empty() {
+        Compatibility::NoSharedEggGroup
+    } else {
+        Compatibility::SharedEggGroups(shared_egg_groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent<'a>(name: &'a str, egg_groups: &[&'a str], gender_rate: i64) -> Parent<'a> {
+        Parent {
+            name,
+            egg_groups: egg_groups.to_vec(),
+            gender_rate,
+        }
+    }
+
+    #[test]
+    fn test_shared_egg_groups() {
+        let charizard = parent("charizard", &["monster", "dragon"], 1);
+        let blastoise = parent("blastoise", &["monster", "water1"], 1);
+        let pikachu = parent("pikachu", &["ground", "fairy"], 4);
+
+        assert_eq!(
+            compatibility(&charizard, &blastoise),
+            Compatibility::SharedEggGroups(vec!["monster"])
+        );
+        assert_eq!(
+            compatibility(&charizard, &pikachu),
+            Compatibility::NoSharedEggGroup
+        );
+    }
+
+    #[test]
+    fn test_ditto() {
+        let ditto = parent("ditto", &["ditto"], -1);
+        let magnemite = parent("magnemite", &["mineral"], -1);
+        let pichu = parent("pichu", &["no-eggs"], 4);
+
+        assert_eq!(compatibility(&magnemite, &ditto), Compatibility::WithDitto);
+        assert_eq!(compatibility(&ditto, &ditto), Compatibility::BothDitto);
+        assert_eq!(
+            compatibility(&ditto, &pichu),
+            Compatibility::Undiscovered("pichu")
+        );
+    }
+
+    #[test]
+    fn test_genders() {
+        let magnemite = parent("magnemite", &["mineral"], -1);
+        let bronzor = parent("bronzor", &["mineral"], -1);
+        let tauros = parent("tauros", &["ground"], 0);
+        let hitmonlee = parent("hitmonlee", &["humanshape"], 0);
+        let miltank = parent("miltank", &["ground"], 8);
+
+        assert_eq!(
+            compatibility(&magnemite, &bronzor),
+            Compatibility::Genderless("magnemite")
+        );
+        assert_eq!(
+            compatibility(&tauros, &hitmonlee),
+            Compatibility::SameGender("male")
+        );
+        assert_eq!(
+            compatibility(&tauros, &miltank),
+            Compatibility::SharedEggGroups(vec!["ground"])
+        );
+    }
+}
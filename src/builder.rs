@@ -1,5 +1,9 @@
+use crate::output_format::OutputFormat;
+
 use std::fmt;
 
+use serde_json::json;
+
 const DEFAULT_CAPACITY: usize = 1500;
 
 pub struct Builder(String);
@@ -22,6 +26,13 @@ impl Builder {
         self.append('\n');
     }
 
+    pub fn append_error(&mut self, error_message: String, output_format: OutputFormat) {
+        match output_format {
+            OutputFormat::Text => self.append(error_message),
+            OutputFormat::Json => self.append(json!({ "error": error_message })),
+        }
+    }
+
     pub fn print(&self) {
         println!("{self}");
     }
@@ -80,3 +91,10 @@ impl Appendable for &str {
         string.push_str(self)
     }
 }
+
+impl Appendable for serde_json::Value {
+    fn append_self_to(&self, string: &mut String) {
+        // Serialising a `Value` can't fail as its keys are always strings
+        string.push_str(&serde_json::to_string_pretty(self).unwrap_or_default());
+    }
+}
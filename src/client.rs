@@ -3,13 +3,16 @@ use std::path::PathBuf;
 use async_trait::async_trait;
 use mockall::automock;
 use rustemon::{
-    client::{CACacheManager, RustemonClient, RustemonClientBuilder},
+    client::{CACacheManager, CacheMode, Environment, RustemonClient, RustemonClientBuilder},
     model::{
+        berries::Berry,
         evolution::EvolutionChain,
         games::Generation,
         items::Item,
         moves::{Move, MoveLearnMethod},
-        pokemon::{Ability, Pokemon, PokemonSpecies, Type},
+        pokemon::{
+            Ability, EggGroup, LocationAreaEncounter, Nature, Pokemon, PokemonSpecies, Type,
+        },
     },
 };
 use thiserror::Error;
@@ -22,54 +25,122 @@ pub enum ClientError {
     CacheSetup(#[from] std::io::Error),
     #[error("Missing environment variable: {0}")]
     MissingEnv(#[from] std::env::VarError),
+    #[error("{0} is not cached. Run again without --offline to fetch it")]
+    NotCached(String),
+    #[error("{0} doesn't exist in the local data")]
+    NotFound(String),
+    #[error("Failed to read local data: {0}")]
+    LocalData(String),
+    #[error("Invalid config: {0}")]
+    Config(String),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ClientOptions {
+    pub offline: bool,
+    pub api_url: Option<String>,
 }
 
 #[automock]
 #[async_trait]
-pub trait ClientImplementation {
-    async fn fetch_ability(&self, ability_name: &str) -> Result<Ability, rustemon::error::Error>;
-    async fn fetch_generation(
-        &self,
-        generation_name: &str,
-    ) -> Result<Generation, rustemon::error::Error>;
-    async fn fetch_item(&self, item_name: &str) -> Result<Item, rustemon::error::Error>;
-    async fn fetch_move(&self, move_name: &str) -> Result<Move, rustemon::error::Error>;
+pub trait ClientImplementation: Send + Sync {
+    async fn fetch_ability(&self, ability_name: &str) -> Result<Ability, ClientError>;
+    async fn fetch_berry(&self, berry_name: &str) -> Result<Berry, ClientError>;
+    async fn fetch_egg_group(&self, egg_group_name: &str) -> Result<EggGroup, ClientError>;
+    async fn fetch_generation(&self, generation_name: &str) -> Result<Generation, ClientError>;
+    async fn fetch_item(&self, item_name: &str) -> Result<Item, ClientError>;
+    async fn fetch_move(&self, move_name: &str) -> Result<Move, ClientError>;
     async fn fetch_move_learn_method(
         &self,
         move_learn_method_name: &str,
-    ) -> Result<MoveLearnMethod, rustemon::error::Error>;
-    async fn fetch_pokemon(&self, pokemon_name: &str) -> Result<Pokemon, rustemon::error::Error>;
+    ) -> Result<MoveLearnMethod, ClientError>;
+    async fn fetch_nature(&self, nature_name: &str) -> Result<Nature, ClientError>;
+    async fn fetch_pokemon(&self, pokemon_name: &str) -> Result<Pokemon, ClientError>;
+    async fn fetch_pokemon_encounters(
+        &self,
+        pokemon_name: &str,
+    ) -> Result<Vec<LocationAreaEncounter>, ClientError>;
     async fn fetch_pokemon_species(
         &self,
         species_name: &str,
-    ) -> Result<PokemonSpecies, rustemon::error::Error>;
-    async fn fetch_type(&self, type_name: &str) -> Result<Type, rustemon::error::Error>;
+    ) -> Result<PokemonSpecies, ClientError>;
+    async fn fetch_type(&self, type_name: &str) -> Result<Type, ClientError>;
 
     async fn fetch_evolution_chain_from_url(
         &self,
         evolution_chain: &str,
-    ) -> Result<EvolutionChain, rustemon::error::Error>;
+    ) -> Result<EvolutionChain, ClientError>;
 }
 
 #[derive(Default)]
-pub struct Client(RustemonClient);
+pub struct Client {
+    client: RustemonClient,
+    offline: bool,
+}
 
 impl Client {
-    pub fn try_build() -> Result<Self, ClientError> {
+    pub fn try_build(options: ClientOptions) -> Result<Self, ClientError> {
         let cache_dir = Self::get_cache_dir()?;
         let cache_manager = CACacheManager::new(cache_dir, false);
+
+        // Serve stale entries without revalidating and never hit the network when offline
+        let cache_mode = if options.offline {
+            CacheMode::OnlyIfCached
+        } else {
+            CacheMode::Default
+        };
+
+        let environment = match options.api_url {
+            Some(api_url) => Environment::Custom(api_url),
+            None => Environment::Production,
+        };
+
         let client = RustemonClientBuilder::default()
             .with_manager(cache_manager)
+            .with_mode(cache_mode)
+            .with_environment(environment)
             .try_build()?;
 
-        Ok(Client(client))
+        Ok(Client {
+            client,
+            offline: options.offline,
+        })
     }
 
-    fn get_cache_dir() -> Result<PathBuf, std::env::VarError> {
+    pub fn get_cache_dir() -> Result<PathBuf, std::env::VarError> {
         let home_dir = std::env::var("HOME")?;
         Ok(PathBuf::from(home_dir).join(".cache").join("poke_search"))
     }
 
+    fn handle_result<T>(
+        &self,
+        result: Result<T, rustemon::error::Error>,
+        resource: &str,
+        name: &str,
+    ) -> Result<T, ClientError> {
+        result.map_err(|error| {
+            if self.offline && Self::is_cache_miss(&error) {
+                ClientError::NotCached(format!("{resource} \"{name}\""))
+            } else {
+                ClientError::from(error)
+            }
+        })
+    }
+
+    // A cache miss in `CacheMode::OnlyIfCached` is returned as a 504 response with a plain text
+    // body, which rustemon fails to parse as JSON at all. A cached response which doesn't fit
+    // the model fails as a data error instead
+    fn is_cache_miss(error: &rustemon::error::Error) -> bool {
+        let rustemon::error::Error::Reqwest(error) = error else {
+            return false;
+        };
+
+        error.is_decode()
+            && std::error::Error::source(error)
+                .and_then(|source| source.downcast_ref::<serde_json::Error>())
+                .is_some_and(serde_json::Error::is_syntax)
+    }
+
     fn extract_id_from_url(&self, url: &str) -> Result<i64, rustemon::error::Error> {
         let id_str = url
             .trim_end_matches('/')
@@ -86,52 +157,96 @@ impl Client {
 
 #[async_trait]
 impl ClientImplementation for Client {
-    async fn fetch_ability(&self, ability_name: &str) -> Result<Ability, rustemon::error::Error> {
-        rustemon::pokemon::ability::get_by_name(ability_name, &self.0).await
+    async fn fetch_ability(&self, ability_name: &str) -> Result<Ability, ClientError> {
+        let result = rustemon::pokemon::ability::get_by_name(ability_name, &self.client).await;
+        self.handle_result(result, "Ability", ability_name)
     }
 
-    async fn fetch_generation(
-        &self,
-        generation_name: &str,
-    ) -> Result<Generation, rustemon::error::Error> {
-        rustemon::games::generation::get_by_name(generation_name, &self.0).await
+    async fn fetch_berry(&self, berry_name: &str) -> Result<Berry, ClientError> {
+        let result = rustemon::berries::berry::get_by_name(berry_name, &self.client).await;
+        self.handle_result(result, "Berry", berry_name)
+    }
+
+    async fn fetch_egg_group(&self, egg_group_name: &str) -> Result<EggGroup, ClientError> {
+        let result = rustemon::pokemon::egg_group::get_by_name(egg_group_name, &self.client).await;
+        self.handle_result(result, "Egg group", egg_group_name)
     }
 
-    async fn fetch_item(&self, item_name: &str) -> Result<Item, rustemon::error::Error> {
-        rustemon::items::item::get_by_name(item_name, &self.0).await
+    async fn fetch_generation(&self, generation_name: &str) -> Result<Generation, ClientError> {
+        let result = rustemon::games::generation::get_by_name(generation_name, &self.client).await;
+        self.handle_result(result, "Generation", generation_name)
     }
 
-    async fn fetch_move(&self, move_name: &str) -> Result<Move, rustemon::error::Error> {
-        rustemon::moves::move_::get_by_name(move_name, &self.0).await
+    async fn fetch_item(&self, item_name: &str) -> Result<Item, ClientError> {
+        let result = rustemon::items::item::get_by_name(item_name, &self.client).await;
+        self.handle_result(result, "Item", item_name)
+    }
+
+    async fn fetch_move(&self, move_name: &str) -> Result<Move, ClientError> {
+        let result = rustemon::moves::move_::get_by_name(move_name, &self.client).await;
+        self.handle_result(result, "Move", move_name)
     }
 
     async fn fetch_move_learn_method(
         &self,
         move_learn_method_name: &str,
-    ) -> Result<MoveLearnMethod, rustemon::error::Error> {
-        rustemon::moves::move_learn_method::get_by_name(move_learn_method_name, &self.0).await
+    ) -> Result<MoveLearnMethod, ClientError> {
+        let result =
+            rustemon::moves::move_learn_method::get_by_name(move_learn_method_name, &self.client)
+                .await;
+        self.handle_result(result, "Move learn method", move_learn_method_name)
     }
 
-    async fn fetch_pokemon(&self, pokemon_name: &str) -> Result<Pokemon, rustemon::error::Error> {
-        rustemon::pokemon::pokemon::get_by_name(pokemon_name, &self.0).await
+    async fn fetch_nature(&self, nature_name: &str) -> Result<Nature, ClientError> {
+        let result = rustemon::pokemon::nature::get_by_name(nature_name, &self.client).await;
+        self.handle_result(result, "Nature", nature_name)
+    }
+
+    async fn fetch_pokemon(&self, pokemon_name: &str) -> Result<Pokemon, ClientError> {
+        let result = rustemon::pokemon::pokemon::get_by_name(pokemon_name, &self.client).await;
+        self.handle_result(result, "Pokemon", pokemon_name)
+    }
+
+    // rustemon only takes `'static` names for the encounters endpoint so it's looked up by id
+    async fn fetch_pokemon_encounters(
+        &self,
+        pokemon_name: &str,
+    ) -> Result<Vec<LocationAreaEncounter>, ClientError> {
+        let pokemon = self.fetch_pokemon(pokemon_name).await?;
+        let result =
+            rustemon::pokemon::pokemon::encounters::get_by_id(pokemon.id, &self.client).await;
+        self.handle_result(result, "Pokemon encounters", pokemon_name)
     }
 
     async fn fetch_pokemon_species(
         &self,
         species_name: &str,
-    ) -> Result<PokemonSpecies, rustemon::error::Error> {
-        rustemon::pokemon::pokemon_species::get_by_name(species_name, &self.0).await
+    ) -> Result<PokemonSpecies, ClientError> {
+        let result =
+            rustemon::pokemon::pokemon_species::get_by_name(species_name, &self.client).await;
+        self.handle_result(result, "Pokemon species", species_name)
     }
 
-    async fn fetch_type(&self, type_name: &str) -> Result<Type, rustemon::error::Error> {
-        rustemon::pokemon::type_::get_by_name(type_name, &self.0).await
+    async fn fetch_type(&self, type_name: &str) -> Result<Type, ClientError> {
+        let result = rustemon::pokemon::type_::get_by_name(type_name, &self.client).await;
+        self.handle_result(result, "Type", type_name)
     }
 
     async fn fetch_evolution_chain_from_url(
         &self,
         evolution_chain_url: &str,
-    ) -> Result<EvolutionChain, rustemon::error::Error> {
+    ) -> Result<EvolutionChain, ClientError> {
         let id = self.extract_id_from_url(evolution_chain_url)?;
-        rustemon::evolution::evolution_chain::get_by_id(id, &self.0).await
+        let result = rustemon::evolution::evolution_chain::get_by_id(id, &self.client).await;
+        self.handle_result(result, "Evolution chain", evolution_chain_url)
     }
 }
+
+/// The id at the end of a resource url, e.g. 6 for "https://pokeapi.co/api/v2/pokemon/6/"
+pub fn resource_id(url: &str) -> i64 {
+    url.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .and_then(|id| id.parse().ok())
+        .unwrap_or_default()
+}
@@ -1,12 +1,14 @@
 use crate::{
     builder::Builder,
-    client::ClientImplementation,
+    client::{ClientError, ClientImplementation},
     formatter::{self, FormatAbility, FormatModel},
     name_matcher::matcher,
+    output_format::OutputFormat,
 };
 
 use itertools::Itertools;
 use rustemon::model::pokemon::Ability;
+use serde_json::json;
 
 pub struct AbilityCommand<'a> {
     builder: &'a mut Builder,
@@ -14,6 +16,7 @@ pub struct AbilityCommand<'a> {
     ability_name: String,
     show_pokemon: bool,
     verbose: bool,
+    output_format: OutputFormat,
 }
 
 impl AbilityCommand<'_> {
@@ -22,6 +25,7 @@ impl AbilityCommand<'_> {
         ability_name: String,
         show_pokemon: bool,
         verbose: bool,
+        output_format: OutputFormat,
     ) -> Builder {
         let mut builder = Builder::default();
 
@@ -31,6 +35,7 @@ impl AbilityCommand<'_> {
             ability_name,
             show_pokemon,
             verbose,
+            output_format,
         }
         ._execute()
         .await;
@@ -42,32 +47,41 @@ impl AbilityCommand<'_> {
         let ability = match self.fetch_ability().await {
             Ok(ability) => ability,
             Err(error_message) => {
-                self.builder.append(error_message);
+                self.builder.append_error(error_message, self.output_format);
                 return;
             }
         };
 
-        self.builder.appendln(formatter::white("Ability"));
-
         let format_ability = FormatAbility::new(ability.clone()).with_verbose(self.verbose);
+
+        let mut ability_pokemon_names = ability
+            .pokemon
+            .into_iter()
+            .map(|ability_pokemon| ability_pokemon.pokemon.name)
+            .collect_vec();
+        ability_pokemon_names.sort();
+
+        match self.output_format {
+            OutputFormat::Text => self.build_output(&format_ability, &ability_pokemon_names),
+            OutputFormat::Json => self.build_json(&format_ability, &ability_pokemon_names),
+        }
+    }
+
+    fn build_output(&mut self, format_ability: &FormatAbility, ability_pokemon_names: &[String]) {
+        self.builder.appendln(formatter::white("Ability"));
         self.builder.append(format_ability.format());
 
         if self.show_pokemon {
             self.builder.newline();
 
-            let mut ability_pokemon_vec = ability.pokemon;
-            ability_pokemon_vec.sort_by(|a, b| a.pokemon.name.cmp(&b.pokemon.name));
-
             self.builder.appendln(formatter::white(&format!(
                 "Pokemon ({})",
-                ability_pokemon_vec.len()
+                ability_pokemon_names.len()
             )));
 
-            let pokemon_names = ability_pokemon_vec
-                .into_iter()
-                .map(|ability_pokemon| {
-                    formatter::split_and_capitalise(&ability_pokemon.pokemon.name)
-                })
+            let pokemon_names = ability_pokemon_names
+                .iter()
+                .map(|pokemon_name| formatter::split_and_capitalise(pokemon_name))
                 .collect_vec();
 
             self.builder
@@ -75,6 +89,16 @@ impl AbilityCommand<'_> {
         }
     }
 
+    fn build_json(&mut self, format_ability: &FormatAbility, ability_pokemon_names: &[String]) {
+        let mut json = json!({ "ability": format_ability.to_json() });
+
+        if self.show_pokemon {
+            json["pokemon"] = json!(ability_pokemon_names);
+        }
+
+        self.builder.append(json);
+    }
+
     async fn fetch_ability(&self) -> Result<Ability, String> {
         let successful_match =
             matcher::match_ability_name(&self.ability_name).map_err(|no_match| no_match.0)?;
@@ -86,13 +110,14 @@ impl AbilityCommand<'_> {
 
         match result {
             Ok(ability) => Ok(ability),
-            Err(_) => {
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
                 let output = matcher::build_unknown_name(
                     &successful_match.keyword,
                     &successful_match.suggested_name,
                 );
                 Err(output)
             }
+            Err(error) => Err(error.to_string()),
         }
     }
 }
@@ -0,0 +1,125 @@
+use crate::{
+    builder::Builder,
+    client::{ClientError, ClientImplementation},
+    fetch_failure::{self, FetchFailure},
+    formatter::{self, FormatBerry, FormatItem, FormatModel},
+    name_matcher::matcher,
+    output_format::OutputFormat,
+};
+
+use rustemon::model::{berries::Berry, items::Item};
+use serde_json::json;
+
+pub struct BerryCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    berry_name: String,
+    verbose: bool,
+    output_format: OutputFormat,
+}
+
+impl BerryCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        berry_name: String,
+        verbose: bool,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        BerryCommand {
+            builder: &mut builder,
+            client,
+            berry_name,
+            verbose,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let berry = match self.fetch_berry().await {
+            Ok(berry) => berry,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        let format_berry = FormatBerry::new(berry);
+
+        // The held item effect lives on the berry's item, so a failure there still shows the berry
+        let (format_item, failures) = match self.fetch_item(format_berry.item_name()).await {
+            Ok(item) => (
+                Some(FormatItem::new(item).with_verbose(self.verbose)),
+                vec![],
+            ),
+            Err(failure) => (None, vec![failure]),
+        };
+
+        match self.output_format {
+            OutputFormat::Text => {
+                self.builder.appendln(formatter::white("Berry"));
+                self.builder.append(format_berry.format());
+
+                if let Some(format_item) = &format_item {
+                    self.builder.newline();
+                    self.builder.appendln(formatter::white("Item"));
+                    self.builder.append(format_item.format());
+                }
+
+                fetch_failure::append_failures(self.builder, &failures);
+            }
+            OutputFormat::Json => {
+                let mut output = json!({
+                    "berry": format_berry.to_json(),
+                    "item": format_item.map(|format_item| format_item.to_json()),
+                });
+
+                if !failures.is_empty() {
+                    output["failed"] = fetch_failure::failures_json(&failures);
+                }
+
+                self.builder.append(output);
+            }
+        }
+    }
+
+    async fn fetch_berry(&self) -> Result<Berry, String> {
+        // Berries are usually referred to by their item name, which the berry endpoint doesn't use
+        let berry_name = self
+            .berry_name
+            .strip_suffix("-berry")
+            .unwrap_or(&self.berry_name);
+
+        let successful_match =
+            matcher::match_berry_name(berry_name).map_err(|no_match| no_match.0)?;
+
+        let result = self
+            .client
+            .fetch_berry(&successful_match.suggested_name)
+            .await;
+
+        match result {
+            Ok(berry) => Ok(berry),
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
+                let output = matcher::build_unknown_name(
+                    &successful_match.keyword,
+                    &successful_match.suggested_name,
+                );
+                Err(output)
+            }
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    async fn fetch_item(&self, item_name: &str) -> Result<Item, FetchFailure> {
+        self.client
+            .fetch_item(item_name)
+            .await
+            .map_err(|error| FetchFailure::new("item", item_name, error))
+    }
+}
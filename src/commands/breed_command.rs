@@ -0,0 +1,178 @@
+use crate::{
+    breeding::{self, Compatibility, Parent},
+    builder::Builder,
+    client::ClientImplementation,
+    formatter::{self, FormatPokemon},
+    output_format::OutputFormat,
+};
+
+use futures::try_join;
+use itertools::Itertools;
+use serde_json::json;
+
+pub struct BreedCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    first_pokemon_name: String,
+    second_pokemon_name: String,
+    output_format: OutputFormat,
+}
+
+impl BreedCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        first_pokemon_name: String,
+        second_pokemon_name: String,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        BreedCommand {
+            builder: &mut builder,
+            client,
+            first_pokemon_name,
+            second_pokemon_name,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let parents = try_join!(
+            FormatPokemon::fetch(self.client, &self.first_pokemon_name),
+            FormatPokemon::fetch(self.client, &self.second_pokemon_name),
+        );
+
+        let (first, second) = match parents {
+            Ok(parents) => parents,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        let first_parent = Parent::new(first.name(), first.species());
+        let second_parent = Parent::new(second.name(), second.species());
+        let compatibility = breeding::compatibility(&first_parent, &second_parent);
+
+        match self.output_format {
+            OutputFormat::Text => self.build_output(&first, &second, &compatibility),
+            OutputFormat::Json => self.build_json(&first, &second, &compatibility),
+        }
+    }
+
+    fn build_output(
+        &mut self,
+        first: &FormatPokemon,
+        second: &FormatPokemon,
+        compatibility: &Compatibility,
+    ) {
+        for parent in [first, second] {
+            let egg_groups = parent
+                .egg_group_names()
+                .into_iter()
+                .map(formatter::format_egg_group_name)
+                .join(" | ");
+
+            self.builder
+                .appendln(formatter::white(&formatter::split_and_capitalise(
+                    parent.name(),
+                )));
+            self.builder.appendln(format!(
+                "  {}: {egg_groups}",
+                formatter::white("Egg Groups")
+            ));
+            self.builder.appendln(format!(
+                "  {}: {}",
+                formatter::white("Gender Ratio"),
+                parent.formatted_gender_ratio()
+            ));
+            self.builder.newline();
+        }
+
+        let pair = format!(
+            "{} and {}",
+            formatter::split_and_capitalise(first.name()),
+            formatter::split_and_capitalise(second.name())
+        );
+
+        let outcome = match compatibility {
+            Compatibility::SharedEggGroups(egg_groups) => {
+                let noun = if egg_groups.len() == 1 {
+                    "egg group"
+                } else {
+                    "egg groups"
+                };
+                let egg_groups = egg_groups
+                    .iter()
+                    .map(|egg_group| formatter::format_egg_group_name(egg_group))
+                    .join(" and ");
+
+                format!("{pair} can breed as they share the {egg_groups} {noun}")
+            }
+            Compatibility::WithDitto => {
+                format!("{pair} can breed as Ditto breeds with most pokemon")
+            }
+            Compatibility::Undiscovered(name) => format!(
+                "{pair} can't breed as {} is in the Undiscovered egg group",
+                formatter::split_and_capitalise(name)
+            ),
+            Compatibility::BothDitto => {
+                format!("{pair} can't breed as Ditto can't breed with Ditto")
+            }
+            Compatibility::Genderless(name) => format!(
+                "{pair} can't breed as {} is genderless and only breeds with Ditto",
+                formatter::split_and_capitalise(name)
+            ),
+            Compatibility::SameGender(gender) => {
+                format!("{pair} can't breed as they're both always {gender}")
+            }
+            Compatibility::NoSharedEggGroup => {
+                format!("{pair} can't breed as they don't share an egg group")
+            }
+        };
+
+        if compatibility.can_breed() {
+            self.builder.append(formatter::green(&outcome));
+        } else {
+            self.builder.append(formatter::red(&outcome));
+        }
+    }
+
+    fn build_json(
+        &mut self,
+        first: &FormatPokemon,
+        second: &FormatPokemon,
+        compatibility: &Compatibility,
+    ) {
+        let pokemon = [first, second]
+            .into_iter()
+            .map(|parent| {
+                let gender_ratio = parent
+                    .gender_ratio()
+                    .map(|(male, female)| json!({ "male": male, "female": female }));
+
+                json!({
+                    "name": parent.name(),
+                    "egg_groups": parent.egg_group_names(),
+                    "gender_ratio": gender_ratio,
+                })
+            })
+            .collect_vec();
+
+        let shared_egg_groups = match compatibility {
+            Compatibility::SharedEggGroups(egg_groups) => egg_groups.clone(),
+            _ => vec![],
+        };
+
+        self.builder.append(json!({
+            "pokemon": pokemon,
+            "can_breed": compatibility.can_breed(),
+            "reason": compatibility.reason(),
+            "shared_egg_groups": shared_egg_groups,
+        }));
+    }
+}
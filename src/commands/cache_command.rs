@@ -0,0 +1,242 @@
+use crate::{
+    builder::Builder, commands::cache_warm_command, formatter, output_format::OutputFormat,
+};
+
+use std::{
+    fs,
+    io::ErrorKind,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use cacache::Metadata;
+use serde_json::json;
+
+pub enum CacheAction {
+    Stats,
+    Clear,
+    Prune { older_than: Duration },
+}
+
+pub struct CacheCommand<'a> {
+    builder: &'a mut Builder,
+    cache_dir: PathBuf,
+    action: CacheAction,
+    output_format: OutputFormat,
+}
+
+impl CacheCommand<'_> {
+    pub fn execute(
+        cache_dir: PathBuf,
+        action: CacheAction,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        CacheCommand {
+            builder: &mut builder,
+            cache_dir,
+            action,
+            output_format,
+        }
+        ._execute();
+
+        builder
+    }
+
+    fn _execute(&mut self) {
+        let entries = match self.list_entries() {
+            Ok(entries) => entries,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        let result = match self.action {
+            CacheAction::Stats => {
+                self.build_stats(&entries);
+                Ok(())
+            }
+            CacheAction::Clear => self.clear(&entries),
+            CacheAction::Prune { older_than } => self.prune(&entries, older_than),
+        };
+
+        if let Err(error_message) = result {
+            self.builder.append_error(error_message, self.output_format);
+        }
+    }
+
+    fn list_entries(&self) -> Result<Vec<Metadata>, String> {
+        // The index directory doesn't exist until something has been cached
+        cacache::list_sync(&self.cache_dir)
+            .filter(|entry| {
+                !matches!(
+                    entry,
+                    Err(cacache::Error::IoError(error, _)) if error.kind() == ErrorKind::NotFound
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| format!("Failed to read cache: {error}"))
+    }
+
+    fn build_stats(&mut self, entries: &[Metadata]) {
+        let total_size = Self::total_size(entries);
+        let oldest_entry = entries.iter().min_by_key(|entry| entry.time);
+        let location = self.cache_dir.display().to_string();
+
+        if self.output_format.is_json() {
+            let oldest_entry = oldest_entry.map(|entry| {
+                json!({
+                    "url": Self::entry_url(entry),
+                    "age_seconds": Self::entry_age(entry).as_secs(),
+                })
+            });
+
+            self.builder.append(json!({
+                "cache": {
+                    "location": location,
+                    "entries": entries.len(),
+                    "size": total_size,
+                    "oldest_entry": oldest_entry,
+                }
+            }));
+
+            return;
+        }
+
+        self.builder.appendln(formatter::white("Cache"));
+        self.builder.append(formatter::formatln(
+            &formatter::white("Location"),
+            &location,
+        ));
+        self.builder.append(formatter::formatln(
+            &formatter::white("Entries"),
+            &entries.len().to_string(),
+        ));
+        self.builder.append(formatter::formatln(
+            &formatter::white("Size"),
+            &Self::format_size(total_size),
+        ));
+
+        let oldest_entry = match oldest_entry {
+            Some(entry) => format!(
+                "{} ({} ago)",
+                Self::entry_url(entry),
+                Self::format_age(Self::entry_age(entry))
+            ),
+            None => String::from("-"),
+        };
+
+        // `formatln` capitalises the value, which would mangle the URL
+        self.builder.appendln(format!(
+            "  {}: {oldest_entry}",
+            formatter::white("Oldest Entry")
+        ));
+    }
+
+    fn clear(&mut self, entries: &[Metadata]) -> Result<(), String> {
+        if self.cache_dir.exists() {
+            cacache::clear_sync(&self.cache_dir)
+                .map_err(|error| format!("Failed to clear cache: {error}"))?;
+        }
+
+        self.reset_warm_progress();
+        self.build_removed_summary(entries.len(), Self::total_size(entries));
+
+        Ok(())
+    }
+
+    fn prune(&mut self, entries: &[Metadata], older_than: Duration) -> Result<(), String> {
+        let stale_entries = entries
+            .iter()
+            .filter(|entry| Self::entry_age(entry) > older_than)
+            .collect::<Vec<_>>();
+
+        for entry in &stale_entries {
+            cacache::RemoveOpts::new()
+                .remove_fully(true)
+                .remove_sync(&self.cache_dir, &entry.key)
+                .map_err(|error| format!("Failed to remove \"{}\": {error}", entry.key))?;
+        }
+
+        if !stale_entries.is_empty() {
+            self.reset_warm_progress();
+        }
+
+        let removed_size = stale_entries.iter().map(|entry| entry.size).sum();
+        self.build_removed_summary(stale_entries.len(), removed_size);
+
+        Ok(())
+    }
+
+    // Otherwise the next `cache warm` would skip everything that was just removed
+    fn reset_warm_progress(&self) {
+        let _ = fs::remove_file(cache_warm_command::progress_path(&self.cache_dir));
+    }
+
+    fn build_removed_summary(&mut self, removed_entries: usize, removed_size: usize) {
+        if self.output_format.is_json() {
+            self.builder.append(json!({
+                "removed_entries": removed_entries,
+                "removed_size": removed_size,
+            }));
+        } else {
+            self.builder.append(format!(
+                "Removed {removed_entries} entries ({})",
+                Self::format_size(removed_size)
+            ));
+        }
+    }
+
+    fn total_size(entries: &[Metadata]) -> usize {
+        entries.iter().map(|entry| entry.size).sum()
+    }
+
+    // Keys are created by `http-cache` in the format "<METHOD>:<URL>"
+    fn entry_url(entry: &Metadata) -> &str {
+        entry
+            .key
+            .split_once(':')
+            .map_or(entry.key.as_str(), |(_method, url)| url)
+    }
+
+    fn entry_age(entry: &Metadata) -> Duration {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+
+        let age_millis = now.saturating_sub(entry.time);
+        Duration::from_millis(u64::try_from(age_millis).unwrap_or(u64::MAX))
+    }
+
+    fn format_age(age: Duration) -> String {
+        let seconds = age.as_secs();
+
+        match seconds {
+            0..60 => format!("{seconds} seconds"),
+            60..3600 => format!("{} minutes", seconds / 60),
+            3600..86400 => format!("{} hours", seconds / 3600),
+            _ => format!("{} days", seconds / 86400),
+        }
+    }
+
+    fn format_size(size: usize) -> String {
+        const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
+
+        let mut size = size as f64;
+        let mut unit_index = 0;
+
+        while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit_index += 1;
+        }
+
+        if unit_index == 0 {
+            format!("{size} {}", UNITS[unit_index])
+        } else {
+            format!("{size:.1} {}", UNITS[unit_index])
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{
+    builder::Builder,
+    client::{ClientError, ClientImplementation},
+    fetch_failure::{self, FetchFailure},
+    formatter,
+    name_matcher::{
+        ability_names::ABILITY_NAMES, berry_names::BERRY_NAMES, egg_group_names::EGG_GROUP_NAMES,
+        generation_names::GENERATION_NAMES, item_names::ITEM_NAMES,
+        move_learn_method_names::MOVE_LEARN_METHOD_NAMES, move_names::MOVE_NAMES,
+        nature_names::NATURE_NAMES, pokemon_names::POKEMON_NAMES, type_names::TYPE_NAMES,
+    },
+    output_format::OutputFormat,
+};
+
+use std::{
+    collections::HashSet,
+    fs::{self, File, OpenOptions},
+    io::{IsTerminal, Write},
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+use futures::{StreamExt, stream};
+use serde_json::json;
+
+const PROGRESS_FILE_SUFFIX: &str = "-warm-progress";
+
+/// Where `cache warm` records what it has already fetched. This sits next to the cache rather
+/// than inside it, as cacache expects its directory to only contain its own entries
+pub fn progress_path(cache_dir: &Path) -> PathBuf {
+    let mut file_name = cache_dir.file_name().unwrap_or_default().to_os_string();
+    file_name.push(PROGRESS_FILE_SUFFIX);
+
+    cache_dir.with_file_name(file_name)
+}
+
+#[derive(Clone, Copy)]
+enum Resource {
+    Ability,
+    Berry,
+    EggGroup,
+    Encounters,
+    Generation,
+    Item,
+    Move,
+    MoveLearnMethod,
+    Nature,
+    Pokemon,
+    Type,
+}
+
+impl Resource {
+    const ALL: &[Resource] = &[
+        Resource::Ability,
+        Resource::Berry,
+        Resource::EggGroup,
+        Resource::Encounters,
+        Resource::Generation,
+        Resource::Item,
+        Resource::Move,
+        Resource::MoveLearnMethod,
+        Resource::Nature,
+        Resource::Pokemon,
+        Resource::Type,
+    ];
+
+    fn keyword(&self) -> &'static str {
+        match self {
+            Resource::Ability => "ability",
+            Resource::Berry => "berry",
+            Resource::EggGroup => "egg-group",
+            Resource::Encounters => "encounters",
+            Resource::Generation => "generation",
+            Resource::Item => "item",
+            Resource::Move => "move",
+            Resource::MoveLearnMethod => "move-learn-method",
+            Resource::Nature => "nature",
+            Resource::Pokemon => "pokemon",
+            Resource::Type => "type",
+        }
+    }
+
+    fn names(&self) -> &'static LazyLock<Vec<String>> {
+        match self {
+            Resource::Ability => &ABILITY_NAMES,
+            Resource::Berry => &BERRY_NAMES,
+            Resource::EggGroup => &EGG_GROUP_NAMES,
+            Resource::Encounters => &POKEMON_NAMES,
+            Resource::Generation => &GENERATION_NAMES,
+            Resource::Item => &ITEM_NAMES,
+            Resource::Move => &MOVE_NAMES,
+            Resource::MoveLearnMethod => &MOVE_LEARN_METHOD_NAMES,
+            Resource::Nature => &NATURE_NAMES,
+            Resource::Pokemon => &POKEMON_NAMES,
+            Resource::Type => &TYPE_NAMES,
+        }
+    }
+}
+
+struct Task {
+    resource: Resource,
+    name: &'static str,
+}
+
+impl Task {
+    fn progress_key(&self) -> String {
+        format!("{}:{}", self.resource.keyword(), self.name)
+    }
+}
+
+pub struct CacheWarmCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    cache_dir: PathBuf,
+    concurrency: usize,
+    restart: bool,
+    output_format: OutputFormat,
+}
+
+impl CacheWarmCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        cache_dir: PathBuf,
+        concurrency: usize,
+        restart: bool,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        CacheWarmCommand {
+            builder: &mut builder,
+            client,
+            cache_dir,
+            concurrency,
+            restart,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let progress_path = progress_path(&self.cache_dir);
+
+        if self.restart {
+            let _ = fs::remove_file(&progress_path);
+        }
+
+        let completed = self.read_progress(&progress_path);
+        let mut progress_file = match self.open_progress_file(&progress_path) {
+            Ok(progress_file) => progress_file,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        let (skipped_tasks, tasks): (Vec<_>, Vec<_>) = Resource::ALL
+            .iter()
+            .flat_map(|resource| {
+                resource.names().iter().map(|name| Task {
+                    resource: *resource,
+                    name: name.as_str(),
+                })
+            })
+            .partition(|task| completed.contains(&task.progress_key()));
+
+        let skipped = skipped_tasks.len();
+        let total = tasks.len();
+        let show_progress = std::io::stderr().is_terminal();
+        let client = self.client;
+
+        let mut results = stream::iter(tasks)
+            .map(|task| async move {
+                let result = Self::fetch(client, &task).await;
+                (task, result)
+            })
+            .buffer_unordered(self.concurrency.max(1));
+
+        let mut fetched = 0;
+        let mut failures = Vec::new();
+
+        while let Some((task, result)) = results.next().await {
+            match result {
+                Ok(()) => {
+                    fetched += 1;
+                    // Failing to record progress only means the entry is fetched again next time
+                    let _ = writeln!(progress_file, "{}", task.progress_key());
+                }
+                Err(error) => {
+                    failures.push(FetchFailure::new(task.resource.keyword(), task.name, error))
+                }
+            }
+
+            if show_progress {
+                eprint!("\rWarming cache: {}/{total}", fetched + failures.len());
+            }
+        }
+
+        if show_progress {
+            eprintln!();
+        }
+
+        // Start from scratch next time once everything has been fetched successfully
+        if failures.is_empty() {
+            let _ = fs::remove_file(&progress_path);
+        }
+
+        self.build_summary(fetched, skipped, &failures);
+    }
+
+    async fn fetch(client: &dyn ClientImplementation, task: &Task) -> Result<(), ClientError> {
+        match task.resource {
+            Resource::Ability => client.fetch_ability(task.name).await.map(|_| ()),
+            Resource::Berry => client.fetch_berry(task.name).await.map(|_| ()),
+            Resource::EggGroup => client.fetch_egg_group(task.name).await.map(|_| ()),
+            Resource::Encounters => client.fetch_pokemon_encounters(task.name).await.map(|_| ()),
+            Resource::Generation => client.fetch_generation(task.name).await.map(|_| ()),
+            Resource::Item => client.fetch_item(task.name).await.map(|_| ()),
+            Resource::Move => client.fetch_move(task.name).await.map(|_| ()),
+            Resource::MoveLearnMethod => {
+                client.fetch_move_learn_method(task.name).await.map(|_| ())
+            }
+            Resource::Nature => client.fetch_nature(task.name).await.map(|_| ()),
+            Resource::Type => client.fetch_type(task.name).await.map(|_| ()),
+            Resource::Pokemon => {
+                // `pokemon` also needs the species and evolution chain to work offline
+                let pokemon = client.fetch_pokemon(task.name).await?;
+                let species = client.fetch_pokemon_species(&pokemon.species.name).await?;
+
+                if let Some(evolution_chain) = &species.evolution_chain {
+                    client
+                        .fetch_evolution_chain_from_url(&evolution_chain.url)
+                        .await?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    fn read_progress(&self, progress_path: &Path) -> HashSet<String> {
+        fs::read_to_string(progress_path)
+            .map(|progress| progress.lines().map(ToOwned::to_owned).collect())
+            .unwrap_or_default()
+    }
+
+    fn open_progress_file(&self, progress_path: &Path) -> Result<File, String> {
+        let progress_dir = progress_path.parent().unwrap_or(&self.cache_dir);
+
+        fs::create_dir_all(progress_dir)
+            .and_then(|_| {
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(progress_path)
+            })
+            .map_err(|error| format!("Failed to open warm progress file: {error}"))
+    }
+
+    fn build_summary(&mut self, fetched: usize, skipped: usize, failures: &[FetchFailure]) {
+        if self.output_format.is_json() {
+            self.builder.append(json!({
+                "fetched": fetched,
+                "skipped": skipped,
+                "failures": fetch_failure::failures_json(failures),
+            }));
+
+            return;
+        }
+
+        self.builder.appendln(formatter::white("Cache warm"));
+        self.builder.append(formatter::formatln(
+            &formatter::white("Fetched"),
+            &fetched.to_string(),
+        ));
+        self.builder.append(formatter::formatln(
+            &formatter::white("Skipped"),
+            &format!("{skipped} (already warmed)"),
+        ));
+        self.builder.append(formatter::formatln(
+            &formatter::white("Failed"),
+            &failures.len().to_string(),
+        ));
+
+        if failures.is_empty() {
+            return;
+        }
+
+        fetch_failure::append_failures(self.builder, failures);
+        self.builder.newline();
+        self.builder
+            .append("Run `cache warm` again to retry the failures");
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    builder::Builder,
+    client::ClientImplementation,
+    commands::pokemon_command::STAT_NAMES,
+    formatter::{self, FormatModel, FormatPokemon},
+    output_format::OutputFormat,
+    stat_calculator::{self, STAT_ABBREVIATIONS, STAT_API_NAMES},
+    type_badge,
+};
+
+use futures::future::try_join_all;
+use itertools::Itertools;
+use serde_json::{Map, json};
+use unicode_width::UnicodeWidthStr;
+
+pub const MIN_COMPARE_SIZE: usize = 2;
+
+// A cell's display text can contain colour codes, so its printed width is tracked separately
+struct Cell {
+    text: String,
+    width: usize,
+}
+
+impl Cell {
+    fn plain(text: String) -> Self {
+        let width = UnicodeWidthStr::width(text.as_str());
+        Cell { text, width }
+    }
+}
+
+struct Row {
+    label: &'static str,
+    cells: Vec<Cell>,
+}
+
+pub struct CompareCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    pokemon_names: Vec<String>,
+    output_format: OutputFormat,
+}
+
+impl CompareCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        pokemon_names: Vec<String>,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        CompareCommand {
+            builder: &mut builder,
+            client,
+            pokemon_names,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let contenders = match try_join_all(
+            self.pokemon_names
+                .iter()
+                .map(|pokemon_name| FormatPokemon::fetch(self.client, pokemon_name)),
+        )
+        .await
+        {
+            Ok(contenders) => contenders,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        match self.output_format {
+            OutputFormat::Text => self.build_output(&contenders),
+            OutputFormat::Json => self.build_json(&contenders),
+        }
+    }
+
+    fn build_output(&mut self, contenders: &[FormatPokemon]) {
+        let names = contenders
+            .iter()
+            .map(|contender| Cell::plain(formatter::split_and_capitalise(contender.name())))
+            .collect_vec();
+
+        let mut rows = vec![
+            Row {
+                label: "Type",
+                cells: contenders.iter().map(Self::type_cell).collect(),
+            },
+            Row {
+                label: "Abilities",
+                cells: contenders
+                    .iter()
+                    .map(|contender| {
+                        Cell::plain(
+                            contender
+                                .ability_names()
+                                .into_iter()
+                                .map(formatter::split_and_capitalise)
+                                .join(", "),
+                        )
+                    })
+                    .collect(),
+            },
+            Row {
+                label: "Generation",
+                cells: contenders
+                    .iter()
+                    .map(|contender| {
+                        Cell::plain(contender.generation_numeral().unwrap_or_default())
+                    })
+                    .collect(),
+            },
+            Row {
+                label: "Effort Values",
+                cells: contenders.iter().map(Self::effort_values_cell).collect(),
+            },
+        ];
+
+        let base_stats = contenders
+            .iter()
+            .map(FormatPokemon::base_stats)
+            .collect_vec();
+
+        for (index, label) in STAT_NAMES.iter().enumerate() {
+            let values = base_stats.iter().map(|stats| stats[index]).collect_vec();
+            rows.push(Row {
+                label,
+                cells: Self::highlighted_cells(&values),
+            });
+        }
+
+        let totals = base_stats
+            .iter()
+            .map(|stats| stats.iter().sum())
+            .collect_vec();
+        rows.push(Row {
+            label: "Total",
+            cells: Self::highlighted_cells(&totals),
+        });
+
+        self.build_table(&names, &rows);
+    }
+
+    fn type_cell(contender: &FormatPokemon) -> Cell {
+        let type_names = contender.type_names();
+
+        let width = type_names
+            .iter()
+            .map(|type_name| {
+                UnicodeWidthStr::width(type_badge::format_type_name(type_name).as_str())
+            })
+            .sum::<usize>()
+            + type_names.len().saturating_sub(1);
+
+        Cell {
+            text: type_names.into_iter().map(type_badge::fetch).join(" "),
+            width,
+        }
+    }
+
+    fn effort_values_cell(contender: &FormatPokemon) -> Cell {
+        let effort_values = contender
+            .effort_values()
+            .into_iter()
+            .filter_map(|(stat_name, effort)| {
+                let index = STAT_API_NAMES.iter().position(|name| *name == stat_name)?;
+                Some(format!("{} +{effort}", STAT_ABBREVIATIONS[index]))
+            })
+            .join(", ");
+
+        Cell::plain(effort_values)
+    }
+
+    // Highlights the highest value unless every pokemon shares it
+    fn highlighted_cells(values: &[i64]) -> Vec<Cell> {
+        let highest = values.iter().copied().max().unwrap_or(0);
+        let all_equal = values.iter().all_equal();
+
+        values
+            .iter()
+            .map(|value| {
+                let cell = Cell::plain(value.to_string());
+                if *value == highest && !all_equal {
+                    Cell {
+                        text: formatter::highlight(&formatter::green(&cell.text)),
+                        width: cell.width,
+                    }
+                } else {
+                    cell
+                }
+            })
+            .collect()
+    }
+
+    fn build_table(&mut self, names: &[Cell], rows: &[Row]) {
+        let label_width = rows
+            .iter()
+            .map(|row| UnicodeWidthStr::width(row.label))
+            .max()
+            .unwrap_or(0);
+
+        let column_widths = names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                rows.iter()
+                    .map(|row| row.cells[index].width)
+                    .chain([name.width])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect_vec();
+
+        self.builder.appendln(formatter::white("Compare"));
+        self.builder.appendln(format!(
+            "  {} | {}",
+            " ".repeat(label_width),
+            formatter::white(&Self::join_cells(names, &column_widths))
+        ));
+
+        for row in rows {
+            let padding = " ".repeat(label_width - UnicodeWidthStr::width(row.label));
+            self.builder.appendln(format!(
+                "  {}{padding} | {}",
+                formatter::white(row.label),
+                Self::join_cells(&row.cells, &column_widths)
+            ));
+        }
+    }
+
+    fn join_cells(cells: &[Cell], column_widths: &[usize]) -> String {
+        cells
+            .iter()
+            .zip(column_widths)
+            .map(|(cell, width)| format!("{}{}", cell.text, " ".repeat(width - cell.width)))
+            .join(" | ")
+            .trim_end()
+            .to_owned()
+    }
+
+    fn build_json(&mut self, contenders: &[FormatPokemon]) {
+        let pokemon = contenders
+            .iter()
+            .map(|contender| {
+                let mut json = contender.to_json();
+                json["stats"] = stat_calculator::stat_spread_json(&contender.base_stats());
+                json
+            })
+            .collect_vec();
+
+        let highest = STAT_API_NAMES
+            .iter()
+            .map(|stat_name| stat_name.to_string())
+            .chain([String::from("total")])
+            .map(|stat_name| {
+                let values = pokemon
+                    .iter()
+                    .map(|pokemon| pokemon["stats"][&stat_name].as_i64().unwrap_or(0))
+                    .collect_vec();
+                let max = values.iter().copied().max().unwrap_or(0);
+
+                let names = pokemon
+                    .iter()
+                    .zip(&values)
+                    .filter(|(_, value)| **value == max)
+                    .map(|(pokemon, _)| pokemon["name"].clone())
+                    .collect_vec();
+
+                (stat_name, json!(names))
+            })
+            .collect::<Map<_, _>>();
+
+        self.builder.append(json!({
+            "pokemon": pokemon,
+            "highest": highest,
+        }));
+    }
+}
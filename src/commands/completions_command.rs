@@ -0,0 +1,84 @@
+use crate::{builder::Builder, completion};
+
+use clap::ValueEnum;
+
+// Each script passes the words typed so far to the hidden `__complete` command, so names
+// complete from the same lists the fuzzy matcher uses
+const BASH_SCRIPT: &str = r#"_poke_search() {
+    local IFS=$'\n'
+    COMPREPLY=($(poke_search __complete -- "${COMP_WORDS[@]:1:COMP_CWORD}" 2>/dev/null))
+}
+
+complete -F _poke_search poke_search"#;
+
+const ZSH_SCRIPT: &str = r#"#compdef poke_search
+
+_poke_search() {
+    local -a candidates
+    candidates=(${(f)"$(poke_search __complete -- "${(@)words[2,CURRENT]}" 2>/dev/null)"})
+    compadd -a candidates
+}
+
+if [ "$funcstack[1]" = "_poke_search" ]; then
+    _poke_search "$@"
+else
+    compdef _poke_search poke_search
+fi"#;
+
+const FISH_SCRIPT: &str = r#"function __poke_search_complete
+    set -l words (commandline -opc)
+    set -e words[1]
+    poke_search __complete -- $words (commandline -ct | string collect --allow-empty) 2>/dev/null
+end
+
+complete -c poke_search -f -a '(__poke_search_complete)'"#;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+pub struct CompletionsCommand<'a> {
+    builder: &'a mut Builder,
+}
+
+impl CompletionsCommand<'_> {
+    pub fn execute(shell: CompletionShell) -> Builder {
+        let mut builder = Builder::default();
+
+        CompletionsCommand {
+            builder: &mut builder,
+        }
+        .build_script(shell);
+
+        builder
+    }
+
+    pub fn complete(words: Vec<String>) -> Builder {
+        let mut builder = Builder::default();
+        let words = words.iter().map(String::as_str).collect::<Vec<_>>();
+
+        CompletionsCommand {
+            builder: &mut builder,
+        }
+        .build_candidates(&words);
+
+        builder
+    }
+
+    fn build_script(&mut self, shell: CompletionShell) {
+        let script = match shell {
+            CompletionShell::Bash => BASH_SCRIPT,
+            CompletionShell::Zsh => ZSH_SCRIPT,
+            CompletionShell::Fish => FISH_SCRIPT,
+        };
+
+        self.builder.append(script);
+    }
+
+    fn build_candidates(&mut self, words: &[&str]) {
+        self.builder.append(completion::complete(words).join("\n"));
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{
+    builder::Builder,
+    client::{ClientError, ClientImplementation},
+    commands::type_command::{DamageType, EXCLUDED_TYPES, TypeChart, TypeCommand},
+    formatter::{self, FormatMove},
+    name_matcher::{matcher, type_names::TYPE_NAMES},
+    output_format::OutputFormat,
+    type_badge,
+};
+
+use std::collections::HashMap;
+
+use futures::future::try_join_all;
+use itertools::Itertools;
+use rustemon::model::pokemon::{Pokemon, Type};
+use serde_json::json;
+
+pub const MAX_MOVES: usize = 4;
+
+// Stellar only exists as a tera type, so nothing ever defends as it
+const IGNORED_DEFENDING_TYPES: &[&str] = &["stellar"];
+
+struct Coverage {
+    types: Vec<String>,
+    best: DamageType,
+}
+
+impl Coverage {
+    fn name(&self) -> String {
+        self.types.join("/")
+    }
+
+    fn formatted_types(&self) -> String {
+        self.types
+            .iter()
+            .map(|type_name| type_badge::fetch(type_name))
+            .join(" ")
+    }
+
+    fn is_walled(&self) -> bool {
+        self.best <= DamageType::Half
+    }
+}
+
+pub struct CoverageCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    pokemon_name: String,
+    move_names: Vec<String>,
+    output_format: OutputFormat,
+}
+
+impl CoverageCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        pokemon_name: String,
+        move_names: Vec<String>,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        CoverageCommand {
+            builder: &mut builder,
+            client,
+            pokemon_name,
+            move_names,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let (pokemon, moves) = match self.fetch_pokemon_and_moves().await {
+            Ok(result) => result,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        if !moves.iter().any(FormatMove::is_damaging) {
+            self.builder.append_error(
+                String::from("None of the moves deal damage, so there is no coverage to show"),
+                self.output_format,
+            );
+            return;
+        }
+
+        let offence = match self.fetch_offence(&moves).await {
+            Ok(offence) => offence,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        let single_types = self.build_single_coverage(&offence);
+        let dual_types = self.build_dual_coverage(&offence);
+
+        match self.output_format {
+            OutputFormat::Text => self.build_output(&pokemon, &moves, &single_types, &dual_types),
+            OutputFormat::Json => self.build_json(&pokemon, &moves, &single_types, &dual_types),
+        }
+    }
+
+    async fn fetch_pokemon_and_moves(&self) -> Result<(Pokemon, Vec<FormatMove>), String> {
+        let moves = try_join_all(
+            self.move_names
+                .iter()
+                .unique()
+                .map(|move_name| self.fetch_move(move_name)),
+        );
+
+        let (pokemon, moves) = tokio::try_join!(self.fetch_pokemon(), moves)?;
+
+        Ok((pokemon, moves))
+    }
+
+    async fn fetch_pokemon(&self) -> Result<Pokemon, String> {
+        let successful_match =
+            matcher::match_pokemon_name(&self.pokemon_name).map_err(|no_match| no_match.0)?;
+
+        let result = self
+            .client
+            .fetch_pokemon(&successful_match.suggested_name)
+            .await;
+
+        match result {
+            Ok(pokemon) => Ok(pokemon),
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
+                let output = matcher::build_unknown_name(
+                    &successful_match.keyword,
+                    &successful_match.suggested_name,
+                );
+                Err(output)
+            }
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    async fn fetch_move(&self, move_name: &str) -> Result<FormatMove, String> {
+        let successful_match =
+            matcher::match_move_name(move_name).map_err(|no_match| no_match.0)?;
+
+        let result = self
+            .client
+            .fetch_move(&successful_match.suggested_name)
+            .await;
+
+        match result {
+            Ok(move_) => Ok(FormatMove::new(move_)),
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
+                let output = matcher::build_unknown_name(
+                    &successful_match.keyword,
+                    &successful_match.suggested_name,
+                );
+                Err(output)
+            }
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    /// The multiplier each damaging move type deals to every single type
+    async fn fetch_offence(
+        &self,
+        moves: &[FormatMove],
+    ) -> Result<Vec<HashMap<String, DamageType>>, String> {
+        let types = try_join_all(
+            moves
+                .iter()
+                .filter(|move_| move_.is_damaging())
+                .map(FormatMove::type_name)
+                .unique()
+                .map(|type_name| self.fetch_type(type_name)),
+        )
+        .await?;
+
+        Ok(types
+            .iter()
+            .map(|type_| TypeCommand::offence_multipliers(type_, TypeChart::Latest))
+            .collect())
+    }
+
+    async fn fetch_type(&self, type_name: &str) -> Result<Type, String> {
+        self.client
+            .fetch_type(type_name)
+            .await
+            .map_err(|error| error.to_string())
+    }
+
+    fn defending_type_names() -> Vec<&'static String> {
+        TYPE_NAMES
+            .iter()
+            .filter(|type_name| {
+                !EXCLUDED_TYPES.contains(&type_name.as_str())
+                    && !IGNORED_DEFENDING_TYPES.contains(&type_name.as_str())
+            })
+            .collect()
+    }
+
+    fn build_single_coverage(&self, offence: &[HashMap<String, DamageType>]) -> Vec<Coverage> {
+        Self::defending_type_names()
+            .into_iter()
+            .map(|type_name| Self::build_coverage(offence, vec![type_name.clone()]))
+            .collect()
+    }
+
+    fn build_dual_coverage(&self, offence: &[HashMap<String, DamageType>]) -> Vec<Coverage> {
+        Self::defending_type_names()
+            .into_iter()
+            .tuple_combinations()
+            .map(|(type_name, second_type_name)| {
+                Self::build_coverage(offence, vec![type_name.clone(), second_type_name.clone()])
+            })
+            .collect()
+    }
+
+    fn build_coverage(offence: &[HashMap<String, DamageType>], types: Vec<String>) -> Coverage {
+        let best = offence
+            .iter()
+            .map(|multipliers| {
+                types
+                    .iter()
+                    .map(|type_name| {
+                        multipliers
+                            .get(type_name)
+                            .copied()
+                            .unwrap_or(DamageType::Normal)
+                    })
+                    .reduce(DamageType::combine)
+                    .unwrap_or(DamageType::Normal)
+            })
+            .max()
+            .unwrap_or(DamageType::Normal);
+
+        Coverage { types, best }
+    }
+
+    fn is_stab(pokemon: &Pokemon, move_: &FormatMove) -> bool {
+        move_.is_damaging()
+            && pokemon
+                .types
+                .iter()
+                .any(|type_| type_.type_.name == move_.type_name())
+    }
+
+    fn build_output(
+        &mut self,
+        pokemon: &Pokemon,
+        moves: &[FormatMove],
+        single_types: &[Coverage],
+        dual_types: &[Coverage],
+    ) {
+        let pokemon_types = pokemon
+            .types
+            .iter()
+            .map(|type_| type_badge::fetch(&type_.type_.name))
+            .join(" | ");
+
+        self.builder.appendln(format!(
+            "{} {pokemon_types}",
+            formatter::split_and_capitalise(&pokemon.name)
+        ));
+        self.builder.newline();
+
+        self.builder.appendln(formatter::white("Moves"));
+        for move_ in moves {
+            let mut line = format!(
+                "  {}: {} {}",
+                formatter::split_and_capitalise(&move_.move_.name),
+                type_badge::fetch(move_.type_name()),
+                formatter::capitalise(move_.damage_class())
+            );
+
+            if Self::is_stab(pokemon, move_) {
+                line.push_str(&format!(" {}", formatter::highlight("STAB")));
+            } else if !move_.is_damaging() {
+                line.push_str(&format!(" {}", formatter::red("(ignored)")));
+            }
+
+            self.builder.appendln(line);
+        }
+
+        self.builder.newline();
+        self.builder.appendln(formatter::white("Single Types"));
+        for (best, coverage) in Self::group_by_best(single_types) {
+            let formatted_types = coverage
+                .iter()
+                .map(|coverage| coverage.formatted_types())
+                .join(" | ");
+
+            self.builder.appendln(format!(
+                "  {}: {formatted_types}",
+                best.format_offence(best.label())
+            ));
+        }
+
+        self.builder.newline();
+        self.builder.appendln(formatter::white("Dual Types"));
+        for (best, coverage) in Self::group_by_best(dual_types) {
+            self.builder.appendln(format!(
+                "  {}: {}",
+                best.format_offence(best.label()),
+                coverage.len()
+            ));
+        }
+
+        self.builder.newline();
+        self.builder.appendln(formatter::white("Walled"));
+
+        let walled = single_types
+            .iter()
+            .chain(dual_types)
+            .filter(|coverage| coverage.is_walled())
+            .map(|coverage| coverage.formatted_types())
+            .collect_vec();
+
+        if walled.is_empty() {
+            self.builder.append(formatter::green("  None"));
+        } else {
+            self.builder.append(formatter::format_columns(&walled, 3));
+            self.builder.pop();
+        }
+    }
+
+    fn group_by_best(coverage: &[Coverage]) -> Vec<(DamageType, Vec<&Coverage>)> {
+        coverage
+            .iter()
+            .into_group_map_by(|coverage| coverage.best)
+            .into_iter()
+            .sorted_by_key(|(best, _)| std::cmp::Reverse(*best))
+            .collect()
+    }
+
+    fn build_json(
+        &mut self,
+        pokemon: &Pokemon,
+        moves: &[FormatMove],
+        single_types: &[Coverage],
+        dual_types: &[Coverage],
+    ) {
+        let formatted_moves = moves
+            .iter()
+            .map(|move_| {
+                json!({
+                    "name": move_.move_.name,
+                    "type": move_.type_name(),
+                    "damage_class": move_.damage_class(),
+                    "stab": Self::is_stab(pokemon, move_),
+                })
+            })
+            .collect_vec();
+
+        let coverage_json = |coverage: &[Coverage]| {
+            coverage
+                .iter()
+                .map(|coverage| (coverage.name(), json!(coverage.best.label())))
+                .collect::<serde_json::Map<_, _>>()
+        };
+
+        let walled = single_types
+            .iter()
+            .chain(dual_types)
+            .filter(|coverage| coverage.is_walled())
+            .map(Coverage::name)
+            .collect_vec();
+
+        self.builder.append(json!({
+            "pokemon": {
+                "name": pokemon.name,
+                "types": pokemon
+                    .types
+                    .iter()
+                    .map(|type_| &type_.type_.name)
+                    .collect_vec(),
+            },
+            "moves": formatted_moves,
+            "single_types": coverage_json(single_types),
+            "dual_types": coverage_json(dual_types),
+            "walled": walled,
+        }));
+    }
+}
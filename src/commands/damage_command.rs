@@ -0,0 +1,488 @@
+use crate::{
+    builder::Builder,
+    client::{ClientError, ClientImplementation},
+    commands::{
+        nature_command::NatureCommand,
+        type_command::{DamageType, TypeChart, TypeCommand},
+    },
+    formatter::{self, FormatMove},
+    name_matcher::matcher,
+    output_format::OutputFormat,
+    stat_calculator::{
+        self, ATTACK, DEFENSE, HP, MAX_IV, Nature, SPECIAL_ATTACK, SPECIAL_DEFENSE,
+        STAT_ABBREVIATIONS, StatOptions, StatSpread,
+    },
+    type_badge,
+};
+
+use itertools::Itertools;
+use rustemon::model::{moves::Move, pokemon::Pokemon};
+use serde_json::json;
+
+pub const DEFAULT_LEVEL: i64 = 50;
+
+const MIN_RANDOM_ROLL: i64 = 85;
+const MAX_RANDOM_ROLL: i64 = 100;
+
+/// A pokemon in the calculation and how it's built. Anything left out is treated as
+/// perfect IVs, no EVs and a neutral nature
+#[derive(Clone, Debug)]
+pub struct Combatant {
+    pub pokemon_name: String,
+    pub stat_options: StatOptions,
+}
+
+impl Combatant {
+    fn level(&self) -> i64 {
+        self.stat_options.level
+    }
+
+    fn stats(&self, pokemon: &Pokemon, nature: Option<&Nature>) -> StatSpread {
+        let StatOptions {
+            level, ivs, evs, ..
+        } = self.stat_options;
+
+        stat_calculator::calculate_stats(
+            &stat_calculator::base_stats(pokemon),
+            level,
+            &ivs.unwrap_or([MAX_IV; stat_calculator::STAT_COUNT]),
+            &evs.unwrap_or_default(),
+            nature,
+        )
+    }
+}
+
+struct DamageRange {
+    rolls: Vec<i64>,
+}
+
+impl DamageRange {
+    fn min(&self) -> i64 {
+        self.rolls.first().copied().unwrap_or(0)
+    }
+
+    fn max(&self) -> i64 {
+        self.rolls.last().copied().unwrap_or(0)
+    }
+
+    fn percentage(damage: i64, hp: i64) -> f64 {
+        damage as f64 * 100.0 / hp as f64
+    }
+
+    fn knockout(&self, hp: i64) -> String {
+        if self.max() == 0 {
+            return String::from("No damage");
+        }
+
+        let hits_to_faint = |damage: i64| (hp + damage - 1) / damage;
+        let min_hits = hits_to_faint(self.max());
+        let max_hits = hits_to_faint(self.min().max(1));
+        let hits = match min_hits {
+            1 => String::from("OHKO"),
+            _ => format!("{min_hits}HKO"),
+        };
+
+        if min_hits == max_hits {
+            format!("Guaranteed {hits}")
+        } else {
+            format!("Possible {hits}")
+        }
+    }
+}
+
+struct Calculation {
+    attack_stat: usize,
+    defense_stat: usize,
+    attack: i64,
+    defense: i64,
+    hp: i64,
+    stab: bool,
+    effectiveness: DamageType,
+    normal: DamageRange,
+    critical: DamageRange,
+}
+
+pub struct DamageCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    attacker: Combatant,
+    defender: Combatant,
+    move_name: String,
+    // The natures from each combatant's `stat_options`, once they've been fetched
+    attacker_nature: Option<Nature>,
+    defender_nature: Option<Nature>,
+    output_format: OutputFormat,
+}
+
+impl DamageCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        attacker: Combatant,
+        defender: Combatant,
+        move_name: String,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        DamageCommand {
+            builder: &mut builder,
+            client,
+            attacker,
+            defender,
+            move_name,
+            attacker_nature: None,
+            defender_nature: None,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let fetched = tokio::try_join!(
+            self.fetch_pokemon(&self.attacker.pokemon_name),
+            self.fetch_pokemon(&self.defender.pokemon_name),
+            self.fetch_move(),
+            self.fetch_nature(&self.attacker),
+            self.fetch_nature(&self.defender),
+        );
+
+        let (attacker, defender, move_, attacker_nature, defender_nature) = match fetched {
+            Ok(fetched) => fetched,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        self.attacker_nature = attacker_nature;
+        self.defender_nature = defender_nature;
+
+        let move_ = FormatMove::new(move_);
+        let Some(power) = move_.move_.power.filter(|_| move_.is_damaging()) else {
+            let error_message = format!(
+                "{} doesn't have a base power, so its damage can't be calculated",
+                formatter::split_and_capitalise(&move_.move_.name)
+            );
+            self.builder.append_error(error_message, self.output_format);
+            return;
+        };
+
+        let effectiveness = match self.fetch_effectiveness(&move_, &defender).await {
+            Ok(effectiveness) => effectiveness,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        let calculation = self.calculate(&attacker, &defender, &move_, power, effectiveness);
+
+        match self.output_format {
+            OutputFormat::Text => self.build_output(&attacker, &defender, &move_, &calculation),
+            OutputFormat::Json => self.build_json(&attacker, &defender, &move_, &calculation),
+        }
+    }
+
+    async fn fetch_pokemon(&self, pokemon_name: &str) -> Result<Pokemon, String> {
+        let successful_match =
+            matcher::match_pokemon_name(pokemon_name).map_err(|no_match| no_match.0)?;
+
+        let result = self
+            .client
+            .fetch_pokemon(&successful_match.suggested_name)
+            .await;
+
+        match result {
+            Ok(pokemon) => Ok(pokemon),
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
+                let output = matcher::build_unknown_name(
+                    &successful_match.keyword,
+                    &successful_match.suggested_name,
+                );
+                Err(output)
+            }
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    async fn fetch_nature(&self, combatant: &Combatant) -> Result<Option<Nature>, String> {
+        let Some(nature_name) = &combatant.stat_options.nature else {
+            return Ok(None);
+        };
+
+        let nature = NatureCommand::fetch_nature(self.client, nature_name).await?;
+
+        Ok(Some(Nature::from(&nature)))
+    }
+
+    async fn fetch_move(&self) -> Result<Move, String> {
+        let successful_match =
+            matcher::match_move_name(&self.move_name).map_err(|no_match| no_match.0)?;
+
+        let result = self
+            .client
+            .fetch_move(&successful_match.suggested_name)
+            .await;
+
+        match result {
+            Ok(move_) => Ok(move_),
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
+                let output = matcher::build_unknown_name(
+                    &successful_match.keyword,
+                    &successful_match.suggested_name,
+                );
+                Err(output)
+            }
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    async fn fetch_effectiveness(
+        &self,
+        move_: &FormatMove,
+        defender: &Pokemon,
+    ) -> Result<DamageType, String> {
+        let move_type = self
+            .client
+            .fetch_type(move_.type_name())
+            .await
+            .map_err(|error| error.to_string())?;
+
+        let multipliers = TypeCommand::offence_multipliers(&move_type, TypeChart::Latest);
+
+        let effectiveness = defender
+            .types
+            .iter()
+            .map(|type_| {
+                multipliers
+                    .get(&type_.type_.name)
+                    .copied()
+                    .unwrap_or(DamageType::Normal)
+            })
+            .reduce(DamageType::combine)
+            .unwrap_or(DamageType::Normal);
+
+        Ok(effectiveness)
+    }
+
+    fn calculate(
+        &self,
+        attacker: &Pokemon,
+        defender: &Pokemon,
+        move_: &FormatMove,
+        power: i64,
+        effectiveness: DamageType,
+    ) -> Calculation {
+        let (attack_stat, defense_stat) = match move_.damage_class() {
+            "physical" => (ATTACK, DEFENSE),
+            _ => (SPECIAL_ATTACK, SPECIAL_DEFENSE),
+        };
+
+        let attacker_stats = self.attacker.stats(attacker, self.attacker_nature.as_ref());
+        let defender_stats = self.defender.stats(defender, self.defender_nature.as_ref());
+
+        let stab = attacker
+            .types
+            .iter()
+            .any(|type_| type_.type_.name == move_.type_name());
+
+        let base_damage = Self::base_damage(
+            self.attacker.level(),
+            power,
+            attacker_stats[attack_stat],
+            defender_stats[defense_stat],
+        );
+
+        let damage_range = |critical: bool| DamageRange {
+            rolls: (MIN_RANDOM_ROLL..=MAX_RANDOM_ROLL)
+                .map(|roll| Self::modify_damage(base_damage, roll, critical, stab, effectiveness))
+                .collect(),
+        };
+
+        Calculation {
+            attack_stat,
+            defense_stat,
+            attack: attacker_stats[attack_stat],
+            defense: defender_stats[defense_stat],
+            hp: defender_stats[HP],
+            stab,
+            effectiveness,
+            normal: damage_range(false),
+            critical: damage_range(true),
+        }
+    }
+
+    fn base_damage(level: i64, power: i64, attack: i64, defense: i64) -> i64 {
+        (2 * level / 5 + 2) * power * attack / defense.max(1) / 50 + 2
+    }
+
+    /// Applies the modifiers in the order the games do. Critical hits deal 1.5x damage as they
+    /// have since generation VI, and like STAB they round half down rather than truncating
+    fn modify_damage(
+        base_damage: i64,
+        roll: i64,
+        critical: bool,
+        stab: bool,
+        effectiveness: DamageType,
+    ) -> i64 {
+        let mut damage = base_damage;
+
+        if critical {
+            damage = (damage * 6144 + 2047) / 4096;
+        }
+
+        damage = damage * roll / 100;
+
+        if stab {
+            damage = (damage * 6144 + 2047) / 4096;
+        }
+
+        damage = match effectiveness {
+            DamageType::None => return 0,
+            DamageType::Quarter => damage / 4,
+            DamageType::Half => damage / 2,
+            DamageType::Normal => damage,
+            DamageType::Double => damage * 2,
+            DamageType::Quadruple => damage * 4,
+        };
+
+        damage.max(1)
+    }
+
+    fn format_pokemon(pokemon: &Pokemon, combatant: &Combatant) -> String {
+        let types = pokemon
+            .types
+            .iter()
+            .map(|type_| type_badge::fetch(&type_.type_.name))
+            .join(" | ");
+
+        format!(
+            "{} {types} (Lv. {})",
+            formatter::split_and_capitalise(&pokemon.name),
+            combatant.level()
+        )
+    }
+
+    fn format_range(range: &DamageRange, hp: i64) -> String {
+        format!(
+            "{} - {} ({:.1}% - {:.1}%) {}",
+            range.min(),
+            range.max(),
+            DamageRange::percentage(range.min(), hp),
+            DamageRange::percentage(range.max(), hp),
+            formatter::highlight(&range.knockout(hp))
+        )
+    }
+
+    fn build_output(
+        &mut self,
+        attacker: &Pokemon,
+        defender: &Pokemon,
+        move_: &FormatMove,
+        calculation: &Calculation,
+    ) {
+        self.builder.appendln(formatter::white("Attacker"));
+        self.builder.appendln(format!(
+            "  {}",
+            Self::format_pokemon(attacker, &self.attacker)
+        ));
+        self.builder.appendln(format!(
+            "  {}: {}",
+            STAT_ABBREVIATIONS[calculation.attack_stat], calculation.attack
+        ));
+        self.builder.newline();
+
+        self.builder.appendln(formatter::white("Defender"));
+        self.builder.appendln(format!(
+            "  {}",
+            Self::format_pokemon(defender, &self.defender)
+        ));
+        self.builder.appendln(format!(
+            "  HP: {} | {}: {}",
+            calculation.hp, STAT_ABBREVIATIONS[calculation.defense_stat], calculation.defense
+        ));
+        self.builder.newline();
+
+        let mut modifiers = vec![format!(
+            "{} effectiveness",
+            calculation
+                .effectiveness
+                .format_offence(calculation.effectiveness.label())
+        )];
+        if calculation.stab {
+            modifiers.push(String::from("STAB"));
+        }
+
+        self.builder.appendln(formatter::white("Move"));
+        self.builder.appendln(format!(
+            "  {} {} {} {}",
+            formatter::split_and_capitalise(&move_.move_.name),
+            type_badge::fetch(move_.type_name()),
+            formatter::capitalise(move_.damage_class()),
+            formatter::parse_maybe_i64(move_.move_.power),
+        ));
+        self.builder
+            .appendln(format!("  {}", modifiers.join(" | ")));
+        self.builder.newline();
+
+        self.builder.appendln(formatter::white("Damage"));
+        self.builder.appendln(format!(
+            "  Normal: {}",
+            Self::format_range(&calculation.normal, calculation.hp)
+        ));
+        self.builder.append(format!(
+            "  Critical: {}",
+            Self::format_range(&calculation.critical, calculation.hp)
+        ));
+    }
+
+    fn range_json(range: &DamageRange, hp: i64) -> serde_json::Value {
+        json!({
+            "min": range.min(),
+            "max": range.max(),
+            "min_percentage": DamageRange::percentage(range.min(), hp),
+            "max_percentage": DamageRange::percentage(range.max(), hp),
+            "knockout": range.knockout(hp),
+            "rolls": range.rolls,
+        })
+    }
+
+    fn build_json(
+        &mut self,
+        attacker: &Pokemon,
+        defender: &Pokemon,
+        move_: &FormatMove,
+        calculation: &Calculation,
+    ) {
+        let stat_names = stat_calculator::STAT_API_NAMES;
+
+        self.builder.append(json!({
+            "attacker": {
+                "name": attacker.name,
+                "level": self.attacker.level(),
+                stat_names[calculation.attack_stat]: calculation.attack,
+            },
+            "defender": {
+                "name": defender.name,
+                "level": self.defender.level(),
+                "hp": calculation.hp,
+                stat_names[calculation.defense_stat]: calculation.defense,
+            },
+            "move": {
+                "name": move_.move_.name,
+                "type": move_.type_name(),
+                "damage_class": move_.damage_class(),
+                "power": move_.move_.power,
+                "stab": calculation.stab,
+                "effectiveness": calculation.effectiveness.label(),
+            },
+            "damage": {
+                "normal": Self::range_json(&calculation.normal, calculation.hp),
+                "critical": Self::range_json(&calculation.critical, calculation.hp),
+            },
+        }));
+    }
+}
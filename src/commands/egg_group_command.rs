@@ -0,0 +1,109 @@
+use crate::{
+    builder::Builder,
+    client::{self, ClientError, ClientImplementation},
+    formatter,
+    name_matcher::matcher,
+    output_format::OutputFormat,
+};
+
+use itertools::Itertools;
+use rustemon::model::pokemon::EggGroup;
+use serde_json::json;
+
+pub struct EggGroupCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    egg_group_name: String,
+    output_format: OutputFormat,
+}
+
+impl EggGroupCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        egg_group_name: String,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        EggGroupCommand {
+            builder: &mut builder,
+            client,
+            egg_group_name,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let egg_group = match self.fetch_egg_group().await {
+            Ok(egg_group) => egg_group,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        // Species are listed in Pokedex order so evolution lines stay together
+        let species_names = egg_group
+            .pokemon_species
+            .iter()
+            .sorted_by_key(|species| client::resource_id(&species.url))
+            .map(|species| species.name.clone())
+            .collect_vec();
+
+        match self.output_format {
+            OutputFormat::Text => self.build_output(&egg_group.name, &species_names),
+            OutputFormat::Json => self.builder.append(json!({
+                "egg_group": egg_group.name,
+                "pokemon_species": species_names,
+            })),
+        }
+    }
+
+    fn build_output(&mut self, egg_group_name: &str, species_names: &[String]) {
+        self.builder.appendln(format!(
+            "{} {}",
+            formatter::white("Egg Group:"),
+            formatter::format_egg_group_name(egg_group_name)
+        ));
+        self.builder.newline();
+
+        self.builder.appendln(formatter::white(&format!(
+            "Pokemon ({})",
+            species_names.len()
+        )));
+
+        let species_names = species_names
+            .iter()
+            .map(|species_name| formatter::split_and_capitalise(species_name))
+            .collect_vec();
+
+        self.builder
+            .append(formatter::format_columns(&species_names, 4));
+    }
+
+    async fn fetch_egg_group(&self) -> Result<EggGroup, String> {
+        let successful_match =
+            matcher::match_egg_group_name(&self.egg_group_name).map_err(|no_match| no_match.0)?;
+
+        let result = self
+            .client
+            .fetch_egg_group(&successful_match.suggested_name)
+            .await;
+
+        match result {
+            Ok(egg_group) => Ok(egg_group),
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
+                let output = matcher::build_unknown_name(
+                    &successful_match.keyword,
+                    &successful_match.suggested_name,
+                );
+                Err(output)
+            }
+            Err(error) => Err(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{
+    builder::Builder,
+    client::{self, ClientError, ClientImplementation},
+    formatter,
+    name_matcher::matcher,
+    output_format::OutputFormat,
+};
+
+use itertools::Itertools;
+use rustemon::model::pokemon::LocationAreaEncounter;
+use serde_json::json;
+use unicode_width::UnicodeWidthStr;
+
+/// Where a pokemon can be found in one game. Encounter slots which only differ in their level
+/// or chance are merged into a single level range with their chances added together
+struct AreaEncounter {
+    location_area: String,
+    method: String,
+    conditions: Vec<String>,
+    min_level: i64,
+    max_level: i64,
+    chance: i64,
+}
+
+impl AreaEncounter {
+    fn levels(&self) -> String {
+        if self.min_level == self.max_level {
+            format!("Lv. {}", self.min_level)
+        } else {
+            format!("Lv. {}-{}", self.min_level, self.max_level)
+        }
+    }
+
+    fn formatted_conditions(&self) -> String {
+        self.conditions
+            .iter()
+            .map(|condition| formatter::split_and_capitalise(condition))
+            .join(", ")
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "location_area": self.location_area,
+            "method": self.method,
+            "min_level": self.min_level,
+            "max_level": self.max_level,
+            "chance": self.chance,
+            "conditions": self.conditions,
+        })
+    }
+}
+
+struct VersionEncounters {
+    version: String,
+    encounters: Vec<AreaEncounter>,
+}
+
+pub struct EncountersCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    pokemon_name: String,
+    output_format: OutputFormat,
+}
+
+impl EncountersCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        pokemon_name: String,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::new(3000);
+
+        EncountersCommand {
+            builder: &mut builder,
+            client,
+            pokemon_name,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let (pokemon_name, location_area_encounters) = match self.fetch_encounters().await {
+            Ok(encounters) => encounters,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        let versions = Self::group_by_version(&location_area_encounters);
+
+        match self.output_format {
+            OutputFormat::Text => self.build_output(&pokemon_name, &versions),
+            OutputFormat::Json => self.build_json(&pokemon_name, &versions),
+        }
+    }
+
+    async fn fetch_encounters(&self) -> Result<(String, Vec<LocationAreaEncounter>), String> {
+        let successful_match =
+            matcher::match_pokemon_name(&self.pokemon_name).map_err(|no_match| no_match.0)?;
+        let pokemon_name = successful_match.suggested_name;
+
+        let result = self.client.fetch_pokemon_encounters(&pokemon_name).await;
+
+        match result {
+            Ok(encounters) => Ok((pokemon_name, encounters)),
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => Err(
+                matcher::build_unknown_name(&successful_match.keyword, &pokemon_name),
+            ),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    // Versions are ordered by release using the id at the end of their resource url
+    fn group_by_version(
+        location_area_encounters: &[LocationAreaEncounter],
+    ) -> Vec<VersionEncounters> {
+        location_area_encounters
+            .iter()
+            .enumerate()
+            .flat_map(|(area_index, area_encounter)| {
+                area_encounter
+                    .version_details
+                    .iter()
+                    .flat_map(move |version_detail| {
+                        version_detail
+                            .encounter_details
+                            .iter()
+                            .map(move |encounter| {
+                                let conditions = encounter
+                                    .condition_values
+                                    .iter()
+                                    .map(|condition| condition.name.clone())
+                                    .sorted()
+                                    .collect_vec();
+
+                                let area_encounter = AreaEncounter {
+                                    location_area: area_encounter.location_area.name.clone(),
+                                    method: encounter.method.name.clone(),
+                                    conditions,
+                                    min_level: encounter.min_level,
+                                    max_level: encounter.max_level,
+                                    chance: encounter.chance,
+                                };
+
+                                let version = &version_detail.version;
+                                (
+                                    client::resource_id(&version.url),
+                                    &version.name,
+                                    area_index,
+                                    area_encounter,
+                                )
+                            })
+                    })
+            })
+            .sorted_by(|(a_version, _, a_area, a), (b_version, _, b_area, b)| {
+                (a_version, a_area, &a.method, &a.conditions).cmp(&(
+                    b_version,
+                    b_area,
+                    &b.method,
+                    &b.conditions,
+                ))
+            })
+            .chunk_by(|(_, version_name, _, _)| version_name.to_string())
+            .into_iter()
+            .map(|(version, encounters)| VersionEncounters {
+                version,
+                encounters: Self::merge_encounters(encounters.map(|(.., encounter)| encounter)),
+            })
+            .collect_vec()
+    }
+
+    fn merge_encounters(encounters: impl Iterator<Item = AreaEncounter>) -> Vec<AreaEncounter> {
+        let mut merged: Vec<AreaEncounter> = Vec::new();
+
+        for encounter in encounters {
+            match merged.last_mut() {
+                Some(last)
+                    if last.location_area == encounter.location_area
+                        && last.method == encounter.method
+                        && last.conditions == encounter.conditions =>
+                {
+                    last.min_level = last.min_level.min(encounter.min_level);
+                    last.max_level = last.max_level.max(encounter.max_level);
+                    last.chance = (last.chance + encounter.chance).min(100);
+                }
+                _ => merged.push(encounter),
+            }
+        }
+
+        merged
+    }
+
+    fn build_output(&mut self, pokemon_name: &str, versions: &[VersionEncounters]) {
+        let formatted_name = formatter::split_and_capitalise(pokemon_name);
+        self.builder
+            .appendln(format!("{} {formatted_name}", formatter::white("Pokemon:")));
+
+        if versions.is_empty() {
+            self.builder
+                .append(format!("{formatted_name} can't be found in the wild"));
+            return;
+        }
+
+        let rows = versions
+            .iter()
+            .map(|version| {
+                version
+                    .encounters
+                    .iter()
+                    .map(|encounter| {
+                        [
+                            formatter::split_and_capitalise(&encounter.location_area),
+                            formatter::split_and_capitalise(&encounter.method),
+                            encounter.levels(),
+                            format!("{}%", encounter.chance),
+                            encounter.formatted_conditions(),
+                        ]
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        // Every game shares the same column widths so the whole list lines up
+        let widths = rows.iter().flatten().fold([0; 4], |mut widths, row| {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(UnicodeWidthStr::width(cell.as_str()));
+            }
+            widths
+        });
+
+        for (version, rows) in versions.iter().zip(rows) {
+            self.builder.newline();
+            self.builder.appendln(formatter::white(&format!(
+                "{} ({})",
+                formatter::split_and_capitalise(&version.version),
+                rows.len()
+            )));
+
+            // `format!` pads by character count, which doesn't match the printed width
+            let padding =
+                |cell: &str, width: usize| " ".repeat(width - UnicodeWidthStr::width(cell));
+
+            for [location_area, method, levels, chance, conditions] in rows {
+                let line = format!(
+                    "  {location_area}{}  {method}{}  {levels}{}  {}{chance}  {conditions}",
+                    padding(&location_area, widths[0]),
+                    padding(&method, widths[1]),
+                    padding(&levels, widths[2]),
+                    padding(&chance, widths[3]),
+                );
+                self.builder.appendln(line.trim_end());
+            }
+        }
+    }
+
+    fn build_json(&mut self, pokemon_name: &str, versions: &[VersionEncounters]) {
+        let versions = versions
+            .iter()
+            .map(|version| {
+                json!({
+                    "version": version.version,
+                    "encounters": version
+                        .encounters
+                        .iter()
+                        .map(AreaEncounter::to_json)
+                        .collect_vec(),
+                })
+            })
+            .collect_vec();
+
+        self.builder.append(json!({
+            "pokemon": pokemon_name,
+            "versions": versions,
+        }));
+    }
+}
@@ -1,9 +1,11 @@
 use rustemon::model::games::Generation;
+use serde_json::json;
 
 use crate::{
     builder::Builder,
     client::ClientImplementation,
     formatter::{FormatGeneration, FormatModel},
+    output_format::OutputFormat,
 };
 
 pub struct GenerationCommand<'a> {
@@ -13,6 +15,7 @@ pub struct GenerationCommand<'a> {
     show_pokemon: bool,
     show_abilities: bool,
     show_moves: bool,
+    output_format: OutputFormat,
 }
 
 impl GenerationCommand<'_> {
@@ -22,6 +25,7 @@ impl GenerationCommand<'_> {
         show_pokemon: bool,
         show_abilities: bool,
         show_moves: bool,
+        output_format: OutputFormat,
     ) -> Builder {
         let mut builder = Builder::default();
 
@@ -32,6 +36,7 @@ impl GenerationCommand<'_> {
             show_pokemon,
             show_abilities,
             show_moves,
+            output_format,
         }
         ._execute()
         .await;
@@ -43,7 +48,7 @@ impl GenerationCommand<'_> {
         let generation = match self.fetch_generation().await {
             Ok(generation) => generation,
             Err(error) => {
-                self.builder.append(error);
+                self.builder.append_error(error, self.output_format);
                 return;
             }
         };
@@ -55,7 +60,13 @@ impl GenerationCommand<'_> {
             self.show_moves,
         );
 
-        self.builder.append(format_generation.format());
+        match self.output_format {
+            OutputFormat::Text => self.builder.append(format_generation.format()),
+            OutputFormat::Json => {
+                self.builder
+                    .append(json!({ "generation": format_generation.to_json() }));
+            }
+        }
     }
 
     async fn fetch_generation(&self) -> Result<Generation, String> {
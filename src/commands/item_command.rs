@@ -1,17 +1,20 @@
 use crate::{
     builder::Builder,
-    client::ClientImplementation,
+    client::{ClientError, ClientImplementation},
     formatter::{self, FormatItem, FormatModel},
     name_matcher::matcher,
+    output_format::OutputFormat,
 };
 
 use rustemon::model::items::Item;
+use serde_json::json;
 
 pub struct ItemCommand<'a> {
     builder: &'a mut Builder,
     client: &'a dyn ClientImplementation,
     item_name: String,
     verbose: bool,
+    output_format: OutputFormat,
 }
 
 impl ItemCommand<'_> {
@@ -19,6 +22,7 @@ impl ItemCommand<'_> {
         client: &dyn ClientImplementation,
         item_name: String,
         verbose: bool,
+        output_format: OutputFormat,
     ) -> Builder {
         let mut builder = Builder::default();
 
@@ -27,6 +31,7 @@ impl ItemCommand<'_> {
             client,
             item_name,
             verbose,
+            output_format,
         }
         ._execute()
         .await;
@@ -38,14 +43,22 @@ impl ItemCommand<'_> {
         let item = match self.fetch_item().await {
             Ok(item) => item,
             Err(error_message) => {
-                self.builder.append(error_message);
+                self.builder.append_error(error_message, self.output_format);
                 return;
             }
         };
 
         let format_item = FormatItem::new(item).with_verbose(self.verbose);
-        self.builder.appendln(formatter::white("Item"));
-        self.builder.append(format_item.format());
+
+        match self.output_format {
+            OutputFormat::Text => {
+                self.builder.appendln(formatter::white("Item"));
+                self.builder.append(format_item.format());
+            }
+            OutputFormat::Json => self
+                .builder
+                .append(json!({ "item": format_item.to_json() })),
+        }
     }
 
     async fn fetch_item(&self) -> Result<Item, String> {
@@ -59,13 +72,14 @@ impl ItemCommand<'_> {
 
         match result {
             Ok(item) => Ok(item),
-            Err(_) => {
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
                 let output = matcher::build_unknown_name(
                     &successful_match.keyword,
                     &successful_match.suggested_name,
                 );
                 Err(output)
             }
+            Err(error) => Err(error.to_string()),
         }
     }
 }
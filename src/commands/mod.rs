@@ -1,7 +1,21 @@
 pub mod ability_command;
+pub mod berry_command;
+pub mod breed_command;
+pub mod cache_command;
+pub mod cache_warm_command;
+pub mod compare_command;
+pub mod completions_command;
+pub mod coverage_command;
+pub mod damage_command;
+pub mod egg_group_command;
+pub mod encounters_command;
 pub mod generation_command;
 pub mod item_command;
 pub mod move_command;
 pub mod moves_command;
+pub mod nature_command;
 pub mod pokemon_command;
+pub mod search_command;
+pub mod shell_command;
+pub mod team_command;
 pub mod type_command;
@@ -1,19 +1,23 @@
 use crate::{
     builder::Builder,
-    client::ClientImplementation,
+    client::{ClientError, ClientImplementation},
+    fetch_failure::{self, FetchFailure},
     formatter::{self, FormatModel, FormatMove},
     name_matcher::matcher,
+    output_format::OutputFormat,
     type_badge,
 };
 
 use futures::{StreamExt, stream};
 use itertools::{Itertools, any};
 use rustemon::model::{moves::Move, pokemon::Pokemon};
+use serde_json::json;
 use unicode_width::UnicodeWidthStr;
 
 #[derive(Ord, PartialOrd, Eq, PartialEq)]
 struct FormattedPokemon {
     name: String,
+    pokemon_name: String,
     types: Vec<String>,
     formatted_type: String,
     type_visual_width: usize,
@@ -23,6 +27,13 @@ impl FormattedPokemon {
     fn name_visual_width(&self) -> usize {
         UnicodeWidthStr::width(self.name.as_str())
     }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "name": self.pokemon_name,
+            "types": self.types,
+        })
+    }
 }
 
 impl From<Pokemon> for FormattedPokemon {
@@ -54,6 +65,7 @@ impl From<Pokemon> for FormattedPokemon {
 
         Self {
             name,
+            pokemon_name,
             types,
             formatted_type,
             type_visual_width,
@@ -67,6 +79,8 @@ pub struct MoveCommand<'a> {
     move_name: String,
     include_learned_by: bool,
     types: Option<Vec<String>>,
+    version_group: Option<String>,
+    output_format: OutputFormat,
 }
 
 impl MoveCommand<'_> {
@@ -75,6 +89,8 @@ impl MoveCommand<'_> {
         move_name: String,
         include_learned_by: bool,
         types: Option<Vec<String>>,
+        version_group: Option<String>,
+        output_format: OutputFormat,
     ) -> Builder {
         let mut builder = if include_learned_by {
             Builder::new(3000)
@@ -88,6 +104,8 @@ impl MoveCommand<'_> {
             move_name,
             include_learned_by,
             types,
+            version_group,
+            output_format,
         }
         ._execute()
         .await;
@@ -96,22 +114,72 @@ impl MoveCommand<'_> {
     }
 
     async fn _execute(&mut self) {
+        if let Err(error_message) = self.correct_version_group() {
+            self.builder.append_error(error_message, self.output_format);
+            return;
+        }
+
         let move_ = match self.fetch_move().await {
             Ok(move_) => move_,
             Err(error_message) => {
-                self.builder.append(error_message);
+                self.builder.append_error(error_message, self.output_format);
                 return;
             }
         };
 
-        let mut format_move = FormatMove::new(move_);
+        let format_move = FormatMove::new(move_);
+
+        let (pokemon_list, failures) = if self.include_learned_by {
+            let (pokemon_list, failures) = self.learned_by_pokemon(&format_move).await;
+            (Some(pokemon_list), failures)
+        } else {
+            (None, Vec::new())
+        };
+
+        match self.output_format {
+            OutputFormat::Text => self.build_output(&format_move, pokemon_list, &failures),
+            OutputFormat::Json => self.build_json(&format_move, pokemon_list, &failures),
+        }
+    }
 
+    fn build_output(
+        &mut self,
+        format_move: &FormatMove,
+        pokemon_list: Option<Vec<FormattedPokemon>>,
+        failures: &[FetchFailure],
+    ) {
         self.builder.appendln(formatter::white("Move"));
         self.builder.append(format_move.format());
 
-        if self.include_learned_by {
-            self.build_learned_by(&mut format_move).await;
+        if let Some(pokemon_list) = pokemon_list {
+            self.build_learned_by(&pokemon_list);
         }
+
+        fetch_failure::append_failures(self.builder, failures);
+    }
+
+    fn build_json(
+        &mut self,
+        format_move: &FormatMove,
+        pokemon_list: Option<Vec<FormattedPokemon>>,
+        failures: &[FetchFailure],
+    ) {
+        let mut json = json!({ "move": format_move.to_json() });
+
+        if let Some(pokemon_list) = pokemon_list {
+            json["learned_by"] = json!(
+                pokemon_list
+                    .iter()
+                    .map(FormattedPokemon::to_json)
+                    .collect_vec()
+            );
+        }
+
+        if !failures.is_empty() {
+            json["failed"] = fetch_failure::failures_json(failures);
+        }
+
+        self.builder.append(json);
     }
 
     async fn fetch_move(&self) -> Result<Move, String> {
@@ -125,20 +193,36 @@ impl MoveCommand<'_> {
 
         match result {
             Ok(move_) => Ok(move_),
-            Err(_) => {
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
                 let output = matcher::build_unknown_name(
                     &successful_match.keyword,
                     &successful_match.suggested_name,
                 );
                 Err(output)
             }
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    fn correct_version_group(&mut self) -> Result<(), String> {
+        if let Some(version_group) = &self.version_group {
+            let successful_match =
+                matcher::match_version_group_name(version_group).map_err(|no_match| no_match.0)?;
+            self.version_group = Some(successful_match.suggested_name);
         }
+
+        Ok(())
     }
 
-    async fn build_learned_by(&mut self, format_move: &mut FormatMove) {
+    async fn learned_by_pokemon(
+        &self,
+        format_move: &FormatMove,
+    ) -> (Vec<FormattedPokemon>, Vec<FetchFailure>) {
         let pokemon_names = self.pokemon_names(format_move);
         let corrected_types = self.corrected_types();
-        let mut pokemon_list = self.fetch_formatted_pokemon(&pokemon_names).await;
+        let (mut pokemon_list, failures) = self
+            .fetch_formatted_pokemon(&format_move.move_.name, &pokemon_names)
+            .await;
 
         if let Some(corrected_types) = &corrected_types {
             pokemon_list.retain(|pokemon| {
@@ -149,13 +233,16 @@ impl MoveCommand<'_> {
         }
 
         pokemon_list.sort();
+        (pokemon_list, failures)
+    }
 
+    fn build_learned_by(&mut self, pokemon_list: &[FormattedPokemon]) {
         self.builder.newline();
 
         let header = formatter::white(&format!("Learned by: ({})", pokemon_list.len()));
         self.builder.appendln(header);
         self.builder
-            .append(Self::format_learned_by_columns(&pokemon_list));
+            .append(Self::format_learned_by_columns(pokemon_list));
     }
 
     fn format_learned_by_columns(pokemon_list: &[FormattedPokemon]) -> String {
@@ -239,15 +326,44 @@ impl MoveCommand<'_> {
         }
     }
 
-    async fn fetch_formatted_pokemon(&self, pokemon_names: &Vec<String>) -> Vec<FormattedPokemon> {
+    async fn fetch_formatted_pokemon(
+        &self,
+        move_name: &str,
+        pokemon_names: &Vec<String>,
+    ) -> (Vec<FormattedPokemon>, Vec<FetchFailure>) {
         let client_ref = &self.client;
-        stream::iter(pokemon_names)
+        let (pokemon_list, failures) = stream::iter(pokemon_names)
             .map(|pokemon_name| async move {
-                let pokemon = client_ref.fetch_pokemon(pokemon_name).await.unwrap();
-                FormattedPokemon::from(pokemon)
+                let pokemon = client_ref
+                    .fetch_pokemon(pokemon_name)
+                    .await
+                    .map_err(|error| FetchFailure::new("pokemon", pokemon_name, error))?;
+
+                Ok(self
+                    .learns_in_version_group(&pokemon, move_name)
+                    .then(|| FormattedPokemon::from(pokemon)))
             })
             .buffer_unordered(50)
             .collect::<Vec<_>>()
             .await
+            .into_iter()
+            .partition_result::<Vec<_>, Vec<_>, _, _>();
+
+        (pokemon_list.into_iter().flatten().collect(), failures)
+    }
+
+    // `learned_by_pokemon` covers every game, so a version group is checked against the
+    // pokemon's own move list
+    fn learns_in_version_group(&self, pokemon: &Pokemon, move_name: &str) -> bool {
+        let Some(version_group) = &self.version_group else {
+            return true;
+        };
+
+        pokemon
+            .moves
+            .iter()
+            .filter(|pokemon_move| pokemon_move.move_.name == move_name)
+            .flat_map(|pokemon_move| &pokemon_move.version_group_details)
+            .any(|details| &details.version_group.name == version_group)
     }
 }
@@ -1,24 +1,144 @@
 use crate::{
     builder::Builder,
-    client::ClientImplementation,
+    client::{ClientError, ClientImplementation},
+    fetch_failure::{self, FetchFailure},
     formatter,
     formatter::{FormatModel, FormatMove},
     name_matcher::matcher,
+    output_format::OutputFormat,
 };
 
+use std::cmp::Ordering;
+
+use clap::ValueEnum;
 use itertools::Itertools;
-use rustemon::model::pokemon::{Pokemon, PokemonMove};
+use rustemon::model::pokemon::{Pokemon, PokemonMove, PokemonMoveVersion};
+use serde_json::{Map, json};
 
 use futures::{StreamExt, stream};
 
 const BUILDER_CAPACITY: usize = 50000;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MoveGrouping {
+    LearnMethod,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MoveSort {
+    Power,
+    Accuracy,
+    Pp,
+    Priority,
+    Name,
+    Level,
+    Type,
+}
+
+impl MoveSort {
+    fn compare(&self, a: &FormatMove, b: &FormatMove) -> Ordering {
+        match self {
+            MoveSort::Power => a.move_.power.cmp(&b.move_.power),
+            // Moves without an accuracy can't miss
+            MoveSort::Accuracy => a
+                .move_
+                .accuracy
+                .unwrap_or(i64::MAX)
+                .cmp(&b.move_.accuracy.unwrap_or(i64::MAX)),
+            MoveSort::Pp => a.move_.pp.cmp(&b.move_.pp),
+            MoveSort::Priority => a.move_.priority.cmp(&b.move_.priority),
+            MoveSort::Name => a.move_.name.cmp(&b.move_.name),
+            // Only level-up moves have a level, so every other move follows them
+            MoveSort::Level => Self::level_key(a).cmp(&Self::level_key(b)),
+            MoveSort::Type => a.type_name().cmp(b.type_name()),
+        }
+    }
+
+    fn level_key(format_move: &FormatMove) -> (bool, Option<i64>) {
+        (
+            format_move.learn_method() != Some("level-up"),
+            format_move.level_learned_at(),
+        )
+    }
+
+    // Numbers where bigger is better read best first, everything else reads like a list
+    fn is_descending(&self) -> bool {
+        matches!(
+            self,
+            MoveSort::Power | MoveSort::Accuracy | MoveSort::Pp | MoveSort::Priority
+        )
+    }
+}
+
+/// How the moves are laid out once they've been fetched and filtered. Without a sort moves are
+/// listed by power, apart from grouped level-up moves which are listed in the order they're
+/// learned. When grouping the limit applies to each group
+#[derive(Clone, Copy, Debug)]
+pub struct MoveListOptions {
+    pub group_by: Option<MoveGrouping>,
+    pub sort: Option<MoveSort>,
+    pub reverse: bool,
+    pub limit: Option<usize>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LearnMethodGroup {
+    LevelUp,
+    Machine,
+    Egg,
+    Tutor,
+    Other,
+}
+
+impl LearnMethodGroup {
+    const ALL: &[LearnMethodGroup] = &[
+        LearnMethodGroup::LevelUp,
+        LearnMethodGroup::Machine,
+        LearnMethodGroup::Egg,
+        LearnMethodGroup::Tutor,
+        LearnMethodGroup::Other,
+    ];
+
+    fn from_learn_method(learn_method: Option<&str>) -> Self {
+        match learn_method {
+            Some("level-up") => LearnMethodGroup::LevelUp,
+            Some("machine") => LearnMethodGroup::Machine,
+            Some("egg") => LearnMethodGroup::Egg,
+            Some("tutor") => LearnMethodGroup::Tutor,
+            _ => LearnMethodGroup::Other,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            LearnMethodGroup::LevelUp => "Level-up",
+            LearnMethodGroup::Machine => "TM/Machine",
+            LearnMethodGroup::Egg => "Egg",
+            LearnMethodGroup::Tutor => "Tutor",
+            LearnMethodGroup::Other => "Other",
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            LearnMethodGroup::LevelUp => "level-up",
+            LearnMethodGroup::Machine => "machine",
+            LearnMethodGroup::Egg => "egg",
+            LearnMethodGroup::Tutor => "tutor",
+            LearnMethodGroup::Other => "other",
+        }
+    }
+}
+
 pub struct MovesCommand<'a> {
     builder: &'a mut Builder,
     client: &'a dyn ClientImplementation,
     pokemon_name: String,
     type_names: Option<Vec<String>>,
     categories: Option<Vec<String>>,
+    version_group: Option<String>,
+    list_options: MoveListOptions,
+    output_format: OutputFormat,
 }
 
 impl MovesCommand<'_> {
@@ -27,6 +147,9 @@ impl MovesCommand<'_> {
         pokemon_name: String,
         type_names: Option<Vec<String>>,
         categories: Option<Vec<String>>,
+        version_group: Option<String>,
+        list_options: MoveListOptions,
+        output_format: OutputFormat,
     ) -> Builder {
         let mut builder = Builder::new(BUILDER_CAPACITY);
 
@@ -36,6 +159,9 @@ impl MovesCommand<'_> {
             pokemon_name,
             type_names,
             categories,
+            version_group,
+            list_options,
+            output_format,
         }
         ._execute()
         .await;
@@ -44,17 +170,37 @@ impl MovesCommand<'_> {
     }
 
     async fn _execute(&mut self) {
+        if let Err(error_message) = self.correct_version_group() {
+            self.builder.append_error(error_message, self.output_format);
+            return;
+        }
+
         let pokemon = match self.fetch_pokemon().await {
             Ok(pokemon) => pokemon,
             Err(error_message) => {
-                self.builder.append(error_message);
+                self.builder.append_error(error_message, self.output_format);
                 return;
             }
         };
 
-        let moves = self.process_moves(self.fetch_moves(pokemon.moves).await);
-        let move_output = self.build_output(&moves);
-        let pokemon_name = formatter::capitalise(&pokemon.name);
+        let (moves, failures) = self.fetch_moves(pokemon.moves).await;
+        let moves = self.process_moves(moves);
+
+        if self.output_format.is_json() {
+            self.build_json(&pokemon.name, &moves, &failures);
+            return;
+        }
+
+        self.build_text_output(&pokemon.name, &moves, failures.is_empty());
+        fetch_failure::append_failures(self.builder, &failures);
+    }
+
+    fn build_text_output(&mut self, pokemon_name: &str, moves: &[FormatMove], all_loaded: bool) {
+        let move_output = match self.list_options.group_by {
+            Some(MoveGrouping::LearnMethod) => self.build_grouped_output(moves),
+            None => self.build_output(moves),
+        };
+        let pokemon_name = formatter::capitalise(pokemon_name);
 
         if let Some(type_names) = &self.type_names {
             // move_output can be empty only if a type_name filter is passed and there are no moves of that type
@@ -74,11 +220,29 @@ impl MovesCommand<'_> {
             }
         };
 
+        if let Some(version_group) = &self.version_group
+            && moves.is_empty()
+            && all_loaded
+        {
+            self.builder.append(format!(
+                "{pokemon_name} doesn't learn any moves in {}",
+                formatter::split_and_capitalise(version_group)
+            ));
+
+            return;
+        }
+
         self.builder
             .appendln(format!("{} {pokemon_name}", formatter::white("Pokemon:")));
 
+        // Grouped moves can be listed once for each way they're learned
+        let move_count = moves
+            .iter()
+            .unique_by(|format_move| &format_move.move_.name)
+            .count();
+
         self.builder
-            .appendln(formatter::white(&format!("Moves: ({})", moves.len())));
+            .appendln(formatter::white(&format!("Moves: ({move_count})")));
         self.builder.append(move_output);
     }
 
@@ -93,42 +257,140 @@ impl MovesCommand<'_> {
 
         match result {
             Ok(pokemon) => Ok(pokemon),
-            Err(_) => {
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
                 let output = matcher::build_unknown_name(
                     &successful_match.keyword,
                     &successful_match.suggested_name,
                 );
                 Err(output)
             }
+            Err(error) => Err(error.to_string()),
         }
     }
 
-    async fn fetch_moves(&self, pokemon_moves: Vec<PokemonMove>) -> Vec<FormatMove> {
-        stream::iter(pokemon_moves)
-            .map(|pokemon_move| async move {
-                let version_group_details = pokemon_move.version_group_details.last();
+    fn correct_version_group(&mut self) -> Result<(), String> {
+        if let Some(version_group) = &self.version_group {
+            let successful_match =
+                matcher::match_version_group_name(version_group).map_err(|no_match| no_match.0)?;
+            self.version_group = Some(successful_match.suggested_name);
+        }
 
-                let move_learn_method = if let Some(version_group_details) = version_group_details {
-                    self.client
-                        .fetch_move_learn_method(&version_group_details.move_learn_method.name)
-                        .await
-                        .ok()
-                } else {
-                    None
-                };
+        Ok(())
+    }
+
+    // Without a version group the most recent game a move can be learned in is used. A move can
+    // be learned several ways in one game, so level up is preferred as it's the only way with a
+    // level to show
+    fn version_group_details<'a>(
+        &self,
+        pokemon_move: &'a PokemonMove,
+    ) -> Option<&'a PokemonMoveVersion> {
+        let version_group = match &self.version_group {
+            Some(version_group) => version_group,
+            None => {
+                &pokemon_move
+                    .version_group_details
+                    .last()?
+                    .version_group
+                    .name
+            }
+        };
+
+        let mut details = pokemon_move
+            .version_group_details
+            .iter()
+            .filter(|details| &details.version_group.name == version_group);
+
+        details
+            .clone()
+            .find(|details| details.move_learn_method.name == "level-up")
+            .or_else(|| details.next())
+    }
+
+    // A move can be learned several ways in one game, e.g. by level up and by TM, which only
+    // matters when grouping by learn method
+    fn learn_details<'a>(&self, pokemon_move: &'a PokemonMove) -> Vec<&'a PokemonMoveVersion> {
+        let Some(details) = self.version_group_details(pokemon_move) else {
+            return Vec::new();
+        };
 
-                // TODO: Gracecfully filter out failed requests for a move
+        if self.list_options.group_by.is_none() {
+            return vec![details];
+        }
+
+        pokemon_move
+            .version_group_details
+            .iter()
+            .filter(|other| other.version_group.name == details.version_group.name)
+            .unique_by(|other| &other.move_learn_method.name)
+            .collect_vec()
+    }
+
+    async fn fetch_moves(
+        &self,
+        pokemon_moves: Vec<PokemonMove>,
+    ) -> (Vec<FormatMove>, Vec<FetchFailure>) {
+        let pokemon_moves = pokemon_moves.into_iter().filter(|pokemon_move| {
+            self.version_group.is_none() || self.version_group_details(pokemon_move).is_some()
+        });
+
+        let (moves, mut failures) = stream::iter(pokemon_moves)
+            .map(|pokemon_move| async move {
+                let move_name = &pokemon_move.move_.name;
                 let move_ = self
                     .client
-                    .fetch_move(&pokemon_move.move_.name)
+                    .fetch_move(move_name)
                     .await
-                    .unwrap();
-
-                FormatMove::with_maybe_details(move_, move_learn_method, version_group_details)
+                    .map_err(|error| FetchFailure::new("move", move_name, error))?;
+
+                let learn_details = self.learn_details(&pokemon_move);
+                if learn_details.is_empty() {
+                    return Ok((vec![FormatMove::new(move_)], Vec::new()));
+                }
+
+                let mut format_moves = Vec::with_capacity(learn_details.len());
+                let mut learn_method_failures = Vec::new();
+                for details in learn_details {
+                    let learn_method_name = &details.move_learn_method.name;
+                    let move_learn_method = self
+                        .client
+                        .fetch_move_learn_method(learn_method_name)
+                        .await
+                        .map_err(|error| {
+                            learn_method_failures.push(FetchFailure::new(
+                                "move-learn-method",
+                                learn_method_name,
+                                error,
+                            ));
+                        })
+                        .ok();
+
+                    format_moves.push(FormatMove::with_details(
+                        move_.clone(),
+                        details,
+                        move_learn_method,
+                    ));
+                }
+
+                Ok((format_moves, learn_method_failures))
             })
             .buffer_unordered(100)
             .collect::<Vec<_>>()
             .await
+            .into_iter()
+            .partition_result::<Vec<_>, Vec<_>, _, _>();
+
+        let (moves, learn_method_failures): (Vec<_>, Vec<_>) = moves.into_iter().unzip();
+
+        // Every move learned the same way shares the learn method, so it's only listed once
+        failures.extend(
+            learn_method_failures
+                .into_iter()
+                .flatten()
+                .unique_by(|failure| failure.name.clone()),
+        );
+
+        (moves.into_iter().flatten().collect(), failures)
     }
 
     fn process_moves(&self, moves: Vec<FormatMove>) -> Vec<FormatMove> {
@@ -188,18 +450,120 @@ impl MovesCommand<'_> {
             None => processed_moves,
         };
 
-        processed_moves.sort_by_key(|format_move| format_move.move_.power);
-        processed_moves.reverse();
+        let MoveListOptions {
+            group_by,
+            sort,
+            reverse,
+            limit,
+        } = self.list_options;
+        let sort = sort.unwrap_or(MoveSort::Power);
+
+        processed_moves.sort_by(|a, b| sort.compare(a, b));
+        if sort.is_descending() != reverse {
+            processed_moves.reverse();
+        }
+
+        if let (Some(limit), None) = (limit, group_by) {
+            processed_moves.truncate(limit);
+        }
 
         processed_moves
     }
 
-    fn build_output(&self, moves: &[FormatMove]) -> String {
-        moves.iter().fold(String::new(), |mut output, format_move| {
-            output.push_str(&format_move.format());
-            output.push('\n');
+    fn build_output<'a>(&self, moves: impl IntoIterator<Item = &'a FormatMove>) -> String {
+        moves
+            .into_iter()
+            .fold(String::new(), |mut output, format_move| {
+                output.push_str(&format_move.format());
+                output.push('\n');
+
+                output
+            })
+    }
+
+    fn build_grouped_output(&self, moves: &[FormatMove]) -> String {
+        self.group_by_learn_method(moves).into_iter().fold(
+            String::new(),
+            |mut output, (group, group_moves)| {
+                if group_moves.is_empty() {
+                    return output;
+                }
+
+                let header = format!("{} ({})", group.label(), group_moves.len());
+                output.push_str(&formatter::white(&header));
+                output.push('\n');
+                output.push_str(&self.build_output(group_moves));
+
+                output
+            },
+        )
+    }
+
+    // Keeps the existing order within each group apart from level-up moves, which are listed in
+    // the order they're learned unless a sort was asked for
+    fn group_by_learn_method<'a>(
+        &self,
+        moves: &'a [FormatMove],
+    ) -> Vec<(LearnMethodGroup, Vec<&'a FormatMove>)> {
+        LearnMethodGroup::ALL
+            .iter()
+            .map(|group| {
+                let mut group_moves = moves
+                    .iter()
+                    .filter(|format_move| {
+                        LearnMethodGroup::from_learn_method(format_move.learn_method()) == *group
+                    })
+                    .collect_vec();
+
+                if *group == LearnMethodGroup::LevelUp && self.list_options.sort.is_none() {
+                    group_moves.sort_by(|a, b| MoveSort::Level.compare(a, b));
+                    if self.list_options.reverse {
+                        group_moves.reverse();
+                    }
+                }
+
+                if let Some(limit) = self.list_options.limit {
+                    group_moves.truncate(limit);
+                }
+
+                (*group, group_moves)
+            })
+            .collect_vec()
+    }
+
+    fn build_json(&mut self, pokemon_name: &str, moves: &[FormatMove], failures: &[FetchFailure]) {
+        let moves_json = match self.list_options.group_by {
+            Some(MoveGrouping::LearnMethod) => {
+                let groups = self
+                    .group_by_learn_method(moves)
+                    .into_iter()
+                    .map(|(group, group_moves)| {
+                        let group_json = group_moves
+                            .iter()
+                            .map(|format_move| format_move.to_json())
+                            .collect_vec();
+                        (group.key().to_owned(), json!(group_json))
+                    })
+                    .collect::<Map<_, _>>();
+
+                serde_json::Value::Object(groups)
+            }
+            None => json!(moves.iter().map(FormatMove::to_json).collect_vec()),
+        };
+
+        let mut json = json!({
+            "pokemon": pokemon_name,
+            "moves": moves_json,
+        });
+
+        if let Some(version_group) = &self.version_group {
+            json["version_group"] = json!(version_group);
+        }
+
+        if !failures.is_empty() {
+            json["failed"] = fetch_failure::failures_json(failures);
+        }
 
-            output
-        })
+        self.builder.append(json);
     }
 }
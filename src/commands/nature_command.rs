@@ -0,0 +1,227 @@
+use crate::{
+    builder::Builder,
+    client::{ClientError, ClientImplementation},
+    formatter,
+    name_matcher::{matcher, nature_names::NATURE_NAMES},
+    output_format::OutputFormat,
+};
+
+use futures::{StreamExt, TryStreamExt, stream};
+use itertools::Itertools;
+use rustemon::model::pokemon::Nature;
+use serde_json::json;
+use unicode_width::UnicodeWidthStr;
+
+const NEUTRAL: &str = "-";
+const TABLE_HEADERS: [&str; 5] = ["Nature", "Increased", "Decreased", "Likes", "Dislikes"];
+
+struct NatureRow {
+    name: String,
+    increased: Option<String>,
+    decreased: Option<String>,
+    likes: Option<String>,
+    dislikes: Option<String>,
+}
+
+impl NatureRow {
+    fn cells(&self) -> [String; 5] {
+        let format = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map_or_else(|| String::from(NEUTRAL), formatter::split_and_capitalise)
+        };
+
+        [
+            formatter::capitalise(&self.name),
+            format(&self.increased),
+            format(&self.decreased),
+            format(&self.likes),
+            format(&self.dislikes),
+        ]
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "name": self.name,
+            "increased_stat": self.increased,
+            "decreased_stat": self.decreased,
+            "likes_flavor": self.likes,
+            "hates_flavor": self.dislikes,
+        })
+    }
+}
+
+impl From<Nature> for NatureRow {
+    fn from(nature: Nature) -> Self {
+        Self {
+            name: nature.name,
+            increased: nature.increased_stat.map(|stat| stat.name),
+            decreased: nature.decreased_stat.map(|stat| stat.name),
+            likes: nature.likes_flavor.map(|flavor| flavor.name),
+            dislikes: nature.hates_flavor.map(|flavor| flavor.name),
+        }
+    }
+}
+
+pub struct NatureCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    nature_name: Option<String>,
+    output_format: OutputFormat,
+}
+
+impl NatureCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        nature_name: Option<String>,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        NatureCommand {
+            builder: &mut builder,
+            client,
+            nature_name,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let result = match self.nature_name.clone() {
+            Some(nature_name) => Self::fetch_nature(self.client, &nature_name)
+                .await
+                .map(|nature| vec![nature]),
+            None => self.fetch_all_natures().await,
+        };
+
+        let natures = match result {
+            Ok(natures) => natures.into_iter().map(NatureRow::from).collect_vec(),
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        match (self.output_format, natures.as_slice()) {
+            (OutputFormat::Json, [nature]) if self.nature_name.is_some() => {
+                self.builder.append(json!({ "nature": nature.to_json() }));
+            }
+            (OutputFormat::Json, _) => {
+                let natures = natures.iter().map(NatureRow::to_json).collect_vec();
+                self.builder.append(json!({ "natures": natures }));
+            }
+            (OutputFormat::Text, [nature]) if self.nature_name.is_some() => {
+                self.build_nature_output(nature)
+            }
+            (OutputFormat::Text, _) => self.build_table_output(&natures),
+        }
+    }
+
+    /// Also used to look up `--nature` for the commands which work out actual stats
+    pub(crate) async fn fetch_nature(
+        client: &dyn ClientImplementation,
+        nature_name: &str,
+    ) -> Result<Nature, String> {
+        let successful_match =
+            matcher::match_nature_name(nature_name).map_err(|no_match| no_match.0)?;
+
+        let result = client.fetch_nature(&successful_match.suggested_name).await;
+
+        match result {
+            Ok(nature) => Ok(nature),
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
+                let output = matcher::build_unknown_name(
+                    &successful_match.keyword,
+                    &successful_match.suggested_name,
+                );
+                Err(output)
+            }
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    async fn fetch_all_natures(&self) -> Result<Vec<Nature>, String> {
+        stream::iter(NATURE_NAMES.iter())
+            .map(|nature_name| self.client.fetch_nature(nature_name))
+            .buffered(8)
+            .try_collect()
+            .await
+            .map_err(|error: ClientError| error.to_string())
+    }
+
+    fn build_nature_output(&mut self, nature: &NatureRow) {
+        let [name, increased, decreased, likes, dislikes] = nature.cells();
+
+        self.builder.appendln(formatter::white("Nature"));
+        self.builder
+            .append(formatter::formatln(&formatter::white("Name"), &name));
+        self.builder.append(formatter::formatln(
+            &formatter::white("Increased"),
+            &Self::with_modifier(&increased, "+10%"),
+        ));
+        self.builder.append(formatter::formatln(
+            &formatter::white("Decreased"),
+            &Self::with_modifier(&decreased, "-10%"),
+        ));
+        self.builder
+            .append(formatter::formatln(&formatter::white("Likes"), &likes));
+        self.builder.append(formatter::formatln(
+            &formatter::white("Dislikes"),
+            &dislikes,
+        ));
+        self.builder.pop();
+    }
+
+    fn with_modifier(stat: &str, modifier: &str) -> String {
+        if stat == NEUTRAL {
+            String::from(NEUTRAL)
+        } else {
+            format!("{stat} ({modifier})")
+        }
+    }
+
+    fn build_table_output(&mut self, natures: &[NatureRow]) {
+        let rows = natures.iter().map(NatureRow::cells).collect_vec();
+
+        let column_widths: [usize; 5] = std::array::from_fn(|column| {
+            rows.iter()
+                .map(|row| UnicodeWidthStr::width(row[column].as_str()))
+                .chain([TABLE_HEADERS[column].len()])
+                .max()
+                .unwrap_or(0)
+        });
+
+        let format_row = |cells: [&str; 5]| {
+            cells
+                .iter()
+                .zip(column_widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .join("  ")
+                .trim_end()
+                .to_owned()
+        };
+
+        self.builder.appendln(formatter::white(&format!(
+            "  {}",
+            format_row(TABLE_HEADERS)
+        )));
+
+        for row in &rows {
+            let cells = row.each_ref().map(String::as_str);
+            let line = format!("  {}", format_row(cells));
+
+            // Neutral natures don't change any stats, so they're highlighted to stand apart
+            if row[1] == NEUTRAL {
+                self.builder.appendln(formatter::yellow(&line));
+            } else {
+                self.builder.appendln(line);
+            }
+        }
+
+        self.builder.pop();
+    }
+}
@@ -1,9 +1,16 @@
 use crate::{
     builder::Builder,
     client::ClientImplementation,
-    commands::type_command::TypeCommand,
+    commands::{
+        nature_command::NatureCommand,
+        type_command::{TypeChart, TypeCommand},
+    },
+    fetch_failure::{self, FetchFailure},
     formatter::{self, FormatAbility, FormatModel, FormatPokemon},
     name_matcher::matcher,
+    output_format::OutputFormat,
+    stat_calculator::{self, Nature, STAT_API_NAMES, StatOptions, StatSpread},
+    type_badge,
 };
 
 use futures::{StreamExt, stream};
@@ -14,8 +21,9 @@ use rustemon::model::{
     evolution::{ChainLink, EvolutionChain, EvolutionDetail},
     pokemon::{Pokemon, PokemonSpecies},
 };
+use serde_json::{Map, json};
 
-static STAT_NAMES: &[&str] = &[
+pub(crate) static STAT_NAMES: &[&str] = &[
     "HP",
     "Attack",
     "Defense",
@@ -62,6 +70,29 @@ impl NormalisedEvolutionDetail {
             _ => String::from("Attack = Defense"),
         }
     }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "trigger": self.trigger,
+            "item": self.item,
+            "gender": self.gender,
+            "held_item": self.held_item,
+            "known_move": self.known_move,
+            "known_move_type": self.known_move_type,
+            "location": self.location,
+            "min_level": self.min_level,
+            "min_happiness": self.min_happiness,
+            "min_beauty": self.min_beauty,
+            "min_affection": self.min_affection,
+            "needs_overworld_rain": self.needs_overworld_rain,
+            "party_species": self.party_species,
+            "party_type": self.party_type,
+            "relative_physical_stats": self.relative_physical_stats,
+            "time_of_day": self.time_of_day,
+            "trade_species": self.trade_species,
+            "turn_upside_down": self.turn_upside_down,
+        })
+    }
 }
 
 impl From<&EvolutionDetail> for NormalisedEvolutionDetail {
@@ -111,6 +142,24 @@ struct NormalisedEvolutionChain {
     children: Vec<NormalisedEvolutionChain>,
 }
 
+impl NormalisedEvolutionChain {
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "name": self.name,
+            "evolution_details": self
+                .evolution_details
+                .iter()
+                .map(NormalisedEvolutionDetail::to_json)
+                .collect_vec(),
+            "evolves_to": self
+                .children
+                .iter()
+                .map(NormalisedEvolutionChain::to_json)
+                .collect_vec(),
+        })
+    }
+}
+
 impl From<&ChainLink> for NormalisedEvolutionChain {
     fn from(chain: &ChainLink) -> Self {
         let children = chain.evolves_to.iter().map(Self::from).collect();
@@ -127,20 +176,105 @@ impl From<&ChainLink> for NormalisedEvolutionChain {
     }
 }
 
+/// Another variety of the same species, along with how its base stats differ from the pokemon
+/// being looked at
+struct PokemonForm {
+    pokemon: Pokemon,
+    stat_changes: StatSpread,
+}
+
+impl PokemonForm {
+    fn new(pokemon: Pokemon, compared_to: &Pokemon) -> Self {
+        let base_stats = stat_calculator::base_stats(compared_to);
+        let form_stats = stat_calculator::base_stats(&pokemon);
+        let stat_changes = std::array::from_fn(|stat| form_stats[stat] - base_stats[stat]);
+
+        Self {
+            pokemon,
+            stat_changes,
+        }
+    }
+
+    fn type_names(&self) -> Vec<&str> {
+        self.pokemon
+            .types
+            .iter()
+            .map(|pokemon_type| pokemon_type.type_.name.as_str())
+            .collect_vec()
+    }
+
+    fn formatted_stat_changes(&self) -> String {
+        let total: i64 = self.stat_changes.iter().sum();
+        let changes = STAT_NAMES
+            .iter()
+            .zip(self.stat_changes)
+            .filter(|(_, change)| *change != 0)
+            .map(|(stat_name, change)| format!("{stat_name} {change:+}"))
+            .collect_vec();
+
+        if changes.is_empty() {
+            return String::from("None");
+        }
+
+        format!("{} (Total {total:+})", changes.join(" | "))
+    }
+
+    fn format(&self) -> String {
+        let mut output = String::new();
+
+        let joined_types = self
+            .type_names()
+            .into_iter()
+            .map(type_badge::fetch)
+            .join(" | ");
+
+        output.push_str(&formatter::formatln(
+            &formatter::white("Name"),
+            &formatter::split_and_capitalise(&self.pokemon.name),
+        ));
+        output.push_str(&formatter::formatln(
+            &formatter::white("Type"),
+            &joined_types,
+        ));
+        output.push_str(&formatter::formatln(
+            &formatter::white("Stat Changes"),
+            &self.formatted_stat_changes(),
+        ));
+
+        output
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "name": self.pokemon.name,
+            "types": self.type_names(),
+            "stat_changes": stat_calculator::stat_spread_json(&self.stat_changes),
+        })
+    }
+}
+
 pub struct PokemonCommand<'a> {
     builder: &'a mut Builder,
     client: &'a dyn ClientImplementation,
     pokemon_name: String,
-    show_types: bool,
+    type_chart: Option<TypeChart>,
     show_evolution: bool,
+    show_forms: bool,
+    stat_options: Option<StatOptions>,
+    // The nature from `stat_options`, once it's been fetched
+    nature: Option<Nature>,
+    output_format: OutputFormat,
 }
 
 impl PokemonCommand<'_> {
     pub async fn execute(
         client: &dyn ClientImplementation,
         pokemon_name: String,
-        show_types: bool,
+        type_chart: Option<TypeChart>,
         show_evolution: bool,
+        show_forms: bool,
+        stat_options: Option<StatOptions>,
+        output_format: OutputFormat,
     ) -> Builder {
         let mut builder = Builder::default();
 
@@ -148,8 +282,12 @@ impl PokemonCommand<'_> {
             builder: &mut builder,
             client,
             pokemon_name,
-            show_types,
+            type_chart,
             show_evolution,
+            show_forms,
+            stat_options,
+            nature: None,
+            output_format,
         }
         ._execute()
         .await;
@@ -161,7 +299,7 @@ impl PokemonCommand<'_> {
         let pokemon = match self.fetch_pokemon().await {
             Ok(pokemon) => pokemon,
             Err(error_message) => {
-                self.builder.append(error_message);
+                self.builder.append_error(error_message, self.output_format);
                 return;
             }
         };
@@ -170,7 +308,15 @@ impl PokemonCommand<'_> {
         let species = match self.fetch_pokemon_species(species_name).await {
             Ok(species) => species,
             Err(error_message) => {
-                self.builder.append(error_message);
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        self.nature = match self.fetch_nature().await {
+            Ok(nature) => nature,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
                 return;
             }
         };
@@ -178,13 +324,25 @@ impl PokemonCommand<'_> {
         let format_pokemon = FormatPokemon::new(pokemon.clone(), species.clone());
         let pokemon_rc = Rc::new(pokemon.clone());
 
+        if self.output_format.is_json() {
+            self.build_json(&format_pokemon, &pokemon_rc, &species)
+                .await;
+            return;
+        }
+
         self.build_summary(&format_pokemon);
         self.builder.newline();
 
         self.build_stat_output(&pokemon_rc);
         self.builder.newline();
 
-        self.build_ability_output(&pokemon_rc).await;
+        // Anything which failed to load is listed once after every section
+        let mut failures = self.build_ability_output(&pokemon_rc).await;
+
+        if self.show_forms {
+            self.builder.newline();
+            failures.extend(self.build_form_output(&format_pokemon, &pokemon).await);
+        }
 
         if self.show_evolution {
             let evolution_chain = self.fetch_evolution_chain(&species).await;
@@ -197,22 +355,126 @@ impl PokemonCommand<'_> {
             };
         }
 
-        if self.show_types {
-            let types = pokemon
-                .types
-                .into_iter()
-                .map(|t| t.type_.name)
-                .collect_vec();
-
-            let (type1, type2) = (types[0].to_string(), types.get(1).map(ToString::to_string));
-
-            // TODO: We should extract the logic we need from this as it restricts what we can actually do with `TypeCommand`
-            let type_builder = TypeCommand::execute(self.client, type1, type2, false).await;
+        if let Some(type_chart) = self.type_chart {
+            let type_builder = self.fetch_type_information(&pokemon, type_chart).await;
 
             self.builder.newline();
             self.builder.appendln(formatter::white("Type information"));
             self.builder.append(type_builder);
         }
+
+        fetch_failure::append_failures(self.builder, &failures);
+    }
+
+    async fn build_json(
+        &mut self,
+        format_pokemon: &FormatPokemon,
+        pokemon: &Rc<Pokemon>,
+        species: &PokemonSpecies,
+    ) {
+        let (abilities, mut failures) = self.fetch_abilities(pokemon).await;
+
+        let mut json = json!({
+            "summary": format_pokemon.to_json(),
+            "stats": self.stats_json(pokemon),
+            "abilities": abilities.iter().map(FormatAbility::to_json).collect_vec(),
+        });
+
+        if self.show_forms {
+            let (forms, form_failures) = self.fetch_forms(format_pokemon, pokemon).await;
+            json["forms"] = json!(forms.iter().map(PokemonForm::to_json).collect_vec());
+            failures.extend(form_failures);
+        }
+
+        if !failures.is_empty() {
+            json["failed"] = fetch_failure::failures_json(&failures);
+        }
+
+        if let Some(stat_options) = &self.stat_options {
+            json["actual_stats"] = self.actual_stats_json(pokemon, stat_options);
+        }
+
+        if self.show_evolution {
+            let evolution_chain = self.fetch_evolution_chain(species).await;
+
+            json["evolution"] = match evolution_chain {
+                Some(evolution_chain) => {
+                    NormalisedEvolutionChain::from(&evolution_chain.chain).to_json()
+                }
+                None => serde_json::Value::Null,
+            };
+        }
+
+        if let Some(type_chart) = self.type_chart {
+            let (type1, type2) = Self::type_names(pokemon, type_chart);
+
+            json["types"] = TypeCommand::json(self.client, type1, type2, type_chart).await;
+        }
+
+        self.builder.append(json);
+    }
+
+    fn stats_json(&self, pokemon: &Pokemon) -> serde_json::Value {
+        let mut stats = pokemon
+            .stats
+            .iter()
+            .map(|stat| (stat.stat.name.clone(), json!(stat.base_stat)))
+            .collect::<Map<_, _>>();
+
+        let stat_total: i64 = pokemon.stats.iter().map(|stat| stat.base_stat).sum();
+        stats.insert(String::from("total"), json!(stat_total));
+
+        serde_json::Value::Object(stats)
+    }
+
+    fn actual_stats_json(
+        &self,
+        pokemon: &Pokemon,
+        stat_options: &StatOptions,
+    ) -> serde_json::Value {
+        let ranges =
+            stat_options.stat_ranges(&stat_calculator::base_stats(pokemon), self.nature.as_ref());
+
+        let mut stats = STAT_API_NAMES
+            .iter()
+            .zip(ranges)
+            .map(|(stat_name, (min, max))| {
+                (stat_name.to_string(), json!({ "min": min, "max": max }))
+            })
+            .collect::<Map<_, _>>();
+
+        stats.insert(String::from("level"), json!(stat_options.level));
+        stats.insert(
+            String::from("nature"),
+            json!(self.nature.as_ref().map(|nature| &nature.name)),
+        );
+
+        serde_json::Value::Object(stats)
+    }
+
+    fn type_names(pokemon: &Pokemon, type_chart: TypeChart) -> (String, Option<String>) {
+        let types = type_chart
+            .pokemon_types(pokemon)
+            .iter()
+            .map(|t| t.type_.name.clone())
+            .collect_vec();
+
+        (types[0].to_string(), types.get(1).map(ToString::to_string))
+    }
+
+    async fn fetch_type_information(&self, pokemon: &Pokemon, type_chart: TypeChart) -> Builder {
+        let (type1, type2) = Self::type_names(pokemon, type_chart);
+
+        // TODO: We should extract the logic we need from this as it restricts what we can actually do with `TypeCommand`
+        TypeCommand::execute(
+            self.client,
+            type1,
+            type2,
+            false,
+            type_chart,
+            self.output_format,
+        )
+        .await
     }
 
     async fn fetch_pokemon(&self) -> Result<Pokemon, String> {
@@ -225,6 +487,20 @@ impl PokemonCommand<'_> {
             .map_err(|error| error.to_string())
     }
 
+    async fn fetch_nature(&self) -> Result<Option<Nature>, String> {
+        let Some(nature_name) = self
+            .stat_options
+            .as_ref()
+            .and_then(|stat_options| stat_options.nature.as_deref())
+        else {
+            return Ok(None);
+        };
+
+        let nature = NatureCommand::fetch_nature(self.client, nature_name).await?;
+
+        Ok(Some(Nature::from(&nature)))
+    }
+
     async fn fetch_pokemon_species(&self, species_name: &str) -> Result<PokemonSpecies, String> {
         self.client
             .fetch_pokemon_species(species_name)
@@ -247,7 +523,12 @@ impl PokemonCommand<'_> {
     }
 
     fn build_stat_output(&mut self, pokemon: &Rc<Pokemon>) {
-        self.builder.appendln(formatter::white("Stats"));
+        self.builder.appendln(formatter::white(&self.stat_header()));
+
+        let ranges = self.stat_options.as_ref().map(|stat_options| {
+            stat_options.stat_ranges(&stat_calculator::base_stats(pokemon), self.nature.as_ref())
+        });
+
         let mut stat_total = 0;
         pokemon.stats.iter().enumerate().for_each(|(index, stat)| {
             // This assumes the stats returned from the API are always in the same order.
@@ -256,8 +537,15 @@ impl PokemonCommand<'_> {
             let stat_name = &formatter::white(STAT_NAMES[index]);
             let stat_amount = stat.base_stat;
             stat_total += stat_amount;
+
+            let stat_value = match ranges.map(|ranges| ranges[index]) {
+                Some((min, max)) if min == max => format!("{stat_amount} → {max}"),
+                Some((min, max)) => format!("{stat_amount} → {min} - {max}"),
+                None => stat_amount.to_string(),
+            };
+
             self.builder
-                .append(formatter::formatln(stat_name, &stat_amount.to_string()));
+                .append(formatter::formatln(stat_name, &stat_value));
         });
         self.builder.append(formatter::formatln(
             &formatter::white("Total"),
@@ -265,9 +553,35 @@ impl PokemonCommand<'_> {
         ));
     }
 
-    async fn build_ability_output(&mut self, pokemon: &Rc<Pokemon>) {
+    fn stat_header(&self) -> String {
+        let Some(stat_options) = &self.stat_options else {
+            return String::from("Stats");
+        };
+
+        let mut details = vec![format!("Lv. {}", stat_options.level)];
+        if let Some(nature) = &self.nature {
+            details.push(formatter::capitalise(&nature.name));
+        }
+
+        format!("Stats ({})", details.join(", "))
+    }
+
+    async fn build_ability_output(&mut self, pokemon: &Rc<Pokemon>) -> Vec<FetchFailure> {
         self.builder.appendln(formatter::white("Abilities"));
 
+        let (abilities, failures) = self.fetch_abilities(pokemon).await;
+        abilities.into_iter().for_each(|ability| {
+            self.builder.appendln(ability.format());
+        });
+
+        self.builder.pop();
+        failures
+    }
+
+    async fn fetch_abilities(
+        &self,
+        pokemon: &Rc<Pokemon>,
+    ) -> (Vec<FormatAbility>, Vec<FetchFailure>) {
         let unique_abilities = pokemon
             .abilities
             .iter()
@@ -280,19 +594,65 @@ impl PokemonCommand<'_> {
 
         stream::iter(unique_abilities)
             .map(|a| async move {
-                // TODO: Gracefully filter out failed requests for an ability
-                let ability = client_ref.fetch_ability(&a.name).await.unwrap();
-                FormatAbility::new(ability).with_pokemon(Rc::clone(pokemon_ref))
+                let ability = client_ref
+                    .fetch_ability(&a.name)
+                    .await
+                    .map_err(|error| FetchFailure::new("ability", &a.name, error))?;
+
+                Ok(FormatAbility::new(ability).with_pokemon(Rc::clone(pokemon_ref)))
             })
             .buffer_unordered(2)
             .collect::<Vec<_>>()
             .await
             .into_iter()
-            .for_each(|ability| {
-                self.builder.appendln(ability.format());
-            });
+            .partition_result()
+    }
+
+    async fn build_form_output(
+        &mut self,
+        format_pokemon: &FormatPokemon,
+        pokemon: &Pokemon,
+    ) -> Vec<FetchFailure> {
+        let (forms, failures) = self.fetch_forms(format_pokemon, pokemon).await;
+
+        self.builder
+            .appendln(formatter::white(&format!("Forms ({})", forms.len())));
+
+        if forms.is_empty() && failures.is_empty() {
+            self.builder.appendln(format!(
+                "  {} has no other forms",
+                formatter::split_and_capitalise(&pokemon.name)
+            ));
+        }
+
+        forms.iter().for_each(|form| {
+            self.builder.appendln(form.format());
+        });
 
         self.builder.pop();
+        failures
+    }
+
+    async fn fetch_forms(
+        &self,
+        format_pokemon: &FormatPokemon,
+        pokemon: &Pokemon,
+    ) -> (Vec<PokemonForm>, Vec<FetchFailure>) {
+        let client_ref = &self.client;
+
+        stream::iter(format_pokemon.form_names())
+            .map(|form_name| async move {
+                client_ref
+                    .fetch_pokemon(form_name)
+                    .await
+                    .map(|form| PokemonForm::new(form, pokemon))
+                    .map_err(|error| FetchFailure::new("pokemon", form_name, error))
+            })
+            .buffered(4)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .partition_result()
     }
 
     fn formatted_pokemon_name(&self, pokemon: &Pokemon, pokemon_name: &str) -> String {
@@ -0,0 +1,321 @@
+use crate::{
+    builder::Builder,
+    client::{ClientError, ClientImplementation},
+    fetch_failure::{self, FetchFailure},
+    formatter,
+    input_parser::generation_number,
+    name_matcher::{generation_names::GENERATION_NAMES, pokemon_names::POKEMON_NAMES},
+    output_format::OutputFormat,
+    search_query::{Query, SearchStat},
+    stat_calculator::{self, STAT_ABBREVIATIONS},
+    type_badge,
+};
+
+use std::collections::HashSet;
+
+use futures::{StreamExt, future::try_join_all, stream};
+use itertools::Itertools;
+use rustemon::model::pokemon::{Pokemon, PokemonSpecies};
+use serde_json::json;
+use unicode_width::UnicodeWidthStr;
+
+const CONCURRENCY: usize = 8;
+const STAT_COLUMN_WIDTH: usize = 5;
+
+pub struct SearchCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    terms: Vec<String>,
+    sort: SearchStat,
+    output_format: OutputFormat,
+}
+
+impl SearchCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        terms: Vec<String>,
+        sort: SearchStat,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        SearchCommand {
+            builder: &mut builder,
+            client,
+            terms,
+            sort,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let (results, failures) = match self.search().await {
+            Ok(search) => search,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        match self.output_format {
+            OutputFormat::Text => {
+                self.build_output(&results);
+                fetch_failure::append_failures(self.builder, &failures);
+            }
+            OutputFormat::Json => self.build_json(&results, &failures),
+        }
+    }
+
+    async fn search(&self) -> Result<(Vec<Pokemon>, Vec<FetchFailure>), String> {
+        let query = Query::parse(&self.terms)?;
+        let candidates = self.candidate_names(&query).await?;
+
+        let query = &query;
+
+        let (matches, failures) = stream::iter(&candidates)
+            .map(|pokemon_name| async move {
+                let (pokemon, species) = self
+                    .fetch_candidate(pokemon_name, query.has_generation_filter())
+                    .await
+                    .map_err(|error| FetchFailure::new("pokemon", pokemon_name, error))?;
+
+                Ok(query.matches(&pokemon, species.as_ref()).then_some(pokemon))
+            })
+            .buffer_unordered(CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .partition_result::<Vec<_>, Vec<_>, _, _>();
+
+        let mut results = matches.into_iter().flatten().collect_vec();
+        results.sort_by(|a, b| {
+            self.sort
+                .value(b)
+                .cmp(&self.sort.value(a))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        Ok((results, failures))
+    }
+
+    /// Types, abilities and generations list the pokemon in them, so only those need to be
+    /// fetched. A query of stat filters alone is checked against every pokemon, the same ones
+    /// `cache warm` fetches
+    async fn candidate_names(&self, query: &Query) -> Result<Vec<String>, String> {
+        let types = try_join_all(query.type_names().into_iter().map(|type_name| async move {
+            let type_ = self
+                .client
+                .fetch_type(type_name)
+                .await
+                .map_err(|error| error.to_string())?;
+
+            Ok::<_, String>(
+                type_
+                    .pokemon
+                    .into_iter()
+                    .map(|type_pokemon| type_pokemon.pokemon.name)
+                    .collect::<HashSet<_>>(),
+            )
+        }));
+
+        let abilities = try_join_all(query.ability_names().into_iter().map(
+            |ability_name| async move {
+                let ability = self
+                    .client
+                    .fetch_ability(ability_name)
+                    .await
+                    .map_err(|error| error.to_string())?;
+
+                Ok::<_, String>(
+                    ability
+                        .pokemon
+                        .into_iter()
+                        .map(|ability_pokemon| ability_pokemon.pokemon.name)
+                        .collect::<HashSet<_>>(),
+                )
+            },
+        ));
+
+        let (types, abilities, generations) =
+            futures::try_join!(types, abilities, self.generation_candidates(query))?;
+
+        Ok(types
+            .into_iter()
+            .chain(abilities)
+            .chain(generations)
+            .reduce(|candidates, names| &candidates & &names)
+            .map_or_else(
+                || POKEMON_NAMES.clone(),
+                |candidates| candidates.into_iter().sorted().collect(),
+            ))
+    }
+
+    // Generations only list species, so every form of those species is a candidate
+    async fn generation_candidates(
+        &self,
+        query: &Query,
+    ) -> Result<Option<HashSet<String>>, String> {
+        if !query.has_generation_filter() {
+            return Ok(None);
+        }
+
+        let generation_names = GENERATION_NAMES.iter().filter(|generation_name| {
+            generation_number(generation_name)
+                .is_some_and(|number| query.includes_generation(number))
+        });
+
+        let generations = try_join_all(
+            generation_names.map(|generation_name| self.client.fetch_generation(generation_name)),
+        )
+        .await
+        .map_err(|error| error.to_string())?;
+
+        let species_names = generations
+            .into_iter()
+            .flat_map(|generation| generation.pokemon_species)
+            .map(|species| species.name)
+            .collect::<HashSet<_>>();
+
+        // Forms are named after their species, e.g. "deoxys-attack" or "raichu-alola"
+        let is_form_of_species = |pokemon_name: &str| {
+            std::iter::once(pokemon_name.len())
+                .chain(pokemon_name.match_indices('-').map(|(index, _)| index))
+                .any(|index| species_names.contains(&pokemon_name[..index]))
+        };
+
+        Ok(Some(
+            POKEMON_NAMES
+                .iter()
+                .filter(|pokemon_name| is_form_of_species(pokemon_name))
+                .cloned()
+                .collect(),
+        ))
+    }
+
+    async fn fetch_candidate(
+        &self,
+        pokemon_name: &str,
+        needs_species: bool,
+    ) -> Result<(Pokemon, Option<PokemonSpecies>), ClientError> {
+        let pokemon = self.client.fetch_pokemon(pokemon_name).await?;
+
+        let species = if needs_species {
+            Some(
+                self.client
+                    .fetch_pokemon_species(&pokemon.species.name)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        Ok((pokemon, species))
+    }
+
+    fn build_output(&mut self, results: &[Pokemon]) {
+        let header = formatter::white(&format!("Results ({})", results.len()));
+        self.builder.appendln(header);
+
+        if results.is_empty() {
+            self.builder
+                .append(formatter::red("  No pokemon match the search."));
+            return;
+        }
+
+        let names = results
+            .iter()
+            .map(|pokemon| formatter::split_and_capitalise(&pokemon.name))
+            .collect_vec();
+
+        let name_width = names
+            .iter()
+            .map(|name| UnicodeWidthStr::width(name.as_str()))
+            .chain([UnicodeWidthStr::width("Name")])
+            .max()
+            .unwrap_or(0);
+
+        let badge_width = UnicodeWidthStr::width(type_badge::format_type_name("normal").as_str());
+        let types_width = badge_width * 2 + 1;
+
+        let stat_headers = STAT_ABBREVIATIONS
+            .iter()
+            .copied()
+            .chain(["BST"])
+            .map(|abbreviation| format!("{abbreviation:>STAT_COLUMN_WIDTH$}"))
+            .join(" ");
+
+        self.builder.appendln(formatter::white(&format!(
+            "  {:name_width$} | {:types_width$} | {stat_headers}",
+            "Name", "Type"
+        )));
+
+        for (pokemon, name) in results.iter().zip(names) {
+            let types = pokemon
+                .types
+                .iter()
+                .map(|type_| type_badge::fetch(&type_.type_.name))
+                .join(" ");
+            let types_padding = types_width
+                - (badge_width * pokemon.types.len() + pokemon.types.len().saturating_sub(1));
+
+            self.builder.appendln(format!(
+                "  {name:name_width$} | {types}{} | {}",
+                " ".repeat(types_padding),
+                self.stat_cells(pokemon)
+            ));
+        }
+    }
+
+    fn stat_cells(&self, pokemon: &Pokemon) -> String {
+        let base_stats = stat_calculator::base_stats(pokemon);
+        let total = base_stats.iter().sum();
+
+        base_stats
+            .into_iter()
+            .enumerate()
+            .map(|(stat, value)| (SearchStat::Stat(stat), value))
+            .chain([(SearchStat::Total, total)])
+            .map(|(stat, value)| {
+                let cell = format!("{value:>STAT_COLUMN_WIDTH$}");
+                if stat == self.sort {
+                    formatter::highlight(&cell)
+                } else {
+                    cell
+                }
+            })
+            .join(" ")
+    }
+
+    fn build_json(&mut self, results: &[Pokemon], failures: &[FetchFailure]) {
+        let pokemon = results
+            .iter()
+            .map(|pokemon| {
+                json!({
+                    "name": pokemon.name,
+                    "types": pokemon
+                        .types
+                        .iter()
+                        .map(|type_| &type_.type_.name)
+                        .collect_vec(),
+                    "stats": stat_calculator::stat_spread_json(&stat_calculator::base_stats(pokemon)),
+                })
+            })
+            .collect_vec();
+
+        let mut json = json!({
+            "query": self.terms,
+            "sort": self.sort.name(),
+            "pokemon": pokemon,
+        });
+
+        if !failures.is_empty() {
+            json["failed"] = fetch_failure::failures_json(failures);
+        }
+
+        self.builder.append(json);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    Cli, Commands, builder::Builder, client::Client, client::ClientImplementation, completion,
+    output_format::OutputFormat, run, session_client::SessionClient,
+};
+
+use clap::{ArgMatches, CommandFactory, FromArgMatches, parser::ValueSource};
+use rustyline::{
+    Context, Editor, Helper, Highlighter, Hinter, Validator, completion::Completer,
+    error::ReadlineError, history::DefaultHistory,
+};
+
+// Kept next to the cache rather than inside it, as `cache clear` expects only cacache's entries
+const HISTORY_FILE_SUFFIX: &str = "-shell-history";
+const PROMPT: &str = "poke_search> ";
+
+// Options which pick the client, so they can only be set when the shell is started
+const CLIENT_OPTIONS: &[(&str, &str)] = &[
+    ("offline", "--offline"),
+    ("data_dir", "--data-dir"),
+    ("api_url", "--api-url"),
+];
+
+#[derive(Helper, Hinter, Highlighter, Validator)]
+struct ShellHelper;
+
+impl Completer for ShellHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        let start = line.rfind(char::is_whitespace).map_or(0, |index| index + 1);
+
+        let mut words = line[..start].split_whitespace().collect::<Vec<_>>();
+        words.push(&line[start..]);
+
+        Ok((start, completion::complete(&words)))
+    }
+}
+
+pub struct ShellCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    output_format: OutputFormat,
+}
+
+impl ShellCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        ShellCommand {
+            builder: &mut builder,
+            client,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let mut editor = match Editor::<ShellHelper, DefaultHistory>::new() {
+            Ok(editor) => editor,
+            Err(error) => {
+                self.builder.append_error(
+                    format!("Failed to start shell: {error}"),
+                    self.output_format,
+                );
+                return;
+            }
+        };
+        editor.set_helper(Some(ShellHelper));
+
+        let history_path = Client::get_cache_dir().ok().map(|cache_dir| {
+            let mut file_name = cache_dir.file_name().unwrap_or_default().to_os_string();
+            file_name.push(HISTORY_FILE_SUFFIX);
+
+            cache_dir.with_file_name(file_name)
+        });
+
+        if let Some(history_path) = &history_path {
+            // There's no history the first time the shell is used
+            let _ = editor.load_history(history_path);
+        }
+
+        let session_client = SessionClient::new(self.client);
+
+        eprintln!("Type a command such as `pokemon charizard -e`, `help` or `exit`");
+
+        loop {
+            let line = match editor.readline(PROMPT) {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(error) => {
+                    self.builder
+                        .append_error(format!("Failed to read input: {error}"), self.output_format);
+                    break;
+                }
+            };
+
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let _ = editor.add_history_entry(line);
+
+            if matches!(line, "exit" | "quit") {
+                break;
+            }
+
+            Self::run_line(&session_client, line, self.output_format).await;
+        }
+
+        if let Some(history_path) = &history_path {
+            if let Some(parent) = history_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            let _ = editor.save_history(history_path);
+        }
+    }
+
+    async fn run_line(client: &SessionClient<'_>, line: &str, output_format: OutputFormat) {
+        let Some(words) = shlex::split(line) else {
+            eprintln!("Unterminated quote in `{line}`");
+            return;
+        };
+
+        let args = std::iter::once(String::from(env!("CARGO_PKG_NAME"))).chain(words);
+        let matches = match Cli::command().try_get_matches_from(args) {
+            Ok(matches) => matches,
+            Err(error) => {
+                // Covers `help` and `--help` as well as invalid input
+                let _ = error.print();
+                return;
+            }
+        };
+
+        let cli = match Self::build_cli(&matches, output_format) {
+            Ok(cli) => cli,
+            Err(error_message) => {
+                eprintln!("{error_message}");
+                return;
+            }
+        };
+
+        if matches!(cli.command, Commands::Shell) {
+            eprintln!("Already in a shell");
+            return;
+        }
+
+        Box::pin(run(client, cli)).await.print();
+        println!();
+    }
+
+    /// Every line shares the shell's client, so options which would pick a different one are
+    /// rejected rather than silently ignored. `--format` falls back to the shell's own
+    fn build_cli(matches: &ArgMatches, output_format: OutputFormat) -> Result<Cli, String> {
+        let typed_on_line = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+        if let Some((_, flag)) = CLIENT_OPTIONS.iter().find(|(id, _)| typed_on_line(id)) {
+            return Err(format!(
+                "{flag} can't be changed inside the shell, pass it when starting the shell instead"
+            ));
+        }
+
+        let mut cli = Cli::from_arg_matches(matches).map_err(|error| error.to_string())?;
+
+        if !typed_on_line("format") {
+            cli.format = output_format;
+        }
+
+        Ok(cli)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_cli(line: &str, output_format: OutputFormat) -> Result<Cli, String> {
+        let args = std::iter::once("poke_search").chain(line.split_whitespace());
+        let matches = Cli::command().try_get_matches_from(args).unwrap();
+
+        ShellCommand::build_cli(&matches, output_format)
+    }
+
+    #[test]
+    fn test_format_defaults_to_shell_format() {
+        let cli = build_cli("pokemon charizard", OutputFormat::Json).unwrap();
+        assert_eq!(cli.format, OutputFormat::Json);
+
+        let cli = build_cli("pokemon charizard --format text", OutputFormat::Json).unwrap();
+        assert_eq!(cli.format, OutputFormat::Text);
+    }
+
+    #[test]
+    fn test_client_options_are_rejected() {
+        assert_eq!(
+            build_cli("--offline pokemon charizard", OutputFormat::Text).err(),
+            Some(String::from(
+                "--offline can't be changed inside the shell, pass it when starting the shell instead"
+            ))
+        );
+        assert!(build_cli("pokemon charizard --data-dir data", OutputFormat::Text).is_err());
+        assert!(
+            build_cli(
+                "--api-url http://localhost pokemon charizard",
+                OutputFormat::Text
+            )
+            .is_err()
+        );
+    }
+}
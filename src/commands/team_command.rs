@@ -0,0 +1,382 @@
+use crate::{
+    builder::Builder,
+    client::{ClientError, ClientImplementation},
+    commands::type_command::{DamageType, EXCLUDED_TYPES, TypeChart, TypeCommand},
+    formatter,
+    name_matcher::{matcher, type_names::TYPE_NAMES},
+    output_format::OutputFormat,
+    type_badge,
+};
+
+use std::collections::HashMap;
+
+use futures::future::try_join_all;
+use itertools::Itertools;
+use rustemon::model::pokemon::{Pokemon, Type};
+use serde_json::json;
+use unicode_width::UnicodeWidthStr;
+
+pub const MAX_TEAM_SIZE: usize = 6;
+
+const MAX_COLUMN_WIDTH: usize = 10;
+const MIN_COLUMN_WIDTH: usize = 5;
+const SHARED_WEAKNESS_THRESHOLD: usize = 2;
+
+// Only used by terastallised pokemon so it never deals anything but normal damage
+const IGNORED_ATTACKING_TYPES: &[&str] = &["stellar"];
+
+struct Member {
+    pokemon: Pokemon,
+    defence: HashMap<String, DamageType>,
+}
+
+struct AttackingType {
+    name: String,
+    multipliers: Vec<DamageType>,
+}
+
+impl AttackingType {
+    fn weaknesses(&self) -> usize {
+        self.multipliers
+            .iter()
+            .filter(|damage_type| matches!(damage_type, DamageType::Double | DamageType::Quadruple))
+            .count()
+    }
+
+    fn resistances(&self) -> usize {
+        self.multipliers
+            .iter()
+            .filter(|damage_type| {
+                matches!(
+                    damage_type,
+                    DamageType::None | DamageType::Quarter | DamageType::Half
+                )
+            })
+            .count()
+    }
+
+    fn is_shared_weakness(&self) -> bool {
+        self.weaknesses() >= SHARED_WEAKNESS_THRESHOLD
+    }
+}
+
+pub struct TeamCommand<'a> {
+    builder: &'a mut Builder,
+    client: &'a dyn ClientImplementation,
+    pokemon_names: Vec<String>,
+    output_format: OutputFormat,
+}
+
+impl TeamCommand<'_> {
+    pub async fn execute(
+        client: &dyn ClientImplementation,
+        pokemon_names: Vec<String>,
+        output_format: OutputFormat,
+    ) -> Builder {
+        let mut builder = Builder::default();
+
+        TeamCommand {
+            builder: &mut builder,
+            client,
+            pokemon_names,
+            output_format,
+        }
+        ._execute()
+        .await;
+
+        builder
+    }
+
+    async fn _execute(&mut self) {
+        let members = match self.fetch_members().await {
+            Ok(members) => members,
+            Err(error_message) => {
+                self.builder.append_error(error_message, self.output_format);
+                return;
+            }
+        };
+
+        let attacking_types = self.build_attacking_types(&members);
+
+        match self.output_format {
+            OutputFormat::Text => self.build_output(&members, &attacking_types),
+            OutputFormat::Json => self.build_json(&members, &attacking_types),
+        }
+    }
+
+    async fn fetch_members(&self) -> Result<Vec<Member>, String> {
+        let team = try_join_all(
+            self.pokemon_names
+                .iter()
+                .map(|pokemon_name| self.fetch_pokemon(pokemon_name)),
+        )
+        .await?;
+
+        let type_names = team
+            .iter()
+            .flat_map(|pokemon| pokemon.types.iter().map(|type_| type_.type_.name.clone()))
+            .unique()
+            .collect_vec();
+
+        let types = try_join_all(
+            type_names
+                .iter()
+                .map(|type_name| self.fetch_type(type_name)),
+        )
+        .await?
+        .into_iter()
+        .map(|type_| (type_.name.clone(), type_))
+        .collect::<HashMap<_, _>>();
+
+        let members = team
+            .into_iter()
+            .map(|pokemon| {
+                let mut pokemon_types = pokemon
+                    .types
+                    .iter()
+                    .filter_map(|type_| types.get(&type_.type_.name));
+
+                let defence = match pokemon_types.next() {
+                    Some(type_) => {
+                        TypeCommand::defence_groups(type_, pokemon_types.next(), TypeChart::Latest)
+                            .into_iter()
+                            .flat_map(|(damage_type, type_names)| {
+                                type_names
+                                    .into_iter()
+                                    .map(move |type_name| (type_name, damage_type))
+                            })
+                            .collect()
+                    }
+                    None => HashMap::new(),
+                };
+
+                Member { pokemon, defence }
+            })
+            .collect();
+
+        Ok(members)
+    }
+
+    async fn fetch_pokemon(&self, pokemon_name: &str) -> Result<Pokemon, String> {
+        let successful_match =
+            matcher::match_pokemon_name(pokemon_name).map_err(|no_match| no_match.0)?;
+
+        let result = self
+            .client
+            .fetch_pokemon(&successful_match.suggested_name)
+            .await;
+
+        match result {
+            Ok(pokemon) => Ok(pokemon),
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
+                let output = matcher::build_unknown_name(
+                    &successful_match.keyword,
+                    &successful_match.suggested_name,
+                );
+                Err(output)
+            }
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    async fn fetch_type(&self, type_name: &str) -> Result<Type, String> {
+        self.client
+            .fetch_type(type_name)
+            .await
+            .map_err(|error| error.to_string())
+    }
+
+    fn build_attacking_types(&self, members: &[Member]) -> Vec<AttackingType> {
+        TYPE_NAMES
+            .iter()
+            .filter(|type_name| {
+                !EXCLUDED_TYPES.contains(&type_name.as_str())
+                    && !IGNORED_ATTACKING_TYPES.contains(&type_name.as_str())
+            })
+            .map(|type_name| AttackingType {
+                name: type_name.clone(),
+                multipliers: members
+                    .iter()
+                    .map(|member| {
+                        member
+                            .defence
+                            .get(type_name)
+                            .copied()
+                            .unwrap_or(DamageType::Normal)
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn build_output(&mut self, members: &[Member], attacking_types: &[AttackingType]) {
+        self.builder.appendln(formatter::white("Team"));
+        for member in members {
+            let types = member
+                .pokemon
+                .types
+                .iter()
+                .map(|type_| type_badge::fetch(&type_.type_.name))
+                .join(" | ");
+
+            self.builder.appendln(format!(
+                "  {}: {types}",
+                formatter::split_and_capitalise(&member.pokemon.name)
+            ));
+        }
+
+        self.builder.newline();
+        self.builder.appendln(formatter::white("Defence"));
+        self.build_matrix(members, attacking_types);
+
+        let shared_weaknesses = attacking_types
+            .iter()
+            .filter(|attacking_type| attacking_type.is_shared_weakness())
+            .sorted_by_key(|attacking_type| std::cmp::Reverse(attacking_type.weaknesses()))
+            .collect_vec();
+
+        self.builder.newline();
+        self.builder.appendln(formatter::white("Shared Weaknesses"));
+
+        if shared_weaknesses.is_empty() {
+            self.builder.append(formatter::green("  None"));
+            return;
+        }
+
+        let formatted_weaknesses = shared_weaknesses
+            .iter()
+            .map(|attacking_type| {
+                format!(
+                    "{} ({})",
+                    type_badge::fetch(&attacking_type.name),
+                    attacking_type.weaknesses()
+                )
+            })
+            .join(" | ");
+
+        self.builder.append(format!("  {formatted_weaknesses}"));
+    }
+
+    fn build_matrix(&mut self, members: &[Member], attacking_types: &[AttackingType]) {
+        let label_width = attacking_types
+            .iter()
+            .map(|attacking_type| Self::type_label_width(&attacking_type.name))
+            .max()
+            .unwrap_or(0);
+
+        let member_names = members
+            .iter()
+            .map(|member| {
+                formatter::split_and_capitalise(&member.pokemon.name)
+                    .chars()
+                    .take(MAX_COLUMN_WIDTH)
+                    .collect::<String>()
+            })
+            .collect_vec();
+
+        let column_widths = member_names
+            .iter()
+            .map(|name| UnicodeWidthStr::width(name.as_str()).max(MIN_COLUMN_WIDTH))
+            .collect_vec();
+
+        let header = member_names
+            .iter()
+            .zip(&column_widths)
+            .map(|(name, width)| format!("{name:^width$}"))
+            .chain(["Weak".to_owned(), "Resist".to_owned()])
+            .join(" | ");
+
+        self.builder.appendln(format!(
+            "  {} | {}",
+            " ".repeat(label_width),
+            formatter::white(&header)
+        ));
+
+        for attacking_type in attacking_types {
+            let padding = label_width - Self::type_label_width(&attacking_type.name);
+            let label = format!(
+                "{}{}",
+                type_badge::fetch(&attacking_type.name),
+                " ".repeat(padding)
+            );
+
+            let cells = attacking_type
+                .multipliers
+                .iter()
+                .zip(&column_widths)
+                .map(|(damage_type, width)| {
+                    damage_type.format_defence(&format!("{:^width$}", damage_type.label()))
+                })
+                .collect_vec();
+
+            let weaknesses = format!("{:^4}", attacking_type.weaknesses());
+            let weaknesses = if attacking_type.is_shared_weakness() {
+                formatter::highlight(&formatter::red(&weaknesses))
+            } else {
+                weaknesses
+            };
+
+            let resistances = format!("{:^6}", attacking_type.resistances());
+
+            self.builder.appendln(format!(
+                "  {label} | {} | {weaknesses} | {resistances}",
+                cells.join(" | ")
+            ));
+        }
+
+        self.builder.pop();
+    }
+
+    fn type_label_width(type_name: &str) -> usize {
+        UnicodeWidthStr::width(type_badge::format_type_name(type_name).as_str())
+    }
+
+    fn build_json(&mut self, members: &[Member], attacking_types: &[AttackingType]) {
+        let team = members
+            .iter()
+            .map(|member| {
+                json!({
+                    "name": member.pokemon.name,
+                    "types": member
+                        .pokemon
+                        .types
+                        .iter()
+                        .map(|type_| &type_.type_.name)
+                        .collect_vec(),
+                })
+            })
+            .collect_vec();
+
+        let defence = attacking_types
+            .iter()
+            .map(|attacking_type| {
+                let multipliers = attacking_type
+                    .multipliers
+                    .iter()
+                    .map(DamageType::label)
+                    .collect_vec();
+
+                (
+                    attacking_type.name.clone(),
+                    json!({
+                        "multipliers": multipliers,
+                        "weaknesses": attacking_type.weaknesses(),
+                        "resistances": attacking_type.resistances(),
+                    }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        let shared_weaknesses = attacking_types
+            .iter()
+            .filter(|attacking_type| attacking_type.is_shared_weakness())
+            .map(|attacking_type| &attacking_type.name)
+            .collect_vec();
+
+        self.builder.append(json!({
+            "team": team,
+            "defence": defence,
+            "shared_weaknesses": shared_weaknesses,
+        }));
+    }
+}
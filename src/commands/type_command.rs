@@ -1,21 +1,107 @@
 use crate::{
     builder::Builder,
-    client::ClientImplementation,
+    client::{ClientError, ClientImplementation},
     formatter::{self},
+    input_parser::generation_number,
     matcher::SuccessfulMatch,
     name_matcher::{matcher, type_names},
+    output_format::OutputFormat,
+    roman_numeral::integer_to_roman,
     type_badge::{self},
 };
 
 use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
-use rustemon::model::{pokemon::Type, resource::NamedApiResource};
+use rustemon::model::{
+    games::Generation,
+    pokemon::{Pokemon, PokemonType, Type, TypeRelations},
+    resource::NamedApiResource,
+};
+use serde_json::{Map, json};
 use tokio::try_join;
 
-const EXCLUDED_TYPES: &[&str] = &["unknown", "shadow"];
+pub(crate) const EXCLUDED_TYPES: &[&str] = &["unknown", "shadow"];
+
+// Types which weren't in the first games, with the generation that added them
+const LATER_TYPES: &[(&str, i64)] = &[("dark", 2), ("steel", 2), ("fairy", 6), ("stellar", 9)];
+
+/// Which games' type matchups to use. Older generations had fewer types and a few different
+/// matchups, e.g. Ghost doing nothing to Psychic in Generation I
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TypeChart {
+    #[default]
+    Latest,
+    Generation(i64),
+}
+
+impl TypeChart {
+    /// Whether the type existed in the chart's generation
+    pub(crate) fn includes(&self, type_name: &str) -> bool {
+        let TypeChart::Generation(generation) = self else {
+            return true;
+        };
+
+        LATER_TYPES
+            .iter()
+            .find(|(later_type, _)| *later_type == type_name)
+            .is_none_or(|(_, introduced)| introduced <= generation)
+    }
+
+    /// The matchups for `type_` in the chart's generation
+    pub(crate) fn damage_relations<'a>(&self, type_: &'a Type) -> &'a TypeRelations {
+        self.past_value(
+            &type_.damage_relations,
+            type_
+                .past_damage_relations
+                .iter()
+                .map(|past| (&past.generation, &past.damage_relations)),
+        )
+    }
+
+    /// The types `pokemon` had in the chart's generation, e.g. Clefairy was Normal before Fairy
+    pub(crate) fn pokemon_types<'a>(&self, pokemon: &'a Pokemon) -> &'a [PokemonType] {
+        self.past_value(
+            pokemon.types.as_slice(),
+            pokemon
+                .past_types
+                .iter()
+                .map(|past| (&past.generation, past.types.as_slice())),
+        )
+    }
+
+    // A past entry holds the values used up to and including its generation, so the earliest one
+    // which isn't before the chart's generation applies, falling back to the current value
+    fn past_value<'a, T: ?Sized>(
+        &self,
+        current: &'a T,
+        past: impl Iterator<Item = (&'a NamedApiResource<Generation>, &'a T)>,
+    ) -> &'a T {
+        let TypeChart::Generation(generation) = self else {
+            return current;
+        };
+
+        past.filter_map(|(past_generation, value)| {
+            let past_generation = generation_number(&past_generation.name)?;
+            (past_generation >= *generation).then_some((past_generation, value))
+        })
+        .min_by_key(|(past_generation, _)| *past_generation)
+        .map_or(current, |(_, value)| value)
+    }
 
-enum DamageType {
+    pub(crate) fn generation_name(&self) -> Option<String> {
+        match self {
+            TypeChart::Latest => None,
+            TypeChart::Generation(generation) => Some(format!(
+                "Generation {}",
+                integer_to_roman(*generation as i32).to_uppercase()
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum DamageType {
     None,
     Quarter,
     Half,
@@ -42,6 +128,43 @@ impl DamageType {
             DamageType::Quadruple => Self::QUADRUPLE_MULTIPLIER,
         }
     }
+
+    pub(crate) fn label(&self) -> &'static str {
+        self.multiplier().trim_end()
+    }
+
+    pub(crate) fn format_defence(&self, text: &str) -> String {
+        DamageContext::Defence.formatter(self)(text)
+    }
+
+    pub(crate) fn format_offence(&self, text: &str) -> String {
+        DamageContext::Offence.formatter(self)(text)
+    }
+
+    /// The multiplier of a move hitting both types of a dual type pokemon
+    pub(crate) fn combine(self, other: DamageType) -> DamageType {
+        if self == DamageType::None || other == DamageType::None {
+            return DamageType::None;
+        }
+
+        match self.exponent() + other.exponent() {
+            ..=-2 => DamageType::Quarter,
+            -1 => DamageType::Half,
+            0 => DamageType::Normal,
+            1 => DamageType::Double,
+            _ => DamageType::Quadruple,
+        }
+    }
+
+    fn exponent(&self) -> i8 {
+        match self {
+            DamageType::None | DamageType::Normal => 0,
+            DamageType::Quarter => -2,
+            DamageType::Half => -1,
+            DamageType::Double => 1,
+            DamageType::Quadruple => 2,
+        }
+    }
 }
 
 enum DamageContext {
@@ -61,13 +184,9 @@ impl DamageContext {
         match self {
             DamageContext::Offence => match damage_type {
                 DamageType::None => formatter::red,
-                DamageType::Half => formatter::bright_red,
+                DamageType::Quarter | DamageType::Half => formatter::bright_red,
                 DamageType::Normal => formatter::yellow,
-                DamageType::Double => formatter::green,
-
-                // These are currently not possible for offence
-                DamageType::Quarter => panic!("Quarter damage is not possible for offence"),
-                DamageType::Quadruple => panic!("Quadruple damage is not possible for offence"),
+                DamageType::Double | DamageType::Quadruple => formatter::green,
             },
             DamageContext::Defence => match damage_type {
                 DamageType::Quarter | DamageType::None => formatter::green,
@@ -86,6 +205,8 @@ pub struct TypeCommand<'a> {
     type_name: String,
     second_type_name: Option<String>,
     list_pokemon: bool,
+    type_chart: TypeChart,
+    output_format: OutputFormat,
 }
 
 impl TypeCommand<'_> {
@@ -94,6 +215,8 @@ impl TypeCommand<'_> {
         type_name: String,
         second_type_name: Option<String>,
         list_pokemon: bool,
+        type_chart: TypeChart,
+        output_format: OutputFormat,
     ) -> Builder {
         let mut builder = Builder::default();
 
@@ -103,6 +226,8 @@ impl TypeCommand<'_> {
             type_name,
             second_type_name,
             list_pokemon,
+            type_chart,
+            output_format,
         }
         ._execute()
         .await;
@@ -110,15 +235,52 @@ impl TypeCommand<'_> {
         builder
     }
 
+    /// The same matchups `execute` prints with `--format json`, as a value other commands can
+    /// embed in their own JSON output
+    pub async fn json(
+        client: &dyn ClientImplementation,
+        type_name: String,
+        second_type_name: Option<String>,
+        type_chart: TypeChart,
+    ) -> serde_json::Value {
+        let mut builder = Builder::default();
+
+        let type_command = TypeCommand {
+            builder: &mut builder,
+            client,
+            type_name,
+            second_type_name,
+            list_pokemon: false,
+            type_chart,
+            output_format: OutputFormat::Json,
+        };
+
+        match type_command.fetch_types().await {
+            Ok((type_, second_type)) => type_command.build_json(&type_, second_type.as_ref()),
+            Err(error_message) => json!({ "error": error_message }),
+        }
+    }
+
     async fn _execute(&mut self) {
         let (type_, second_type) = match self.fetch_types().await {
             Ok(types) => types,
             Err(error_message) => {
-                self.builder.append(error_message);
+                self.builder.append_error(error_message, self.output_format);
                 return;
             }
         };
 
+        if self.output_format.is_json() {
+            let json = self.build_json(&type_, second_type.as_ref());
+            self.builder.append(json);
+            return;
+        }
+
+        if let Some(generation_name) = self.type_chart.generation_name() {
+            self.builder.appendln(formatter::white(&generation_name));
+            self.builder.newline();
+        }
+
         match second_type {
             Some(ref second_type) => self.append_dual_type_damage_details(&type_, second_type),
             None => self.append_single_type_damage_details(&type_),
@@ -164,21 +326,55 @@ impl TypeCommand<'_> {
 
         match result {
             Ok(type_) => Ok(type_),
-            Err(_) => {
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
                 let output = matcher::build_unknown_name(
                     &matched_name.keyword,
                     &matched_name.suggested_name,
                 );
                 Err(output)
             }
+            Err(error) => Err(error.to_string()),
         }
     }
 
     fn correct_type_name(&self, name: &str) -> Result<SuccessfulMatch, String> {
-        matcher::match_type_name(name).map_err(|no_match| no_match.0)
+        let successful_match = matcher::match_type_name(name).map_err(|no_match| no_match.0)?;
+
+        match self.type_chart.generation_name() {
+            Some(generation_name)
+                if !self.type_chart.includes(&successful_match.suggested_name) =>
+            {
+                Err(format!(
+                    "{} type doesn't exist in {generation_name}",
+                    formatter::capitalise(&successful_match.suggested_name)
+                ))
+            }
+            _ => Ok(successful_match),
+        }
     }
 
     fn append_pokemon_list(&mut self, type_: &Type, second_type: Option<&Type>) {
+        let pokemon_names = self.pokemon_names(type_, second_type);
+
+        let formatted_pokemon = pokemon_names
+            .iter()
+            .map(|pokemon_name| format!("  {}", formatter::split_and_capitalise(pokemon_name)))
+            .collect_vec();
+
+        let num_pokemon = pokemon_names.len();
+        let header = formatter::white(&format!("Pokemon ({num_pokemon})"));
+        self.builder.appendln(header);
+
+        if num_pokemon > 0 {
+            self.builder
+                .append(formatter::format_columns(&formatted_pokemon, 3));
+        } else {
+            self.builder
+                .append(formatter::red("No pokemon with this type combination."));
+        }
+    }
+
+    fn pokemon_names(&self, type_: &Type, second_type: Option<&Type>) -> Vec<String> {
         let mut pokemon_names = {
             let type_pokemon_names = self.pokemon_names_from_type(type_);
 
@@ -198,23 +394,53 @@ impl TypeCommand<'_> {
         };
 
         pokemon_names.sort();
+        pokemon_names
+    }
 
-        let formatted_pokemon = pokemon_names
+    fn build_json(&self, type_: &Type, second_type: Option<&Type>) -> serde_json::Value {
+        let types = std::iter::once(type_).chain(second_type).collect_vec();
+
+        let offence = types
             .iter()
-            .map(|pokemon_name| format!("  {}", formatter::split_and_capitalise(pokemon_name)))
-            .collect_vec();
+            .map(|type_| {
+                let damage_groups =
+                    Self::single_damage_groups(type_, &DamageContext::Offence, self.type_chart);
+                (type_.name.clone(), self.damage_groups_json(damage_groups))
+            })
+            .collect::<Map<_, _>>();
 
-        let num_pokemon = pokemon_names.len();
-        let header = formatter::white(&format!("Pokemon ({num_pokemon})"));
-        self.builder.appendln(header);
+        let defence_groups = Self::defence_groups(type_, second_type, self.type_chart);
 
-        if num_pokemon > 0 {
-            self.builder
-                .append(formatter::format_columns(&formatted_pokemon, 3));
-        } else {
-            self.builder
-                .append(formatter::red("No pokemon with this type combination."));
+        let mut json = json!({
+            "types": types.iter().map(|type_| &type_.name).collect_vec(),
+            "offence": offence,
+            "defence": self.damage_groups_json(defence_groups),
+        });
+
+        if self.list_pokemon {
+            json["pokemon"] = json!(self.pokemon_names(type_, second_type));
+        }
+
+        if let TypeChart::Generation(generation) = self.type_chart {
+            json["generation"] = json!(generation);
         }
+
+        json
+    }
+
+    fn damage_groups_json(
+        &self,
+        damage_groups: Vec<(DamageType, Vec<String>)>,
+    ) -> serde_json::Value {
+        let groups = damage_groups
+            .into_iter()
+            .map(|(damage_type, mut type_names)| {
+                type_names.sort();
+                (damage_type.label().to_owned(), json!(type_names))
+            })
+            .collect::<Map<_, _>>();
+
+        serde_json::Value::Object(groups)
     }
 
     fn pokemon_names_from_type(&self, type_: &Type) -> impl Iterator<Item = String> {
@@ -280,43 +506,85 @@ impl TypeCommand<'_> {
     }
 
     fn append_single_damage_output(&mut self, type_: &Type, context: DamageContext) {
-        let type_relations = &type_.damage_relations;
+        let damage_groups = Self::single_damage_groups(type_, &context, self.type_chart);
+
+        for (damage_type, type_names) in damage_groups {
+            self.append_types_output(&context, damage_type, &type_names);
+        }
+    }
+
+    pub(crate) fn defence_groups(
+        type_: &Type,
+        second_type: Option<&Type>,
+        type_chart: TypeChart,
+    ) -> Vec<(DamageType, Vec<String>)> {
+        match second_type {
+            Some(second_type) => Self::dual_defence_groups(type_, second_type, type_chart),
+            None => Self::single_damage_groups(type_, &DamageContext::Defence, type_chart),
+        }
+    }
+
+    /// The multiplier a move of `type_` deals to each single type
+    pub(crate) fn offence_multipliers(
+        type_: &Type,
+        type_chart: TypeChart,
+    ) -> HashMap<String, DamageType> {
+        Self::single_damage_groups(type_, &DamageContext::Offence, type_chart)
+            .into_iter()
+            .flat_map(|(damage_type, type_names)| {
+                type_names
+                    .into_iter()
+                    .map(move |type_name| (type_name, damage_type))
+            })
+            .collect()
+    }
+
+    fn single_damage_groups(
+        type_: &Type,
+        context: &DamageContext,
+        type_chart: TypeChart,
+    ) -> Vec<(DamageType, Vec<String>)> {
+        let type_relations = type_chart.damage_relations(type_);
 
         let (no_damage_names, half_damage_names, double_damage_names) = match context {
             DamageContext::Offence => (
-                self.to_type_names(&type_relations.no_damage_to),
-                self.to_type_names(&type_relations.half_damage_to),
-                self.to_type_names(&type_relations.double_damage_to),
+                Self::to_type_names(&type_relations.no_damage_to, type_chart),
+                Self::to_type_names(&type_relations.half_damage_to, type_chart),
+                Self::to_type_names(&type_relations.double_damage_to, type_chart),
             ),
             DamageContext::Defence => (
-                self.to_type_names(&type_relations.no_damage_from),
-                self.to_type_names(&type_relations.half_damage_from),
-                self.to_type_names(&type_relations.double_damage_from),
+                Self::to_type_names(&type_relations.no_damage_from, type_chart),
+                Self::to_type_names(&type_relations.half_damage_from, type_chart),
+                Self::to_type_names(&type_relations.double_damage_from, type_chart),
             ),
         };
 
-        let normal_damage_names = self.normal_damage_names_from(
+        let normal_damage_names = Self::normal_damage_names_from(
             &no_damage_names,
             &half_damage_names,
             &double_damage_names,
+            type_chart,
         );
 
-        self.append_types_output(&context, DamageType::None, &no_damage_names);
-        self.append_types_output(&context, DamageType::Half, &half_damage_names);
-        self.append_types_output(&context, DamageType::Normal, &normal_damage_names);
-        self.append_types_output(&context, DamageType::Double, &double_damage_names);
+        vec![
+            (DamageType::None, no_damage_names),
+            (DamageType::Half, half_damage_names),
+            (DamageType::Normal, normal_damage_names),
+            (DamageType::Double, double_damage_names),
+        ]
     }
 
     fn normal_damage_names_from(
-        &self,
         no_damage_names: &[String],
         half_damage_names: &[String],
         double_damage_names: &[String],
+        type_chart: TypeChart,
     ) -> Vec<String> {
         type_names::TYPE_NAMES
             .iter()
             .filter(|type_name| {
-                !no_damage_names.contains(type_name)
+                type_chart.includes(type_name)
+                    && !no_damage_names.contains(type_name)
                     && !half_damage_names.contains(type_name)
                     && !double_damage_names.contains(type_name)
                     && !EXCLUDED_TYPES.contains(&type_name.as_str())
@@ -326,89 +594,103 @@ impl TypeCommand<'_> {
     }
 
     fn append_dual_defence_output(&mut self, type_: &Type, second_type: &Type) {
-        let (damage_relations, second_damage_relations) =
-            (&type_.damage_relations, &second_type.damage_relations);
+        let damage_groups = Self::dual_defence_groups(type_, second_type, self.type_chart);
 
-        let first_no_damage_from = self.to_type_names(&damage_relations.no_damage_from);
-        let second_no_damage_from = self.to_type_names(&second_damage_relations.no_damage_from);
+        let context = &DamageContext::Defence;
+        for (damage_type, type_names) in damage_groups {
+            self.append_types_output(context, damage_type, &type_names);
+        }
+    }
+
+    fn dual_defence_groups(
+        type_: &Type,
+        second_type: &Type,
+        type_chart: TypeChart,
+    ) -> Vec<(DamageType, Vec<String>)> {
+        let (damage_relations, second_damage_relations) = (
+            type_chart.damage_relations(type_),
+            type_chart.damage_relations(second_type),
+        );
+
+        let first_no_damage_from =
+            Self::to_type_names(&damage_relations.no_damage_from, type_chart);
+        let second_no_damage_from =
+            Self::to_type_names(&second_damage_relations.no_damage_from, type_chart);
         let no_damage_from_types =
-            self.build_combined_hash_set(first_no_damage_from, second_no_damage_from);
+            Self::build_combined_hash_set(first_no_damage_from, second_no_damage_from);
 
-        let first_half_damage_from = self.to_type_names(&damage_relations.half_damage_from);
-        let second_half_damage_from = self.to_type_names(&second_damage_relations.half_damage_from);
+        let first_half_damage_from =
+            Self::to_type_names(&damage_relations.half_damage_from, type_chart);
+        let second_half_damage_from =
+            Self::to_type_names(&second_damage_relations.half_damage_from, type_chart);
         let half_damage_counts =
-            self.build_type_counter(first_half_damage_from, second_half_damage_from);
+            Self::build_type_counter(first_half_damage_from, second_half_damage_from);
 
-        let first_double_damage_from = self.to_type_names(&damage_relations.double_damage_from);
+        let first_double_damage_from =
+            Self::to_type_names(&damage_relations.double_damage_from, type_chart);
         let second_double_damage_from =
-            self.to_type_names(&second_damage_relations.double_damage_from);
+            Self::to_type_names(&second_damage_relations.double_damage_from, type_chart);
         let double_damage_counts =
-            self.build_type_counter(first_double_damage_from, second_double_damage_from);
+            Self::build_type_counter(first_double_damage_from, second_double_damage_from);
 
-        let mut quarter_damage_types: HashSet<String> = HashSet::new();
-        let mut half_damage_types: HashSet<String> = HashSet::new();
-        let mut double_damage_types: HashSet<String> = HashSet::new();
-        let mut quad_damage_types: HashSet<String> = HashSet::new();
-        let mut normal_damage_types: HashSet<String> = HashSet::new();
+        let mut quarter_damage_types: Vec<String> = Vec::new();
+        let mut half_damage_types: Vec<String> = Vec::new();
+        let mut double_damage_types: Vec<String> = Vec::new();
+        let mut quad_damage_types: Vec<String> = Vec::new();
+        let mut normal_damage_types: Vec<String> = Vec::new();
 
         type_names::TYPE_NAMES
             .iter()
-            .filter(|type_name| !no_damage_from_types.contains(type_name.as_str()))
+            .filter(|type_name| {
+                type_chart.includes(type_name) && !no_damage_from_types.contains(type_name.as_str())
+            })
             .for_each(|type_name| {
                 let half_damage_score = -half_damage_counts.get(type_name).unwrap_or(&0);
                 let double_damage_score = double_damage_counts.get(type_name).unwrap_or(&0);
 
                 match double_damage_score + half_damage_score {
-                    -2 => {
-                        quarter_damage_types.insert(type_name.to_owned());
-                    }
-
-                    -1 => {
-                        half_damage_types.insert(type_name.to_owned());
-                    }
-
-                    1 => {
-                        double_damage_types.insert(type_name.to_owned());
-                    }
-
-                    2 => {
-                        quad_damage_types.insert(type_name.to_owned());
-                    }
-
+                    -2 => quarter_damage_types.push(type_name.to_owned()),
+                    -1 => half_damage_types.push(type_name.to_owned()),
+                    1 => double_damage_types.push(type_name.to_owned()),
+                    2 => quad_damage_types.push(type_name.to_owned()),
                     _ => {
                         if !EXCLUDED_TYPES.contains(&type_name.as_str()) {
-                            normal_damage_types.insert(type_name.to_owned());
+                            normal_damage_types.push(type_name.to_owned());
                         }
                     }
                 }
             });
 
-        let context = &DamageContext::Defence;
-        self.append_types_output(context, DamageType::None, &no_damage_from_types);
-        self.append_types_output(context, DamageType::Quarter, &quarter_damage_types);
-        self.append_types_output(context, DamageType::Half, &half_damage_types);
-        self.append_types_output(context, DamageType::Normal, &normal_damage_types);
-        self.append_types_output(context, DamageType::Double, &double_damage_types);
-        self.append_types_output(context, DamageType::Quadruple, &quad_damage_types);
+        vec![
+            (
+                DamageType::None,
+                no_damage_from_types.into_iter().collect_vec(),
+            ),
+            (DamageType::Quarter, quarter_damage_types),
+            (DamageType::Half, half_damage_types),
+            (DamageType::Normal, normal_damage_types),
+            (DamageType::Double, double_damage_types),
+            (DamageType::Quadruple, quad_damage_types),
+        ]
     }
 
-    fn build_type_counter(&self, a: Vec<String>, b: Vec<String>) -> HashMap<String, i8> {
+    fn build_type_counter(a: Vec<String>, b: Vec<String>) -> HashMap<String, i8> {
         let mut counts: HashMap<String, i8> = HashMap::new();
 
-        self.increment_counts(&mut counts, &a);
-        self.increment_counts(&mut counts, &b);
+        Self::increment_counts(&mut counts, &a);
+        Self::increment_counts(&mut counts, &b);
 
         counts
     }
 
-    fn increment_counts(&self, counts: &mut HashMap<String, i8>, vec: &[String]) {
+    fn increment_counts(counts: &mut HashMap<String, i8>, vec: &[String]) {
         for t in vec {
             let value = counts.entry(t.to_owned()).or_insert(0);
             *value += 1;
         }
     }
 
-    fn build_combined_hash_set(&self, a: Vec<String>, b: Vec<String>) -> HashSet<String> {
+    fn build_combined_hash_set(a: Vec<String>, b: Vec<String>) -> HashSet<String> {
         let mut hash_set = HashSet::new();
 
         for e in a {
@@ -422,9 +704,11 @@ impl TypeCommand<'_> {
         hash_set
     }
 
-    fn to_type_names(&self, resources: &[NamedApiResource<Type>]) -> Vec<String> {
+    // Matchups against types that didn't exist yet are left out of older charts
+    fn to_type_names(resources: &[NamedApiResource<Type>], type_chart: TypeChart) -> Vec<String> {
         resources
             .iter()
+            .filter(|type_resource| type_chart.includes(&type_resource.name))
             .map(|type_resource| type_resource.name.clone())
             .collect_vec()
     }
@@ -0,0 +1,136 @@
+use crate::{
+    Cli,
+    name_matcher::{
+        ability_names::ABILITY_NAMES, berry_names::BERRY_NAMES, egg_group_names::EGG_GROUP_NAMES,
+        generation_names::GENERATION_NAMES, item_names::ITEM_NAMES,
+        move_damage_class_names::MOVE_DAMAGE_CLASS_NAMES, move_names::MOVE_NAMES,
+        nature_names::NATURE_NAMES, pokemon_names::POKEMON_NAMES, type_names::TYPE_NAMES,
+        version_group_names::VERSION_GROUP_NAMES,
+    },
+};
+
+use clap::{Arg, ArgAction, Command, CommandFactory};
+
+/// Returns the candidates for the last word of `words`, which are the words typed so far
+/// without the binary name. The last word is the partially typed one and may be empty.
+pub fn complete(words: &[&str]) -> Vec<String> {
+    let Some((current, previous)) = words.split_last() else {
+        return Vec::new();
+    };
+
+    let root = Cli::command();
+    let mut command = &root;
+    let mut positional_index = 0;
+    let mut pending_option: Option<&Arg> = None;
+
+    for word in previous {
+        if let Some(option) = pending_option {
+            // Options taking any number of values keep consuming words until the next flag
+            if !word.starts_with('-') {
+                if !takes_many_values(option) {
+                    pending_option = None;
+                }
+                continue;
+            }
+            pending_option = None;
+        }
+
+        if word.starts_with('-') {
+            pending_option = find_option(&root, command, word).filter(|arg| takes_values(arg));
+        } else if let Some(subcommand) = command.find_subcommand(word) {
+            command = subcommand;
+            positional_index = 0;
+        } else {
+            positional_index += 1;
+        }
+    }
+
+    let candidates = if current.starts_with('-') {
+        option_names(&root, command)
+    } else if let Some(option) = pending_option {
+        arg_values(option)
+    } else if command.has_subcommands() {
+        command
+            .get_subcommands()
+            .filter(|subcommand| !subcommand.is_hide_set())
+            .map(|subcommand| subcommand.get_name().to_owned())
+            .collect()
+    } else {
+        // Positionals taking several values, e.g. `team`, complete every word after them
+        command
+            .get_positionals()
+            .nth(positional_index)
+            .or_else(|| {
+                command
+                    .get_positionals()
+                    .last()
+                    .filter(|arg| takes_many_values(arg))
+            })
+            .map(arg_values)
+            .unwrap_or_default()
+    };
+
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.starts_with(current))
+        .collect()
+}
+
+fn find_option<'a>(root: &'a Command, command: &'a Command, word: &str) -> Option<&'a Arg> {
+    let matches = |arg: &&Arg| match word.strip_prefix("--") {
+        Some(long) => arg.get_long() == Some(long),
+        None => word.len() == 2 && arg.get_short() == word.chars().nth(1),
+    };
+
+    command
+        .get_arguments()
+        .find(matches)
+        .or_else(|| root.get_arguments().find(matches))
+}
+
+fn takes_values(arg: &Arg) -> bool {
+    matches!(arg.get_action(), ArgAction::Set | ArgAction::Append)
+}
+
+fn takes_many_values(arg: &Arg) -> bool {
+    arg.get_num_args()
+        .is_some_and(|num_args| num_args.max_values() > 1)
+}
+
+fn option_names(root: &Command, command: &Command) -> Vec<String> {
+    let global_arguments = root.get_arguments().filter(|arg| arg.is_global_set());
+
+    command
+        .get_arguments()
+        .chain(global_arguments)
+        .filter_map(|arg| arg.get_long().map(|long| format!("--{long}")))
+        .chain([String::from("--help")])
+        .collect()
+}
+
+fn arg_values(arg: &Arg) -> Vec<String> {
+    let possible_values = arg.get_possible_values();
+    if !possible_values.is_empty() {
+        return possible_values
+            .iter()
+            .map(|value| value.get_name().to_owned())
+            .collect();
+    }
+
+    let names: &[String] = match arg.get_id().as_str() {
+        "ability" => &ABILITY_NAMES,
+        "berry" => &BERRY_NAMES,
+        "egg_group" => &EGG_GROUP_NAMES,
+        "generation" => &GENERATION_NAMES,
+        "item" => &ITEM_NAMES,
+        "move_name" | "moves" => &MOVE_NAMES,
+        "nature" | "attacker_nature" | "defender_nature" => &NATURE_NAMES,
+        "pokemon" | "partner" | "attacker" | "defender" => &POKEMON_NAMES,
+        "type_name" | "second_type_name" | "type_names" | "types" => &TYPE_NAMES,
+        "categories" => &MOVE_DAMAGE_CLASS_NAMES,
+        "version_group" => &VERSION_GROUP_NAMES,
+        _ => &[],
+    };
+
+    names.to_vec()
+}
@@ -0,0 +1,49 @@
+use std::{fs, io::ErrorKind, path::PathBuf};
+
+use serde_json::Value;
+
+const CONFIG_FILE_NAME: &str = "config.json";
+
+/// Settings read from `~/.config/poke_search/config.json`, which are used when the matching
+/// flag or environment variable isn't set.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub api_url: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, String> {
+        let Some(path) = Self::get_config_path() else {
+            return Ok(Self::default());
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|error| format!("{}: {error}", path.display()))
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("Failed to read {}: {error}", path.display())),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let config: Value = serde_json::from_str(contents).map_err(|error| error.to_string())?;
+
+        let api_url = match config.get("api_url") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(api_url)) => Some(api_url.clone()),
+            Some(_) => return Err(String::from("\"api_url\" must be a string")),
+        };
+
+        Ok(Config { api_url })
+    }
+
+    pub fn get_config_path() -> Option<PathBuf> {
+        let config_dir = std::env::var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|_| std::env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .ok()?;
+
+        Some(config_dir.join("poke_search").join(CONFIG_FILE_NAME))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{builder::Builder, formatter};
+
+use itertools::Itertools;
+use serde_json::json;
+
+/// One item of a fan-out fetch which couldn't be loaded. The rest of the items are still shown,
+/// followed by a list of these
+pub struct FetchFailure {
+    pub keyword: &'static str,
+    pub name: String,
+    pub error: String,
+}
+
+impl FetchFailure {
+    pub fn new(keyword: &'static str, name: &str, error: impl ToString) -> Self {
+        FetchFailure {
+            keyword,
+            name: name.to_owned(),
+            error: error.to_string(),
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "resource": self.keyword,
+            "name": self.name,
+            "error": self.error,
+        })
+    }
+}
+
+pub fn failures_json(failures: &[FetchFailure]) -> serde_json::Value {
+    json!(failures.iter().map(FetchFailure::to_json).collect_vec())
+}
+
+pub fn append_failures(builder: &mut Builder, failures: &[FetchFailure]) {
+    if failures.is_empty() {
+        return;
+    }
+
+    let noun = if failures.len() == 1 { "item" } else { "items" };
+
+    builder.newline();
+    builder.appendln(formatter::white(&format!(
+        "{} {noun} failed to load",
+        failures.len()
+    )));
+
+    for failure in failures {
+        builder.appendln(format!(
+            "  {} \"{}\": {}",
+            formatter::capitalise(failure.keyword),
+            failure.name,
+            formatter::red(&failure.error)
+        ));
+    }
+}
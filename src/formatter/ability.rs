@@ -1,9 +1,10 @@
 use super::FormatModel;
-use crate::formatter::utils::{extract_effect, formatln, split_and_capitalise, white};
+use crate::formatter::utils::{extract_effect, find_effect, formatln, split_and_capitalise, white};
 
 use std::rc::Rc;
 
 use rustemon::model::pokemon::{Ability, Pokemon};
+use serde_json::json;
 
 pub struct FormatAbility {
     ability: Ability,
@@ -41,17 +42,19 @@ impl FormatAbility {
     }
 
     fn hidden_value(&self) -> Option<String> {
+        Some(self.is_hidden()?.to_string())
+    }
+
+    fn is_hidden(&self) -> Option<bool> {
         let pokemon = self.pokemon.clone()?;
 
-        let is_hidden = self.ability.pokemon.iter().find_map(|ability_pokemon| {
+        self.ability.pokemon.iter().find_map(|ability_pokemon| {
             if ability_pokemon.pokemon.name == pokemon.name {
                 Some(ability_pokemon.is_hidden)
             } else {
                 None
             }
-        })?;
-
-        Some(is_hidden.to_string())
+        })
     }
 
     fn ability_effect(&self) -> Option<String> {
@@ -71,4 +74,12 @@ impl FormatModel for FormatAbility {
 
         output
     }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "name": self.ability.name,
+            "hidden": self.is_hidden(),
+            "description": find_effect(&self.ability.effect_entries, self.verbose),
+        })
+    }
 }
@@ -0,0 +1,113 @@
+use crate::{
+    formatter::utils::{formatln, split_and_capitalise, white},
+    type_badge::{self},
+};
+
+use super::FormatModel;
+
+use itertools::Itertools;
+use rustemon::model::berries::Berry;
+use serde_json::json;
+
+pub struct FormatBerry {
+    berry: Berry,
+}
+
+impl FormatBerry {
+    pub fn new(berry: Berry) -> Self {
+        Self { berry }
+    }
+
+    /// The name of the item this berry is held as, e.g. "cheri-berry"
+    pub fn item_name(&self) -> &str {
+        &self.berry.item.name
+    }
+
+    fn build_flavours(&self, output: &mut String) {
+        // Every berry lists all five flavours, most of them with no potency at all
+        let flavours = self
+            .berry
+            .flavors
+            .iter()
+            .filter(|flavour| flavour.potency > 0)
+            .map(|flavour| {
+                format!(
+                    "{} {}",
+                    split_and_capitalise(&flavour.flavor.name),
+                    flavour.potency
+                )
+            })
+            .join(" | ");
+
+        let flavours = if flavours.is_empty() {
+            String::from("None")
+        } else {
+            flavours
+        };
+
+        output.push_str(&formatln(&white("Flavours"), &flavours));
+    }
+
+    fn build_growth(&self, output: &mut String) {
+        output.push_str(&formatln(
+            &white("Growth Time"),
+            &format!("{} hours per growth stage", self.berry.growth_time),
+        ));
+        output.push_str(&formatln(
+            &white("Max Harvest"),
+            &self.berry.max_harvest.to_string(),
+        ));
+    }
+}
+
+impl FormatModel for FormatBerry {
+    fn format(&self) -> String {
+        let mut output = String::new();
+
+        let berry_name = split_and_capitalise(&self.berry.name);
+        output.push_str(&formatln(&white("Name"), &berry_name));
+        output.push_str(&formatln(
+            &white("Firmness"),
+            &split_and_capitalise(&self.berry.firmness.name),
+        ));
+
+        self.build_flavours(&mut output);
+
+        output.push_str(&formatln(
+            &white("Natural Gift Type"),
+            &type_badge::fetch(&self.berry.natural_gift_type.name),
+        ));
+        output.push_str(&formatln(
+            &white("Natural Gift Power"),
+            &self.berry.natural_gift_power.to_string(),
+        ));
+
+        self.build_growth(&mut output);
+
+        output
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        let flavours = self
+            .berry
+            .flavors
+            .iter()
+            .map(|flavour| {
+                json!({
+                    "flavour": flavour.flavor.name,
+                    "potency": flavour.potency,
+                })
+            })
+            .collect_vec();
+
+        json!({
+            "name": self.berry.name,
+            "firmness": self.berry.firmness.name,
+            "flavours": flavours,
+            "natural_gift_type": self.berry.natural_gift_type.name,
+            "natural_gift_power": self.berry.natural_gift_power,
+            "growth_time": self.berry.growth_time,
+            "max_harvest": self.berry.max_harvest,
+        })
+    }
+}
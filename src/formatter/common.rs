@@ -1,3 +1,4 @@
 pub trait FormatModel {
     fn format(&self) -> String;
+    fn to_json(&self) -> serde_json::Value;
 }
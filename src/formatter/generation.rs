@@ -1,5 +1,6 @@
 use crate::formatter::{self, FormatModel};
 use rustemon::model::games::Generation;
+use serde_json::json;
 
 pub struct FormatGeneration {
     generation: Generation,
@@ -48,64 +49,64 @@ impl FormatGeneration {
         self.generation.abilities.len()
     }
 
-    fn build_pokemon_list(&self, output: &mut String) {
-        let generation = &self.generation;
-
-        output.push_str(&formatter::white(&format!(
-            "Pokemon ({})",
-            generation.pokemon_species.len()
-        )));
-        output.push('\n');
+    fn pokemon_names(&self) -> Vec<&str> {
+        let mut pokemon_names = self
+            .generation
+            .pokemon_species
+            .iter()
+            .map(|species| species.name.as_str())
+            .collect::<Vec<_>>();
 
-        let mut pokemon_species = generation.pokemon_species.iter().collect::<Vec<_>>();
-        pokemon_species.sort_by_key(|p| &p.name);
+        pokemon_names.sort();
+        pokemon_names
+    }
 
-        let pokemon_names: Vec<String> = pokemon_species
-            .into_iter()
-            .map(|species| formatter::split_and_capitalise(&species.name))
-            .collect();
+    fn ability_names(&self) -> Vec<&str> {
+        let mut ability_names = self
+            .generation
+            .abilities
+            .iter()
+            .map(|ability| ability.name.as_str())
+            .collect::<Vec<_>>();
 
-        output.push_str(&formatter::format_columns(&pokemon_names, 4));
+        ability_names.sort();
+        ability_names
     }
 
-    fn build_ability_list(&self, output: &mut String) {
-        let generation = &self.generation;
-
-        output.push_str(&formatter::white(&format!(
-            "Abilities ({})",
-            generation.abilities.len()
-        )));
-        output.push('\n');
+    fn move_names(&self) -> Vec<&str> {
+        let mut move_names = self
+            .generation
+            .moves
+            .iter()
+            .map(|move_| move_.name.as_str())
+            .collect::<Vec<_>>();
 
-        let mut abilities = generation.abilities.clone();
-        abilities.sort_by_key(|a| a.name.clone());
+        move_names.sort();
+        move_names
+    }
 
-        let ability_names: Vec<String> = abilities
-            .iter()
-            .map(|ability| formatter::split_and_capitalise(&ability.name))
-            .collect();
+    fn build_pokemon_list(&self, output: &mut String) {
+        self.build_list(output, "Pokemon", self.pokemon_names());
+    }
 
-        output.push_str(&formatter::format_columns(&ability_names, 4));
+    fn build_ability_list(&self, output: &mut String) {
+        self.build_list(output, "Abilities", self.ability_names());
     }
 
     fn build_move_list(&self, output: &mut String) {
-        let generation = &self.generation;
+        self.build_list(output, "Moves", self.move_names());
+    }
 
-        output.push_str(&formatter::white(&format!(
-            "Moves ({})",
-            generation.moves.len()
-        )));
+    fn build_list(&self, output: &mut String, title: &str, names: Vec<&str>) {
+        output.push_str(&formatter::white(&format!("{title} ({})", names.len())));
         output.push('\n');
 
-        let mut moves = generation.moves.clone();
-        moves.sort_by_key(|m| m.name.clone());
-
-        let move_names: Vec<String> = moves
-            .iter()
-            .map(|move_| formatter::split_and_capitalise(&move_.name))
+        let formatted_names: Vec<String> = names
+            .into_iter()
+            .map(formatter::split_and_capitalise)
             .collect();
 
-        output.push_str(&formatter::format_columns(&move_names, 4));
+        output.push_str(&formatter::format_columns(&formatted_names, 4));
     }
 }
 
@@ -154,4 +155,28 @@ impl FormatModel for FormatGeneration {
 
         output
     }
+
+    fn to_json(&self) -> serde_json::Value {
+        let mut json = json!({
+            "name": self.name(),
+            "main_region": self.generation.main_region.name,
+            "pokemon_count": self.pokemon_count(),
+            "moves_count": self.moves_count(),
+            "abilities_count": self.abilities_count(),
+        });
+
+        if self.show_pokemon {
+            json["pokemon"] = json!(self.pokemon_names());
+        }
+
+        if self.show_abilities {
+            json["abilities"] = json!(self.ability_names());
+        }
+
+        if self.show_moves {
+            json["moves"] = json!(self.move_names());
+        }
+
+        json
+    }
 }
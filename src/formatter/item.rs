@@ -1,7 +1,8 @@
 use super::FormatModel;
-use crate::formatter::utils::{extract_effect, formatln, split_and_capitalise, white};
+use crate::formatter::utils::{extract_effect, find_effect, formatln, split_and_capitalise, white};
 
 use rustemon::model::items::Item;
+use serde_json::json;
 
 pub struct FormatItem {
     item: Item,
@@ -48,4 +49,12 @@ impl FormatModel for FormatItem {
 
         output
     }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "name": self.item.name,
+            "category": self.item.category.name,
+            "effect": find_effect(&self.item.effect_entries, self.verbose),
+        })
+    }
 }
@@ -1,4 +1,5 @@
 pub mod ability;
+pub mod berry;
 pub mod common;
 pub mod generation;
 pub mod item;
@@ -7,6 +8,7 @@ pub mod pokemon;
 pub mod utils;
 
 pub use ability::FormatAbility;
+pub use berry::FormatBerry;
 pub use common::FormatModel;
 pub use generation::FormatGeneration;
 pub use item::FormatItem;
@@ -10,11 +10,16 @@ use super::FormatModel;
 use rustemon::model::{
     moves::{Move, MoveLearnMethod},
     pokemon::PokemonMoveVersion,
+    resource::VerboseEffect,
 };
+use serde_json::json;
 
+// The learn method and level come from the pokemon's move list, the learn method resource is
+// only needed for its description
 struct MoveDetails {
     level_learned_at: i64,
-    move_learn_method: MoveLearnMethod,
+    learn_method: String,
+    move_learn_method: Option<MoveLearnMethod>,
 }
 
 pub struct FormatMove {
@@ -30,24 +35,45 @@ impl FormatMove {
         }
     }
 
-    pub fn with_maybe_details(
+    pub fn with_details(
         move_: Move,
+        version_group_details: &PokemonMoveVersion,
         move_learn_method: Option<MoveLearnMethod>,
-        version_group_details: Option<&PokemonMoveVersion>,
     ) -> Self {
-        if let (Some(move_learn_method), Some(version_group_details)) =
-            (move_learn_method, version_group_details)
-        {
-            return FormatMove {
-                move_,
-                move_details: Some(MoveDetails {
-                    move_learn_method,
-                    level_learned_at: version_group_details.level_learned_at,
-                }),
-            };
+        FormatMove {
+            move_,
+            move_details: Some(MoveDetails {
+                level_learned_at: version_group_details.level_learned_at,
+                learn_method: version_group_details.move_learn_method.name.clone(),
+                move_learn_method,
+            }),
         }
+    }
+
+    pub fn type_name(&self) -> &str {
+        &self.move_.type_.name
+    }
+
+    pub fn damage_class(&self) -> &str {
+        &self.move_.damage_class.name
+    }
 
-        Self::new(move_)
+    /// How the move is learned, if it was built with the details of a pokemon learning it
+    pub fn learn_method(&self) -> Option<&str> {
+        self.move_details
+            .as_ref()
+            .map(|move_details| move_details.learn_method.as_str())
+    }
+
+    pub fn level_learned_at(&self) -> Option<i64> {
+        self.move_details
+            .as_ref()
+            .map(|move_details| move_details.level_learned_at)
+    }
+
+    /// Status moves never deal damage, so they don't count towards type coverage
+    pub fn is_damaging(&self) -> bool {
+        self.damage_class() != "status"
     }
 
     fn build_summary(&self, output: &mut String) {
@@ -82,7 +108,7 @@ impl FormatMove {
             output.push_str(&formatln(&white("Description"), &flavour_text));
         }
 
-        self.build_effects(power, output);
+        self.build_effects(output);
     }
 
     fn flavour_text(&self) -> Option<String> {
@@ -97,30 +123,38 @@ impl FormatMove {
         Some(text.replace('\n', " "))
     }
 
-    fn build_effects(&self, power: String, output: &mut String) {
-        let effect_chance = format!("{}%", parse_maybe_i64(self.move_.effect_chance));
-        self.move_.effect_entries.iter().for_each(|entry| {
-            if entry.language.name != "en" {
-                return;
-            }
-
-            let description = if power == "-" {
-                entry.effect.replace('\n', " ").replace("  ", " ")
-            } else {
-                entry
-                    .short_effect
-                    .replace("$effect_chance%", &effect_chance)
-            };
-
+    fn build_effects(&self, output: &mut String) {
+        self.english_effect_entries().for_each(|entry| {
+            let description = self.effect_description(entry);
             let wrapped_description = clean_and_wrap_text(&description, 4, 80);
             output.push_str(&formatln(&white("Effect"), &wrapped_description));
         });
     }
 
+    fn english_effect_entries(&self) -> impl Iterator<Item = &VerboseEffect> {
+        self.move_
+            .effect_entries
+            .iter()
+            .filter(|entry| entry.language.name == "en")
+    }
+
+    fn effect_description(&self, entry: &VerboseEffect) -> String {
+        if self.move_.power.is_none() {
+            entry.effect.replace('\n', " ").replace("  ", " ")
+        } else {
+            let effect_chance = format!("{}%", parse_maybe_i64(self.move_.effect_chance));
+            entry
+                .short_effect
+                .replace("$effect_chance%", &effect_chance)
+        }
+    }
+
     fn build_move_learn_details(&self, output: &mut String) {
         if let Some(move_details) = &self.move_details {
-            if let Some(description) =
-                self.find_move_learn_description(&move_details.move_learn_method)
+            if let Some(description) = move_details
+                .move_learn_method
+                .as_ref()
+                .and_then(|move_learn_method| self.find_move_learn_description(move_learn_method))
             {
                 output.push_str(&formatln(&white("Learn Method"), &description));
             }
@@ -155,4 +189,31 @@ impl FormatModel for FormatMove {
 
         output
     }
+
+    fn to_json(&self) -> serde_json::Value {
+        let move_ = &self.move_;
+        let effects = self
+            .english_effect_entries()
+            .map(|entry| self.effect_description(entry))
+            .collect::<Vec<_>>();
+
+        let mut json = json!({
+            "name": move_.name,
+            "type": move_.type_.name,
+            "damage_class": move_.damage_class.name,
+            "power": move_.power,
+            "accuracy": move_.accuracy,
+            "pp": move_.pp,
+            "priority": move_.priority,
+            "description": self.flavour_text(),
+            "effects": effects,
+        });
+
+        if let Some(move_details) = &self.move_details {
+            json["learn_method"] = json!(move_details.learn_method);
+            json["learn_level"] = json!(move_details.level_learned_at);
+        }
+
+        json
+    }
 }
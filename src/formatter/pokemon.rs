@@ -1,5 +1,10 @@
 use crate::{
-    formatter::utils::{formatln, split_and_capitalise, white},
+    client::{ClientError, ClientImplementation},
+    formatter::utils::{
+        clean_and_wrap_text, format_egg_group_name, formatln, split_and_capitalise, white,
+    },
+    name_matcher::matcher,
+    stat_calculator::{self, StatSpread},
     type_badge::{self},
 };
 
@@ -7,6 +12,7 @@ use super::FormatModel;
 
 use itertools::Itertools;
 use rustemon::model::pokemon::{Pokemon, PokemonSpecies};
+use serde_json::{Map, json};
 
 pub struct FormatPokemon {
     pokemon: Pokemon,
@@ -18,68 +24,256 @@ impl FormatPokemon {
         Self { pokemon, species }
     }
 
+    /// Fetches a pokemon and its species by a name which may need correcting
+    pub(crate) async fn fetch(
+        client: &dyn ClientImplementation,
+        pokemon_name: &str,
+    ) -> Result<FormatPokemon, String> {
+        let successful_match =
+            matcher::match_pokemon_name(pokemon_name).map_err(|no_match| no_match.0)?;
+
+        let result = client.fetch_pokemon(&successful_match.suggested_name).await;
+
+        let pokemon = match result {
+            Ok(pokemon) => pokemon,
+            Err(ClientError::Rustemon(_) | ClientError::NotFound(_)) => {
+                return Err(matcher::build_unknown_name(
+                    &successful_match.keyword,
+                    &successful_match.suggested_name,
+                ));
+            }
+            Err(error) => return Err(error.to_string()),
+        };
+
+        let species = client
+            .fetch_pokemon_species(&pokemon.species.name)
+            .await
+            .map_err(|error| error.to_string())?;
+
+        Ok(Self::new(pokemon, species))
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.pokemon.name
+    }
+
+    pub(crate) fn species(&self) -> &PokemonSpecies {
+        &self.species
+    }
+
+    pub(crate) fn base_stats(&self) -> StatSpread {
+        stat_calculator::base_stats(&self.pokemon)
+    }
+
     fn build_summary(&self, output: &mut String) {
         let formatted_name = split_and_capitalise(&self.pokemon.name);
         output.push_str(&formatln(&white("Name"), &formatted_name));
 
+        if let Some(genus) = self.genus() {
+            output.push_str(&formatln(&white("Genus"), genus));
+        }
+
         self.build_joined_types(output);
         self.build_joined_abilities(output);
         self.build_generation(output);
         self.build_effort_values(output);
+        self.build_species_details(output);
+    }
+
+    fn build_species_details(&self, output: &mut String) {
+        let form_names = self.form_names();
+        if !form_names.is_empty() {
+            let joined_forms = form_names.into_iter().map(split_and_capitalise).join(" | ");
+            output.push_str(&formatln(&white("Other Forms"), &joined_forms));
+        }
+
+        let joined_egg_groups = self
+            .egg_group_names()
+            .into_iter()
+            .map(format_egg_group_name)
+            .join(" | ");
+        output.push_str(&formatln(&white("Egg Groups"), &joined_egg_groups));
+
+        output.push_str(&formatln(
+            &white("Gender Ratio"),
+            &self.formatted_gender_ratio(),
+        ));
+
+        output.push_str(&formatln(
+            &white("Capture Rate"),
+            &self.species.capture_rate.to_string(),
+        ));
+
+        if let Some(base_happiness) = self.species.base_happiness {
+            output.push_str(&formatln(
+                &white("Base Happiness"),
+                &base_happiness.to_string(),
+            ));
+        }
+
+        output.push_str(&formatln(
+            &white("Growth Rate"),
+            &split_and_capitalise(&self.species.growth_rate.name),
+        ));
+
+        if let Some(hatch_counter) = self.species.hatch_counter {
+            output.push_str(&formatln(
+                &white("Hatch Counter"),
+                &format!("{hatch_counter} egg cycles"),
+            ));
+        }
+
+        let flags = self.flags();
+        if !flags.is_empty() {
+            output.push_str(&formatln(&white("Status"), &flags.join(" | ")));
+        }
+
+        if let Some(flavour_text) = self.flavour_text() {
+            output.push_str(&formatln(
+                &white("Description"),
+                &clean_and_wrap_text(&flavour_text, 4, 80),
+            ));
+        }
+    }
+
+    fn genus(&self) -> Option<&str> {
+        self.species
+            .genera
+            .iter()
+            .find(|genus| genus.language.name == "en")
+            .map(|genus| genus.genus.as_str())
+    }
+
+    /// Every other variety of this pokemon's species, such as megas and regional forms
+    pub(crate) fn form_names(&self) -> Vec<&str> {
+        self.species
+            .varieties
+            .iter()
+            .map(|variety| variety.pokemon.name.as_str())
+            .filter(|name| *name != self.pokemon.name)
+            .collect_vec()
+    }
+
+    pub(crate) fn egg_group_names(&self) -> Vec<&str> {
+        self.species
+            .egg_groups
+            .iter()
+            .map(|egg_group| egg_group.name.as_str())
+            .collect_vec()
+    }
+
+    /// The male and female percentages, or `None` for genderless pokemon. The API stores the
+    /// chance of being female in eighths
+    pub(crate) fn gender_ratio(&self) -> Option<(f64, f64)> {
+        let female_eighths = self.species.gender_rate;
+        if female_eighths < 0 {
+            return None;
+        }
+
+        let female = female_eighths as f64 * 12.5;
+        Some((100.0 - female, female))
+    }
+
+    pub(crate) fn formatted_gender_ratio(&self) -> String {
+        match self.gender_ratio() {
+            Some((male, female)) => format!("{male}% Male | {female}% Female"),
+            None => String::from("Genderless"),
+        }
+    }
+
+    fn flags(&self) -> Vec<&'static str> {
+        [
+            (self.species.is_legendary, "Legendary"),
+            (self.species.is_mythical, "Mythical"),
+            (self.species.is_baby, "Baby"),
+        ]
+        .into_iter()
+        .filter_map(|(is_set, flag)| is_set.then_some(flag))
+        .collect_vec()
+    }
+
+    // Entries are ordered by game so the last one is the most recent description
+    fn flavour_text(&self) -> Option<String> {
+        let entry = self
+            .species
+            .flavor_text_entries
+            .iter()
+            .rfind(|entry| entry.language.name == "en")?;
+
+        Some(entry.flavor_text.split_whitespace().join(" "))
     }
 
     fn build_joined_types(&self, output: &mut String) {
         let joined_types = self
-            .pokemon
-            .types
-            .iter()
-            .map(|pokemon_type| type_badge::fetch(&pokemon_type.type_.name))
+            .type_names()
+            .into_iter()
+            .map(type_badge::fetch)
             .join(" | ");
 
         output.push_str(&formatln(&white("Type"), &joined_types));
     }
 
-    fn build_joined_abilities(&self, output: &mut String) {
-        let unique_abilities = self
-            .pokemon
+    pub(crate) fn type_names(&self) -> Vec<&str> {
+        self.pokemon
+            .types
+            .iter()
+            .map(|pokemon_type| pokemon_type.type_.name.as_str())
+            .collect_vec()
+    }
+
+    pub(crate) fn ability_names(&self) -> Vec<&str> {
+        self.pokemon
             .abilities
             .iter()
             .filter_map(|pokemon_ability| pokemon_ability.ability.as_ref())
-            .unique_by(|ability| &ability.name);
+            .unique_by(|ability| &ability.name)
+            .map(|ability| ability.name.as_str())
+            .collect_vec()
+    }
 
-        let joined_abilities = unique_abilities
-            .map(|ability| split_and_capitalise(&ability.name))
+    fn build_joined_abilities(&self, output: &mut String) {
+        let joined_abilities = self
+            .ability_names()
+            .into_iter()
+            .map(split_and_capitalise)
             .join(" | ");
 
         output.push_str(&formatln(&white("Abilities"), &joined_abilities));
     }
 
+    pub(crate) fn generation_numeral(&self) -> Option<String> {
+        self.species
+            .generation
+            .name
+            .split('-')
+            .next_back()
+            .map(str::to_uppercase)
+    }
+
     fn build_generation(&self, output: &mut String) {
-        if let Some(generation_numeral) = self.species.generation.name.split('-').next_back() {
-            output.push_str(&formatln(
-                &white("Generation"),
-                &generation_numeral.to_uppercase(),
-            ))
+        if let Some(generation_numeral) = self.generation_numeral() {
+            output.push_str(&formatln(&white("Generation"), &generation_numeral))
         }
     }
 
+    /// The stats defeating this pokemon gives EVs in, along with how many
+    pub(crate) fn effort_values(&self) -> Vec<(&str, i64)> {
+        self.pokemon
+            .stats
+            .iter()
+            .filter(|pokemon_stat| pokemon_stat.effort > 0)
+            .map(|pokemon_stat| (pokemon_stat.stat.name.as_str(), pokemon_stat.effort))
+            .collect_vec()
+    }
+
     fn build_effort_values(&self, output: &mut String) {
-        let mut effort_values = self.pokemon.stats.iter().filter_map(|pokemon_stat| {
-            if pokemon_stat.effort > 0 {
-                Some(format!(
-                    "{} +{}",
-                    split_and_capitalise(&pokemon_stat.stat.name),
-                    pokemon_stat.effort
-                ))
-            } else {
-                None
-            }
-        });
+        let joined_effort_values = self
+            .effort_values()
+            .into_iter()
+            .map(|(stat_name, effort)| format!("{} +{effort}", split_and_capitalise(stat_name)))
+            .join(" | ");
 
-        output.push_str(&formatln(
-            &white("Effort Values"),
-            &effort_values.join(" | "),
-        ))
+        output.push_str(&formatln(&white("Effort Values"), &joined_effort_values))
     }
 }
 
@@ -91,4 +285,32 @@ impl FormatModel for FormatPokemon {
 
         output
     }
+
+    fn to_json(&self) -> serde_json::Value {
+        let effort_values = self
+            .effort_values()
+            .into_iter()
+            .map(|(stat_name, effort)| (stat_name.to_owned(), json!(effort)))
+            .collect::<Map<_, _>>();
+
+        json!({
+            "name": self.pokemon.name,
+            "types": self.type_names(),
+            "abilities": self.ability_names(),
+            "generation": self.species.generation.name,
+            "effort_values": effort_values,
+            "genus": self.genus(),
+            "other_forms": self.form_names(),
+            "description": self.flavour_text(),
+            "egg_groups": self.egg_group_names(),
+            "gender_ratio": self.gender_ratio().map(|(male, female)| json!({ "male": male, "female": female })),
+            "capture_rate": self.species.capture_rate,
+            "base_happiness": self.species.base_happiness,
+            "growth_rate": self.species.growth_rate.name,
+            "hatch_counter": self.species.hatch_counter,
+            "is_legendary": self.species.is_legendary,
+            "is_mythical": self.species.is_mythical,
+            "is_baby": self.species.is_baby,
+        })
+    }
 }
@@ -1,3 +1,5 @@
+use crate::name_matcher::egg_group_names::EGG_GROUP_GAME_NAMES;
+
 use colored::{ColoredString, Colorize};
 use itertools::Itertools;
 use rustemon::model::resource::VerboseEffect;
@@ -19,6 +21,16 @@ pub fn split_and_capitalise(s: &str) -> String {
     s.split('-').map(capitalise).join(" ")
 }
 
+pub fn format_egg_group_name(egg_group_name: &str) -> String {
+    EGG_GROUP_GAME_NAMES
+        .iter()
+        .find(|(api_name, _)| *api_name == egg_group_name)
+        .map_or_else(
+            || split_and_capitalise(egg_group_name),
+            |(_, game_name)| String::from(*game_name),
+        )
+}
+
 pub fn format_columns(items: &[String], num_columns: usize) -> String {
     if items.is_empty() {
         return String::new();
@@ -58,20 +70,24 @@ pub(crate) fn formatln(title: &str, value: &str) -> String {
 }
 
 pub(crate) fn extract_effect(effect_entries: &[VerboseEffect], verbose: bool) -> Option<String> {
-    let formatted_effect = effect_entries.iter().find_map(|verbose_effect| {
+    let effect = find_effect(effect_entries, verbose)?;
+
+    Some(clean_and_wrap_text(effect, 4, 80))
+}
+
+pub(crate) fn find_effect(effect_entries: &[VerboseEffect], verbose: bool) -> Option<&str> {
+    effect_entries.iter().find_map(|verbose_effect| {
         if verbose_effect.language.name == "en" {
             let effect = if verbose {
                 &verbose_effect.effect
             } else {
                 &verbose_effect.short_effect
             };
-            Some(clean_and_wrap_text(effect, 4, 80))
+            Some(effect.as_str())
         } else {
             None
         }
-    })?;
-
-    Some(formatted_effect)
+    })
 }
 
 pub(crate) fn parse_maybe_i64(value: Option<i64>) -> String {
@@ -1,10 +1,62 @@
-use crate::{name_matcher::matcher, roman_numeral::integer_to_roman};
+use crate::{
+    commands::type_command::TypeChart,
+    name_matcher::{
+        egg_group_names::EGG_GROUP_GAME_NAMES, generation_names::GENERATION_NAMES, matcher,
+    },
+    roman_numeral::integer_to_roman,
+    search_query::SearchStat,
+    stat_calculator::{MAX_EV, MAX_IV, MAX_TOTAL_EVS, STAT_COUNT, StatSpread},
+};
 use itertools::Itertools;
 
+use std::time::Duration;
+
+// How forms are usually written before the species name, and the suffix PokeAPI uses for them
+const FORM_PREFIXES: &[(&str, &str)] = &[
+    ("alolan", "alola"),
+    ("galarian", "galar"),
+    ("gigantamax", "gmax"),
+    ("gmax", "gmax"),
+    ("hisuian", "hisui"),
+    ("mega", "mega"),
+    ("paldean", "paldea"),
+    ("primal", "primal"),
+];
+
 pub fn parse_name(name: &str) -> String {
     name.to_lowercase().split(' ').join("-")
 }
 
+/// Like `parse_name` but also turns form-qualified names such as "Alolan Raichu" or
+/// "Mega Charizard X" into the names PokeAPI uses ("raichu-alola", "charizard-mega-x"). PokeAPI
+/// drops the dots from names like "Mr. Mime", so they're dropped here too
+pub fn parse_pokemon_name(name: &str) -> String {
+    let name = parse_name(name).replace('.', "");
+
+    let Some((species, suffix)) = FORM_PREFIXES.iter().find_map(|(prefix, suffix)| {
+        name.strip_prefix(prefix)?
+            .strip_prefix('-')
+            .map(|species| (species, suffix))
+    }) else {
+        return name;
+    };
+
+    match species.rsplit_once('-') {
+        Some((species, variant @ ("x" | "y"))) => format!("{species}-{suffix}-{variant}"),
+        _ => format!("{species}-{suffix}"),
+    }
+}
+
+/// Like `parse_name` but also accepts the in-game egg group names, e.g. "Field" for "ground"
+pub fn parse_egg_group_name(name: &str) -> String {
+    let name = parse_name(name);
+
+    EGG_GROUP_GAME_NAMES
+        .iter()
+        .find(|(_, game_name)| parse_name(game_name) == name)
+        .map_or(name, |(api_name, _)| String::from(*api_name))
+}
+
 pub fn parse_generation(generation_name: &str) -> Result<String, String> {
     let normalised = parse_name(generation_name);
 
@@ -34,6 +86,97 @@ pub fn parse_generation(generation_name: &str) -> Result<String, String> {
     }
 }
 
+/// The number of a generation from its PokeAPI name, e.g. 4 for "generation-iv"
+pub fn generation_number(generation_name: &str) -> Option<i64> {
+    let numeral = generation_name.strip_prefix("generation-")?;
+
+    (1..=GENERATION_NAMES.len() as i32)
+        .find(|number| integer_to_roman(*number) == numeral)
+        .map(i64::from)
+}
+
+pub fn parse_type_chart(generation_name: &str) -> Result<TypeChart, String> {
+    let generation = parse_generation(generation_name)?;
+
+    generation_number(&generation)
+        .map(TypeChart::Generation)
+        .ok_or_else(|| format!("Invalid generation \"{generation_name}\""))
+}
+
+pub fn parse_duration(duration: &str) -> Result<Duration, String> {
+    let invalid_duration = || {
+        format!(
+            "'{duration}' isn't a valid duration. Use a number followed by s, m, h, d or w (e.g. 30d)"
+        )
+    };
+
+    let trimmed = duration.trim();
+    let unit_index = trimmed
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid_duration)?;
+    let (amount, unit) = trimmed.split_at(unit_index);
+    let amount = amount.parse::<u64>().map_err(|_| invalid_duration())?;
+
+    let seconds_per_unit = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        "w" => 60 * 60 * 24 * 7,
+        _ => return Err(invalid_duration()),
+    };
+
+    let seconds = amount
+        .checked_mul(seconds_per_unit)
+        .ok_or_else(invalid_duration)?;
+
+    Ok(Duration::from_secs(seconds))
+}
+
+pub fn parse_search_stat(stat: &str) -> Result<SearchStat, String> {
+    SearchStat::find(stat).ok_or_else(|| {
+        format!("'{stat}' isn't a valid stat. Use hp, atk, def, spa, spd, spe or bst")
+    })
+}
+
+pub fn parse_ivs(ivs: &str) -> Result<StatSpread, String> {
+    parse_stat_spread(ivs, MAX_IV)
+}
+
+pub fn parse_evs(evs: &str) -> Result<StatSpread, String> {
+    let spread = parse_stat_spread(evs, MAX_EV)?;
+
+    if spread.iter().sum::<i64>() > MAX_TOTAL_EVS {
+        return Err(format!(
+            "'{evs}' adds up to more than the {MAX_TOTAL_EVS} EVs a pokemon can have"
+        ));
+    }
+
+    Ok(spread)
+}
+
+fn parse_stat_spread(spread: &str, max_value: i64) -> Result<StatSpread, String> {
+    let invalid_spread = || {
+        format!(
+            "'{spread}' isn't a valid stat spread. Use six numbers from 0 to {max_value} in HP/Atk/Def/SpA/SpD/Spe order (e.g. 31/0/31/31/31/31)"
+        )
+    };
+
+    let values = spread
+        .split('/')
+        .map(|value| {
+            value
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .filter(|value| (0..=max_value).contains(value))
+                .ok_or_else(invalid_spread)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    <[i64; STAT_COUNT]>::try_from(values).map_err(|_| invalid_spread())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,6 +188,29 @@ mod tests {
         assert_eq!(parse_name("Mr. Mime"), "mr.-mime");
     }
 
+    #[test]
+    fn test_parse_pokemon_name() {
+        assert_eq!(parse_pokemon_name("Raichu"), "raichu");
+        assert_eq!(parse_pokemon_name("raichu-alola"), "raichu-alola");
+        assert_eq!(parse_pokemon_name("Alolan Raichu"), "raichu-alola");
+        assert_eq!(parse_pokemon_name("Mr. Mime"), "mr-mime");
+        assert_eq!(parse_pokemon_name("Mime Jr."), "mime-jr");
+        assert_eq!(parse_pokemon_name("Galarian Mr. Mime"), "mr-mime-galar");
+        assert_eq!(parse_pokemon_name("Mega Charizard X"), "charizard-mega-x");
+        assert_eq!(parse_pokemon_name("Mega Venusaur"), "venusaur-mega");
+        assert_eq!(parse_pokemon_name("Gigantamax Lapras"), "lapras-gmax");
+        assert_eq!(parse_pokemon_name("Megaman"), "megaman");
+    }
+
+    #[test]
+    fn test_parse_egg_group_name() {
+        assert_eq!(parse_egg_group_name("Monster"), "monster");
+        assert_eq!(parse_egg_group_name("Field"), "ground");
+        assert_eq!(parse_egg_group_name("Human-Like"), "humanshape");
+        assert_eq!(parse_egg_group_name("Water 1"), "water1");
+        assert_eq!(parse_egg_group_name("no-eggs"), "no-eggs");
+    }
+
     #[test]
     fn test_parse_generation() {
         assert_eq!(
@@ -81,4 +247,41 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_parse_type_chart() {
+        assert_eq!(parse_type_chart("1"), Ok(TypeChart::Generation(1)));
+        assert_eq!(parse_type_chart("gen-iv"), Ok(TypeChart::Generation(4)));
+        assert_eq!(
+            parse_type_chart("Generation VI"),
+            Ok(TypeChart::Generation(6))
+        );
+        assert!(parse_type_chart("42").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("45s"), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_duration("10m"), Ok(Duration::from_secs(600)));
+        assert_eq!(parse_duration("12h"), Ok(Duration::from_secs(43200)));
+        assert_eq!(parse_duration("30d"), Ok(Duration::from_secs(2592000)));
+        assert_eq!(parse_duration("2w"), Ok(Duration::from_secs(1209600)));
+
+        assert!(parse_duration("30").is_err());
+        assert!(parse_duration("d").is_err());
+        assert!(parse_duration("30y").is_err());
+        assert!(parse_duration("thirty days").is_err());
+        assert!(parse_duration("999999999999999999d").is_err());
+    }
+
+    #[test]
+    fn test_parse_stat_spreads() {
+        assert_eq!(parse_ivs("31/0/31/31/31/31"), Ok([31, 0, 31, 31, 31, 31]));
+        assert_eq!(parse_evs("4/252/0/0/0/252"), Ok([4, 252, 0, 0, 0, 252]));
+
+        assert!(parse_ivs("31/31/31").is_err());
+        assert!(parse_ivs("32/31/31/31/31/31").is_err());
+        assert!(parse_evs("252/252/252/0/0/0").is_err());
+        assert!(parse_evs("a/0/0/0/0/0").is_err());
+    }
 }
@@ -1,34 +1,100 @@
+pub mod breeding;
 pub mod builder;
 pub mod client;
 pub mod commands;
+pub mod completion;
+pub mod config;
+pub mod fetch_failure;
 pub mod formatter;
 pub mod input_parser;
+pub mod local_client;
 pub mod name_matcher;
+pub mod output_format;
 pub mod roman_numeral;
+pub mod search_query;
+pub mod session_client;
+pub mod stat_calculator;
 pub mod type_badge;
 
 pub use crate::{
     builder::Builder,
-    client::{Client, ClientImplementation},
-    input_parser::{parse_generation, parse_name},
+    client::{Client, ClientImplementation, ClientOptions},
+    config::Config,
+    input_parser::{
+        parse_duration, parse_egg_group_name, parse_evs, parse_generation, parse_ivs, parse_name,
+        parse_pokemon_name, parse_search_stat, parse_type_chart,
+    },
+    local_client::LocalClient,
     name_matcher::matcher,
+    output_format::OutputFormat,
 };
 
+use std::{path::PathBuf, time::Duration};
+
+use search_query::SearchStat;
+use stat_calculator::{StatOptions, StatSpread};
+
 use clap::{Parser, Subcommand};
 
 use commands::{
-    ability_command::AbilityCommand, generation_command::GenerationCommand,
-    item_command::ItemCommand, move_command::MoveCommand, moves_command::MovesCommand,
-    pokemon_command::PokemonCommand, type_command::TypeCommand,
+    ability_command::AbilityCommand,
+    berry_command::BerryCommand,
+    breed_command::BreedCommand,
+    cache_command::{CacheAction, CacheCommand},
+    cache_warm_command::CacheWarmCommand,
+    compare_command::{CompareCommand, MIN_COMPARE_SIZE},
+    completions_command::{CompletionShell, CompletionsCommand},
+    coverage_command::{CoverageCommand, MAX_MOVES},
+    damage_command::{Combatant, DEFAULT_LEVEL, DamageCommand},
+    egg_group_command::EggGroupCommand,
+    encounters_command::EncountersCommand,
+    generation_command::GenerationCommand,
+    item_command::ItemCommand,
+    move_command::MoveCommand,
+    moves_command::{MoveGrouping, MoveListOptions, MoveSort, MovesCommand},
+    nature_command::NatureCommand,
+    pokemon_command::PokemonCommand,
+    search_command::SearchCommand,
+    shell_command::ShellCommand,
+    team_command::{MAX_TEAM_SIZE, TeamCommand},
+    type_command::{TypeChart, TypeCommand},
 };
 
 #[derive(Parser)]
 #[command(about = "Search for pokemon information from the command line")]
 pub struct Cli {
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    #[arg(help = "The format to print results in")]
+    pub format: OutputFormat,
+
+    #[arg(
+        long,
+        global = true,
+        env = "POKE_SEARCH_OFFLINE",
+        default_value_t = false
+    )]
+    #[arg(value_parser = clap::builder::BoolishValueParser::new())]
+    #[arg(help = "Only use cached data and never make network requests")]
+    pub offline: bool,
+
+    #[arg(long, global = true, env = "POKE_SEARCH_DATA_DIR", value_name = "DIR")]
+    #[arg(
+        help = "Read data from a local checkout of PokeAPI's data/v2/csv directory instead of the API"
+    )]
+    pub data_dir: Option<PathBuf>,
+
+    #[arg(long, global = true, env = "POKE_SEARCH_API_URL", value_name = "URL")]
+    #[arg(
+        help = "The PokeAPI instance to use, e.g. a self-hosted mirror [default: https://pokeapi.co/api/v2/]"
+    )]
+    pub api_url: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+// Only one of these is ever built per run, so the size of the largest variant doesn't matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 pub enum Commands {
     #[command(about = "See information about an ability")]
@@ -45,6 +111,120 @@ pub enum Commands {
         verbose: bool,
     },
 
+    #[command(about = "See information about a berry")]
+    Berry {
+        #[arg(help = "The name of the berry you want to see information for")]
+        berry: String,
+
+        #[arg(short, long, default_value_t = false)]
+        #[arg(help = "Show verbose held item description")]
+        verbose: bool,
+    },
+
+    #[command(about = "Check whether two pokemon can breed with each other")]
+    Breed {
+        #[arg(help = "The name of the first pokemon")]
+        pokemon: String,
+
+        #[arg(help = "The name of the pokemon to breed it with")]
+        partner: String,
+    },
+
+    #[command(about = "Inspect or clean up the local cache of fetched data")]
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+
+    #[command(about = "Compare the stats, types and abilities of pokemon side by side")]
+    Compare {
+        #[arg(required = true, num_args = MIN_COMPARE_SIZE..)]
+        #[arg(help = "The names of two or more pokemon to compare")]
+        pokemon: Vec<String>,
+    },
+
+    #[command(about = "Print a script which adds tab completion to your shell")]
+    Completions {
+        #[arg(value_enum)]
+        #[arg(help = "The shell to print the completion script for")]
+        shell: CompletionShell,
+    },
+
+    // Called by the completion scripts with the words typed so far
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        words: Vec<String>,
+    },
+
+    #[command(
+        about = "See which types a pokemon's moves hit super effectively and which wall them"
+    )]
+    Coverage {
+        #[arg(help = "The name of the pokemon using the moves")]
+        pokemon: String,
+
+        #[arg(required = true, num_args = 1..=MAX_MOVES)]
+        #[arg(help = "The names of up to four moves")]
+        moves: Vec<String>,
+    },
+
+    #[command(about = "Calculate how much damage a move does from one pokemon to another")]
+    Damage {
+        #[arg(help = "The name of the pokemon using the move")]
+        attacker: String,
+
+        #[arg(help = "The name of the pokemon being hit")]
+        defender: String,
+
+        #[arg(help = "The name of the move")]
+        move_name: String,
+
+        #[arg(long, default_value_t = DEFAULT_LEVEL, value_parser = clap::value_parser!(i64).range(1..=100))]
+        #[arg(help = "The attacker's level")]
+        attacker_level: i64,
+
+        #[arg(long, value_parser = parse_ivs)]
+        #[arg(help = "The attacker's IVs as HP/Atk/Def/SpA/SpD/Spe, 31 for each by default")]
+        attacker_ivs: Option<StatSpread>,
+
+        #[arg(long, value_parser = parse_evs)]
+        #[arg(help = "The attacker's EVs as HP/Atk/Def/SpA/SpD/Spe, 0 for each by default")]
+        attacker_evs: Option<StatSpread>,
+
+        #[arg(long)]
+        #[arg(help = "The attacker's nature, neutral by default")]
+        attacker_nature: Option<String>,
+
+        #[arg(long, default_value_t = DEFAULT_LEVEL, value_parser = clap::value_parser!(i64).range(1..=100))]
+        #[arg(help = "The defender's level")]
+        defender_level: i64,
+
+        #[arg(long, value_parser = parse_ivs)]
+        #[arg(help = "The defender's IVs as HP/Atk/Def/SpA/SpD/Spe, 31 for each by default")]
+        defender_ivs: Option<StatSpread>,
+
+        #[arg(long, value_parser = parse_evs)]
+        #[arg(help = "The defender's EVs as HP/Atk/Def/SpA/SpD/Spe, 0 for each by default")]
+        defender_evs: Option<StatSpread>,
+
+        #[arg(long)]
+        #[arg(help = "The defender's nature, neutral by default")]
+        defender_nature: Option<String>,
+    },
+
+    #[command(about = "See every pokemon species in an egg group")]
+    EggGroup {
+        #[arg(help = "The name of the egg group, e.g. monster, field or water-1")]
+        egg_group: String,
+    },
+
+    #[command(about = "See where a pokemon can be found in the wild in each game")]
+    Encounters {
+        #[arg(help = "The name of the pokemon you want to find")]
+        pokemon: String,
+    },
+
     #[command(
         alias = "gen",
         about = "Information about a particular generation of pokemon"
@@ -88,6 +268,32 @@ pub enum Commands {
         #[arg(short, long, num_args(0..))]
         #[arg(help = "Only show moves for specific categories (physical, special, status)")]
         categories: Option<Vec<String>>,
+
+        #[arg(long, visible_alias = "game")]
+        #[arg(
+            help = "Only show moves learned in this version group, e.g. firered-leafgreen, using its learn method and level"
+        )]
+        version_group: Option<String>,
+
+        #[arg(short, long, value_enum)]
+        #[arg(
+            help = "Split the moves into sections, e.g. by learn method with level-up moves in level order"
+        )]
+        group_by: Option<MoveGrouping>,
+
+        #[arg(short, long, value_enum)]
+        #[arg(
+            help = "What to sort the moves by, power if not given. Numbers are sorted highest first, everything else A to Z or lowest first"
+        )]
+        sort: Option<MoveSort>,
+
+        #[arg(short, long, default_value_t = false)]
+        #[arg(help = "Reverse the sort order")]
+        reverse: bool,
+
+        #[arg(short, long)]
+        #[arg(help = "Only show this many moves, or this many in each group with --group-by")]
+        limit: Option<usize>,
     },
 
     #[command(about = "See information about a move")]
@@ -102,6 +308,20 @@ pub enum Commands {
         #[arg(short, long, num_args(0..), requires = "learned_by")]
         #[arg(help = "Filter results of `learned_by` by particular types")]
         types: Option<Vec<String>>,
+
+        #[arg(long, visible_alias = "game", requires = "learned_by")]
+        #[arg(
+            help = "Filter results of `learned_by` to pokemon that learn the move in this version group"
+        )]
+        version_group: Option<String>,
+    },
+
+    #[command(
+        about = "See which stats a nature raises and lowers, or every nature if none is given"
+    )]
+    Nature {
+        #[arg(help = "The name of the nature you want to see information for")]
+        nature: Option<String>,
     },
 
     #[command(about = "See information about a pokemon")]
@@ -113,9 +333,57 @@ pub enum Commands {
         #[arg(help = "Show detailed type information")]
         types: bool,
 
+        #[arg(short, long, value_parser = parse_type_chart, requires = "types")]
+        #[arg(help = "Use the type matchups from this generation's games, e.g. 1 or gen-iv")]
+        generation: Option<TypeChart>,
+
         #[arg(short, long, default_value_t = false)]
         #[arg(help = "Show evolution information")]
         evolution: bool,
+
+        #[arg(short, long, default_value_t = false)]
+        #[arg(help = "Show every other form of the pokemon with its types and stat differences")]
+        forms: bool,
+
+        #[arg(short, long, value_parser = clap::value_parser!(i64).range(1..=100))]
+        #[arg(help = "Show the pokemon's actual stats at this level")]
+        level: Option<i64>,
+
+        #[arg(long, value_parser = parse_ivs, requires = "level")]
+        #[arg(help = "IVs as HP/Atk/Def/SpA/SpD/Spe. Every possible IV is covered when left out")]
+        ivs: Option<StatSpread>,
+
+        #[arg(long, value_parser = parse_evs, requires = "level")]
+        #[arg(help = "EVs as HP/Atk/Def/SpA/SpD/Spe. Every possible EV is covered when left out")]
+        evs: Option<StatSpread>,
+
+        #[arg(short, long, requires = "level")]
+        #[arg(help = "The pokemon's nature. Every nature is covered when left out")]
+        nature: Option<String>,
+    },
+
+    #[command(about = "Find every pokemon matching a set of filters")]
+    Search {
+        #[arg(
+            help = "Filters such as type:water, ability:swift-swim, gen<=4, speed>=100 or bst>500. Every filter has to match. Stat filters on their own check every pokemon, which is quickest after `cache warm`"
+        )]
+        query: Vec<String>,
+
+        #[arg(short, long, default_value = "bst", value_parser = parse_search_stat)]
+        #[arg(
+            help = "The stat to sort results by, highest first (hp, atk, def, spa, spd, spe or bst)"
+        )]
+        sort: SearchStat,
+    },
+
+    #[command(about = "Start an interactive shell to run several commands in one session")]
+    Shell,
+
+    #[command(about = "See how a team of pokemon holds up defensively against every type")]
+    Team {
+        #[arg(required = true, num_args = 1..=MAX_TEAM_SIZE)]
+        #[arg(help = "The names of up to six pokemon in the team")]
+        pokemon: Vec<String>,
     },
 
     #[command(about = "See information about a specific type")]
@@ -130,10 +398,56 @@ pub enum Commands {
         #[arg(short, long, default_value_t = false)]
         #[arg(help = "List pokemon that have the specified type/s")]
         pokemon: bool,
+
+        #[arg(short, long, value_parser = parse_type_chart)]
+        #[arg(help = "Use the type matchups from this generation's games, e.g. 1 or gen-iv")]
+        generation: Option<TypeChart>,
+    },
+}
+
+impl Cli {
+    pub fn client_options(&self, config: Config) -> ClientOptions {
+        ClientOptions {
+            offline: self.offline,
+            api_url: self.api_url.clone().or(config.api_url),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+    #[command(about = "Show the number of entries, size and oldest entry of the cache")]
+    Stats,
+
+    #[command(about = "Remove every entry from the cache")]
+    Clear,
+
+    #[command(about = "Remove cache entries older than a given age")]
+    Prune {
+        #[arg(long, value_parser = parse_duration)]
+        #[arg(help = "Remove entries fetched longer ago than this (e.g. 45m, 12h, 30d, 2w)")]
+        older_than: Duration,
+    },
+
+    #[command(
+        about = "Fetch every pokemon and its encounters, move, ability, item, type, generation, nature, berry and egg group into the cache"
+    )]
+    Warm {
+        #[arg(short, long, default_value_t = 8)]
+        #[arg(help = "The maximum number of requests to make at once")]
+        concurrency: usize,
+
+        #[arg(long, default_value_t = false)]
+        #[arg(
+            help = "Ignore the progress of a previous unfinished run and fetch everything again"
+        )]
+        restart: bool,
     },
 }
 
 pub async fn run(client: &dyn ClientImplementation, cli: Cli) -> Builder {
+    let output_format = cli.format;
+
     match cli.command {
         Commands::Ability {
             ability,
@@ -141,7 +455,128 @@ pub async fn run(client: &dyn ClientImplementation, cli: Cli) -> Builder {
             verbose,
         } => {
             let parsed_ability_name = parse_name(&ability);
-            AbilityCommand::execute(client, parsed_ability_name, pokemon, verbose).await
+            AbilityCommand::execute(client, parsed_ability_name, pokemon, verbose, output_format)
+                .await
+        }
+
+        Commands::Berry { berry, verbose } => {
+            let parsed_berry_name = parse_name(&berry);
+            BerryCommand::execute(client, parsed_berry_name, verbose, output_format).await
+        }
+
+        Commands::Breed { pokemon, partner } => {
+            let parsed_pokemon_name = parse_pokemon_name(&pokemon);
+            let parsed_partner_name = parse_pokemon_name(&partner);
+            BreedCommand::execute(
+                client,
+                parsed_pokemon_name,
+                parsed_partner_name,
+                output_format,
+            )
+            .await
+        }
+
+        Commands::Cache { command } => {
+            let cache_dir = match Client::get_cache_dir() {
+                Ok(cache_dir) => cache_dir,
+                Err(error) => {
+                    let mut builder = Builder::default();
+                    builder.append_error(error.to_string(), output_format);
+                    return builder;
+                }
+            };
+
+            let action = match command {
+                CacheCommands::Stats => CacheAction::Stats,
+                CacheCommands::Clear => CacheAction::Clear,
+                CacheCommands::Prune { older_than } => CacheAction::Prune { older_than },
+                CacheCommands::Warm {
+                    concurrency,
+                    restart,
+                } => {
+                    return CacheWarmCommand::execute(
+                        client,
+                        cache_dir,
+                        concurrency,
+                        restart,
+                        output_format,
+                    )
+                    .await;
+                }
+            };
+
+            CacheCommand::execute(cache_dir, action, output_format)
+        }
+
+        Commands::Compare { pokemon } => {
+            let parsed_pokemon_names = pokemon
+                .iter()
+                .map(|name| parse_pokemon_name(name))
+                .collect();
+            CompareCommand::execute(client, parsed_pokemon_names, output_format).await
+        }
+
+        Commands::Completions { shell } => CompletionsCommand::execute(shell),
+
+        Commands::Complete { words } => CompletionsCommand::complete(words),
+
+        Commands::Coverage { pokemon, moves } => {
+            let parsed_pokemon_name = parse_pokemon_name(&pokemon);
+            let parsed_move_names = moves.iter().map(|name| parse_name(name)).collect();
+            CoverageCommand::execute(
+                client,
+                parsed_pokemon_name,
+                parsed_move_names,
+                output_format,
+            )
+            .await
+        }
+
+        Commands::Damage {
+            attacker,
+            defender,
+            move_name,
+            attacker_level,
+            attacker_ivs,
+            attacker_evs,
+            attacker_nature,
+            defender_level,
+            defender_ivs,
+            defender_evs,
+            defender_nature,
+        } => {
+            let attacker = Combatant {
+                pokemon_name: parse_pokemon_name(&attacker),
+                stat_options: StatOptions {
+                    level: attacker_level,
+                    ivs: attacker_ivs,
+                    evs: attacker_evs,
+                    nature: attacker_nature.as_deref().map(parse_name),
+                },
+            };
+            let defender = Combatant {
+                pokemon_name: parse_pokemon_name(&defender),
+                stat_options: StatOptions {
+                    level: defender_level,
+                    ivs: defender_ivs,
+                    evs: defender_evs,
+                    nature: defender_nature.as_deref().map(parse_name),
+                },
+            };
+            let parsed_move_name = parse_name(&move_name);
+
+            DamageCommand::execute(client, attacker, defender, parsed_move_name, output_format)
+                .await
+        }
+
+        Commands::EggGroup { egg_group } => {
+            let parsed_egg_group_name = parse_egg_group_name(&egg_group);
+            EggGroupCommand::execute(client, parsed_egg_group_name, output_format).await
+        }
+
+        Commands::Encounters { pokemon } => {
+            let parsed_pokemon_name = parse_pokemon_name(&pokemon);
+            EncountersCommand::execute(client, parsed_pokemon_name, output_format).await
         }
 
         Commands::Generation {
@@ -151,48 +586,143 @@ pub async fn run(client: &dyn ClientImplementation, cli: Cli) -> Builder {
             moves,
         } => match parse_generation(&generation) {
             Ok(parsed_generation) => {
-                GenerationCommand::execute(client, parsed_generation, pokemon, abilities, moves)
-                    .await
+                GenerationCommand::execute(
+                    client,
+                    parsed_generation,
+                    pokemon,
+                    abilities,
+                    moves,
+                    output_format,
+                )
+                .await
+            }
+            Err(error_message) => {
+                let mut builder = Builder::default();
+                builder.append_error(error_message, output_format);
+                builder
             }
-            Err(error_message) => Builder::from(error_message),
         },
 
         Commands::Item { item, verbose } => {
             let parsed_item_name = parse_name(&item);
-            ItemCommand::execute(client, parsed_item_name, verbose).await
+            ItemCommand::execute(client, parsed_item_name, verbose, output_format).await
         }
 
         Commands::Moves {
             pokemon,
             type_names,
             categories,
+            version_group,
+            group_by,
+            sort,
+            reverse,
+            limit,
         } => {
-            let parsed_pokemon_name = parse_name(&pokemon);
-            MovesCommand::execute(client, parsed_pokemon_name, type_names, categories).await
+            let parsed_pokemon_name = parse_pokemon_name(&pokemon);
+            let parsed_version_group = version_group.as_deref().map(parse_name);
+            let list_options = MoveListOptions {
+                group_by,
+                sort,
+                reverse,
+                limit,
+            };
+
+            MovesCommand::execute(
+                client,
+                parsed_pokemon_name,
+                type_names,
+                categories,
+                parsed_version_group,
+                list_options,
+                output_format,
+            )
+            .await
         }
 
         Commands::Move {
             move_name,
             learned_by,
             types,
+            version_group,
         } => {
             let parsed_move_name = parse_name(&move_name);
-            MoveCommand::execute(client, parsed_move_name, learned_by, types).await
+            let parsed_version_group = version_group.as_deref().map(parse_name);
+            MoveCommand::execute(
+                client,
+                parsed_move_name,
+                learned_by,
+                types,
+                parsed_version_group,
+                output_format,
+            )
+            .await
+        }
+
+        Commands::Nature { nature } => {
+            let parsed_nature_name = nature.as_deref().map(parse_name);
+            NatureCommand::execute(client, parsed_nature_name, output_format).await
         }
 
         Commands::Pokemon {
             pokemon,
             types,
+            generation,
             evolution,
+            forms,
+            level,
+            ivs,
+            evs,
+            nature,
         } => {
-            let parsed_pokemon_name = parse_name(&pokemon);
-            PokemonCommand::execute(client, parsed_pokemon_name, types, evolution).await
+            let parsed_pokemon_name = parse_pokemon_name(&pokemon);
+            let stat_options = level.map(|level| StatOptions {
+                level,
+                ivs,
+                evs,
+                nature: nature.as_deref().map(parse_name),
+            });
+
+            PokemonCommand::execute(
+                client,
+                parsed_pokemon_name,
+                types.then(|| generation.unwrap_or_default()),
+                evolution,
+                forms,
+                stat_options,
+                output_format,
+            )
+            .await
+        }
+
+        Commands::Search { query, sort } => {
+            SearchCommand::execute(client, query, sort, output_format).await
+        }
+
+        Commands::Shell => ShellCommand::execute(client, output_format).await,
+
+        Commands::Team { pokemon } => {
+            let parsed_pokemon_names = pokemon
+                .iter()
+                .map(|name| parse_pokemon_name(name))
+                .collect();
+            TeamCommand::execute(client, parsed_pokemon_names, output_format).await
         }
 
         Commands::Type {
             type_name,
             second_type_name,
             pokemon,
-        } => TypeCommand::execute(client, type_name, second_type_name, pokemon).await,
+            generation,
+        } => {
+            TypeCommand::execute(
+                client,
+                type_name,
+                second_type_name,
+                pokemon,
+                generation.unwrap_or_default(),
+                output_format,
+            )
+            .await
+        }
     }
 }
@@ -0,0 +1,1131 @@
+mod table;
+
+use crate::client::{ClientError, ClientImplementation};
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use itertools::Itertools;
+use rustemon::model::{
+    berries::Berry,
+    evolution::EvolutionChain,
+    games::Generation,
+    items::Item,
+    moves::{Move, MoveLearnMethod},
+    pokemon::{Ability, EggGroup, LocationAreaEncounter, Nature, Pokemon, PokemonSpecies, Type},
+};
+use serde_json::{Value, json};
+
+use table::{Row, Table, named_resource, resource_url};
+
+const API_URL: &str = "https://pokeapi.co/api/v2";
+const ENGLISH: &str = "en";
+
+// PokeAPI numbers berry flavours the same way as the contest types they're tied to
+const BERRY_FLAVORS: &[&str] = &["spicy", "dry", "sweet", "bitter", "sour"];
+
+/// Assembles models from a local checkout of PokeAPI's `data/v2/csv` directory instead of
+/// making HTTP requests. Only English text is included and fields poke_search never reads,
+/// such as sprites and game indices, are left empty.
+pub struct LocalClient {
+    data_dir: PathBuf,
+    tables: Mutex<HashMap<&'static str, Arc<Table>>>,
+}
+
+impl LocalClient {
+    pub fn try_build(data_dir: PathBuf) -> Result<Self, ClientError> {
+        if !data_dir.join("pokemon.csv").is_file() {
+            return Err(ClientError::LocalData(format!(
+                "{} doesn't look like PokeAPI's data/v2/csv directory",
+                data_dir.display()
+            )));
+        }
+
+        Ok(LocalClient {
+            data_dir,
+            tables: Mutex::default(),
+        })
+    }
+
+    fn table(&self, file_name: &'static str) -> Result<Arc<Table>, ClientError> {
+        let mut tables = self
+            .tables
+            .lock()
+            .map_err(|error| ClientError::LocalData(error.to_string()))?;
+
+        if let Some(table) = tables.get(file_name) {
+            return Ok(Arc::clone(table));
+        }
+
+        let table = Table::read(&self.data_dir.join(file_name))
+            .map(Arc::new)
+            .map_err(|error| ClientError::LocalData(format!("{file_name}: {error}")))?;
+
+        tables.insert(file_name, Arc::clone(&table));
+
+        Ok(table)
+    }
+
+    // Tables which only exist in newer data revisions
+    fn optional_table(&self, file_name: &'static str) -> Result<Option<Arc<Table>>, ClientError> {
+        if self.data_dir.join(file_name).is_file() {
+            self.table(file_name).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn english_id(&self) -> Result<String, ClientError> {
+        let languages = self.table("languages.csv")?;
+
+        languages
+            .find("identifier", ENGLISH)
+            .map(|row| row.get("id").to_owned())
+            .ok_or_else(|| {
+                ClientError::LocalData(String::from("English is missing from languages.csv"))
+            })
+    }
+
+    fn english(&self) -> Result<Value, ClientError> {
+        Ok(named_resource("language", ENGLISH, &self.english_id()?))
+    }
+
+    fn english_rows<'a>(
+        &self,
+        table: &'a Table,
+        id_column: &'a str,
+        id: &'a str,
+    ) -> Result<impl Iterator<Item = Row<'a>>, ClientError> {
+        let english_id = self.english_id()?;
+
+        // Name and prose tables use `local_language_id`, flavour text tables use `language_id`
+        Ok(table.rows_where(id_column, id).filter(move |row| {
+            row.get("local_language_id") == english_id || row.get("language_id") == english_id
+        }))
+    }
+
+    fn names(
+        &self,
+        file_name: &'static str,
+        id_column: &str,
+        id: &str,
+    ) -> Result<Value, ClientError> {
+        let table = self.table(file_name)?;
+        let language = self.english()?;
+
+        Ok(self
+            .english_rows(&table, id_column, id)?
+            .map(|row| json!({ "name": row.get("name"), "language": language }))
+            .collect())
+    }
+
+    fn verbose_effects(
+        &self,
+        file_name: &'static str,
+        id_column: &str,
+        id: &str,
+    ) -> Result<Value, ClientError> {
+        let table = self.table(file_name)?;
+        let language = self.english()?;
+
+        Ok(self
+            .english_rows(&table, id_column, id)?
+            .map(|row| {
+                json!({
+                    "effect": strip_markup(row.get("effect")),
+                    "short_effect": strip_markup(row.get("short_effect")),
+                    "language": language,
+                })
+            })
+            .collect())
+    }
+
+    fn version_group_flavor_text(
+        &self,
+        file_name: &'static str,
+        id_column: &str,
+        id: &str,
+        text_key: &str,
+    ) -> Result<Value, ClientError> {
+        let table = self.table(file_name)?;
+        let version_groups = self.table("version_groups.csv")?;
+        let version_groups = version_groups.resources("version-group");
+        let language = self.english()?;
+
+        Ok(self
+            .english_rows(&table, id_column, id)?
+            .map(|row| {
+                json!({
+                    text_key: row.get("flavor_text"),
+                    "language": language,
+                    "version_group": version_groups.get(row.get("version_group_id")),
+                })
+            })
+            .collect())
+    }
+
+    fn resource(
+        &self,
+        file_name: &'static str,
+        resource: &'static str,
+        id: &str,
+    ) -> Result<Value, ClientError> {
+        Ok(self.table(file_name)?.resources(resource).get(id))
+    }
+
+    fn type_relations(
+        &self,
+        type_id: &str,
+        past_generation: Option<i64>,
+    ) -> Result<Value, ClientError> {
+        let efficacy = self.table("type_efficacy.csv")?;
+        let past_efficacy = self.optional_table("type_efficacy_past.csv")?;
+        let types = self.table("types.csv")?;
+        let types = types.resources("type");
+
+        let mut damage_factors = efficacy
+            .rows()
+            .map(|row| {
+                (
+                    (row.get("damage_type_id"), row.get("target_type_id")),
+                    row.get("damage_factor"),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        // A past entry applies up to and including its generation
+        if let (Some(generation), Some(past_efficacy)) = (past_generation, &past_efficacy) {
+            past_efficacy
+                .rows()
+                .filter(|row| row.int("generation_id").is_some_and(|id| id >= generation))
+                .sorted_by_key(|row| std::cmp::Reverse(row.int("generation_id")))
+                .for_each(|row| {
+                    damage_factors.insert(
+                        (row.get("damage_type_id"), row.get("target_type_id")),
+                        row.get("damage_factor"),
+                    );
+                });
+        }
+
+        let relations = |factor: &str, to: bool| {
+            damage_factors
+                .iter()
+                .filter(|((damage_type, target_type), damage_factor)| {
+                    **damage_factor == factor
+                        && if to {
+                            *damage_type == type_id
+                        } else {
+                            *target_type == type_id
+                        }
+                })
+                .map(|((damage_type, target_type), _)| if to { *target_type } else { *damage_type })
+                .sorted_by_key(|id| id.parse::<i64>().unwrap_or_default())
+                .map(|id| types.get(id))
+                .collect::<Vec<_>>()
+        };
+
+        Ok(json!({
+            "no_damage_to": relations("0", true),
+            "half_damage_to": relations("50", true),
+            "double_damage_to": relations("200", true),
+            "no_damage_from": relations("0", false),
+            "half_damage_from": relations("50", false),
+            "double_damage_from": relations("200", false),
+        }))
+    }
+
+    fn past_type_relations(&self, type_id: &str) -> Result<Value, ClientError> {
+        let Some(past_efficacy) = self.optional_table("type_efficacy_past.csv")? else {
+            return Ok(json!([]));
+        };
+
+        let generations = self.table("generations.csv")?;
+        let generations = generations.resources("generation");
+
+        past_efficacy
+            .rows()
+            .filter(|row| {
+                row.get("damage_type_id") == type_id || row.get("target_type_id") == type_id
+            })
+            .filter_map(|row| row.int("generation_id"))
+            .unique()
+            .sorted()
+            .map(|generation_id| {
+                Ok(json!({
+                    "generation": generations.get(&generation_id.to_string()),
+                    "damage_relations": self.type_relations(type_id, Some(generation_id))?,
+                }))
+            })
+            .collect()
+    }
+
+    fn chain_link(
+        &self,
+        species: &Row,
+        chain_species: &[Row],
+        evolutions: &Table,
+    ) -> Result<Value, ClientError> {
+        let species_id = species.get("id");
+        let evolution_details = evolutions
+            .rows_where("evolved_species_id", species_id)
+            .map(|row| self.evolution_detail(&row))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let evolves_to = chain_species
+            .iter()
+            .filter(|row| row.get("evolves_from_species_id") == species_id)
+            .sorted_by_key(|row| row.int("order"))
+            .map(|row| self.chain_link(row, chain_species, evolutions))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(json!({
+            "is_baby": species.flag("is_baby"),
+            "species": named_resource("pokemon-species", species.get("identifier"), species_id),
+            "evolution_details": evolution_details,
+            "evolves_to": evolves_to,
+        }))
+    }
+
+    fn evolution_detail(&self, evolution: &Row) -> Result<Value, ClientError> {
+        Ok(json!({
+            "item": self.resource("items.csv", "item", evolution.get("trigger_item_id"))?,
+            "trigger": self.resource(
+                "evolution_triggers.csv",
+                "evolution-trigger",
+                evolution.get("evolution_trigger_id"),
+            )?,
+            "gender": evolution.int("gender_id"),
+            "held_item": self.resource("items.csv", "item", evolution.get("held_item_id"))?,
+            "known_move": self.resource("moves.csv", "move", evolution.get("known_move_id"))?,
+            "known_move_type": self.resource("types.csv", "type", evolution.get("known_move_type_id"))?,
+            "location": self.resource("locations.csv", "location", evolution.get("location_id"))?,
+            "min_level": evolution.int("minimum_level"),
+            "min_happiness": evolution.int("minimum_happiness"),
+            "min_beauty": evolution.int("minimum_beauty"),
+            "min_affection": evolution.int("minimum_affection"),
+            "needs_multiplayer": false,
+            "needs_overworld_rain": evolution.flag("needs_overworld_rain"),
+            "party_species": self.resource(
+                "pokemon_species.csv",
+                "pokemon-species",
+                evolution.get("party_species_id"),
+            )?,
+            "party_type": self.resource("types.csv", "type", evolution.get("party_type_id"))?,
+            "relative_physical_stats": evolution.int("relative_physical_stats"),
+            "time_of_day": evolution.get("time_of_day"),
+            "trade_species": self.resource(
+                "pokemon_species.csv",
+                "pokemon-species",
+                evolution.get("trade_species_id"),
+            )?,
+            "turn_upside_down": evolution.flag("turn_upside_down"),
+            "region": self.resource("regions.csv", "region", evolution.get("region_id"))?,
+            "base_form": self.resource(
+                "pokemon_species.csv",
+                "pokemon-species",
+                evolution.get("base_form_id"),
+            )?,
+        }))
+    }
+
+    fn not_found(resource: &str, name: &str) -> ClientError {
+        ClientError::NotFound(format!("{resource} \"{name}\""))
+    }
+
+    fn build<T: for<'de> serde::Deserialize<'de>>(value: Value) -> Result<T, ClientError> {
+        serde_json::from_value(value).map_err(|error| ClientError::LocalData(error.to_string()))
+    }
+}
+
+#[async_trait]
+impl ClientImplementation for LocalClient {
+    async fn fetch_ability(&self, ability_name: &str) -> Result<Ability, ClientError> {
+        let abilities = self.table("abilities.csv")?;
+        let ability = abilities
+            .find_named(ability_name)
+            .ok_or_else(|| Self::not_found("Ability", ability_name))?;
+        let id = ability.get("id");
+
+        let pokemon_table = self.table("pokemon.csv")?;
+        let pokemon_resources = pokemon_table.resources("pokemon");
+        let pokemon = self
+            .table("pokemon_abilities.csv")?
+            .rows_where("ability_id", id)
+            .map(|row| {
+                json!({
+                    "is_hidden": row.flag("is_hidden"),
+                    "slot": row.int("slot"),
+                    "pokemon": pokemon_resources.get(row.get("pokemon_id")),
+                })
+            })
+            .collect_vec();
+
+        Self::build(json!({
+            "id": ability.int("id"),
+            "name": ability.get("identifier"),
+            "is_main_series": ability.flag("is_main_series"),
+            "generation": self.resource("generations.csv", "generation", ability.get("generation_id"))?,
+            "names": self.names("ability_names.csv", "ability_id", id)?,
+            "effect_entries": self.verbose_effects("ability_prose.csv", "ability_id", id)?,
+            "effect_changes": [],
+            "flavor_text_entries": self.version_group_flavor_text(
+                "ability_flavor_text.csv",
+                "ability_id",
+                id,
+                "flavor_text",
+            )?,
+            "pokemon": pokemon,
+        }))
+    }
+
+    async fn fetch_berry(&self, berry_name: &str) -> Result<Berry, ClientError> {
+        let items = self.table("items.csv")?;
+        let items = items.resources("item");
+        let berries = self.table("berries.csv")?;
+
+        // Berries don't have an identifier of their own so they're named after their item
+        let berry_item_name = format!("{berry_name}-berry");
+        let berry = berries
+            .rows()
+            .find(|row| {
+                row.get("id") == berry_name
+                    || items.get(row.get("item_id"))["name"] == berry_item_name.as_str()
+            })
+            .ok_or_else(|| Self::not_found("Berry", berry_name))?;
+        let id = berry.get("id");
+
+        let item = items.get(berry.get("item_id"));
+        let name = item["name"]
+            .as_str()
+            .and_then(|item_name| item_name.strip_suffix("-berry"))
+            .unwrap_or(berry_name)
+            .to_owned();
+
+        let flavors = self
+            .table("berry_flavors.csv")?
+            .rows_where("berry_id", id)
+            .filter_map(|row| {
+                let flavor_id = row.get("contest_type_id");
+                let flavor = BERRY_FLAVORS.get(flavor_id.parse::<usize>().ok()?.checked_sub(1)?)?;
+
+                Some(json!({
+                    "potency": row.int("flavor").unwrap_or_default(),
+                    "flavor": named_resource("berry-flavor", flavor, flavor_id),
+                }))
+            })
+            .collect_vec();
+
+        Self::build(json!({
+            "id": berry.int("id"),
+            "name": name,
+            "growth_time": berry.int("growth_time").unwrap_or_default(),
+            "max_harvest": berry.int("max_harvest").unwrap_or_default(),
+            "natural_gift_power": berry.int("natural_gift_power").unwrap_or_default(),
+            "size": berry.int("size").unwrap_or_default(),
+            "smoothness": berry.int("smoothness").unwrap_or_default(),
+            "soil_dryness": berry.int("soil_dryness").unwrap_or_default(),
+            "firmness": self.resource("berry_firmness.csv", "berry-firmness", berry.get("firmness_id"))?,
+            "flavors": flavors,
+            "item": item,
+            "natural_gift_type": self.resource("types.csv", "type", berry.get("natural_gift_type_id"))?,
+        }))
+    }
+
+    async fn fetch_egg_group(&self, egg_group_name: &str) -> Result<EggGroup, ClientError> {
+        let egg_groups = self.table("egg_groups.csv")?;
+        let egg_group = egg_groups
+            .find_named(egg_group_name)
+            .ok_or_else(|| Self::not_found("Egg group", egg_group_name))?;
+        let id = egg_group.get("id");
+
+        let species = self.table("pokemon_species.csv")?;
+        let species = species.resources("pokemon-species");
+        let pokemon_species = self
+            .table("pokemon_egg_groups.csv")?
+            .rows_where("egg_group_id", id)
+            .map(|row| species.get(row.get("species_id")))
+            .collect_vec();
+
+        Self::build(json!({
+            "id": egg_group.int("id"),
+            "name": egg_group.get("identifier"),
+            "names": self.names("egg_group_prose.csv", "egg_group_id", id)?,
+            "pokemon_species": pokemon_species,
+        }))
+    }
+
+    async fn fetch_generation(&self, generation_name: &str) -> Result<Generation, ClientError> {
+        let generations = self.table("generations.csv")?;
+        let generation = generations
+            .find_named(generation_name)
+            .ok_or_else(|| Self::not_found("Generation", generation_name))?;
+        let id = generation.get("id");
+
+        let introduced = |file_name: &'static str, resource: &'static str| {
+            let table = self.table(file_name)?;
+
+            Ok::<_, ClientError>(
+                table
+                    .rows_where("generation_id", id)
+                    .map(|row| named_resource(resource, row.get("identifier"), row.get("id")))
+                    .collect_vec(),
+            )
+        };
+
+        Self::build(json!({
+            "id": generation.int("id"),
+            "name": generation.get("identifier"),
+            "abilities": introduced("abilities.csv", "ability")?,
+            "names": self.names("generation_names.csv", "generation_id", id)?,
+            "main_region": self.resource("regions.csv", "region", generation.get("main_region_id"))?,
+            "moves": introduced("moves.csv", "move")?,
+            "pokemon_species": introduced("pokemon_species.csv", "pokemon-species")?,
+            "types": introduced("types.csv", "type")?,
+            "version_groups": introduced("version_groups.csv", "version-group")?,
+        }))
+    }
+
+    async fn fetch_item(&self, item_name: &str) -> Result<Item, ClientError> {
+        let items = self.table("items.csv")?;
+        let item = items
+            .find_named(item_name)
+            .ok_or_else(|| Self::not_found("Item", item_name))?;
+        let id = item.get("id");
+
+        let attributes = match self.optional_table("item_flag_map.csv")? {
+            Some(flag_map) => {
+                let flags = self.table("item_flags.csv")?;
+                let flags = flags.resources("item-attribute");
+
+                flag_map
+                    .rows_where("item_id", id)
+                    .map(|row| flags.get(row.get("item_flag_id")))
+                    .collect_vec()
+            }
+            None => Vec::new(),
+        };
+
+        let baby_trigger_for = self
+            .table("evolution_chains.csv")?
+            .find("baby_trigger_item_id", id)
+            .map(|row| json!({ "url": resource_url("evolution-chain", row.get("id")) }));
+
+        Self::build(json!({
+            "id": item.int("id"),
+            "name": item.get("identifier"),
+            "cost": item.int("cost").unwrap_or_default(),
+            "fling_power": item.int("fling_power"),
+            "fling_effect": self.resource(
+                "item_fling_effects.csv",
+                "item-fling-effect",
+                item.get("fling_effect_id"),
+            )?,
+            "attributes": attributes,
+            "category": self.resource("item_categories.csv", "item-category", item.get("category_id"))?,
+            "effect_entries": self.verbose_effects("item_prose.csv", "item_id", id)?,
+            "flavor_text_entries": self.version_group_flavor_text(
+                "item_flavor_text.csv",
+                "item_id",
+                id,
+                "text",
+            )?,
+            "game_indices": [],
+            "names": self.names("item_names.csv", "item_id", id)?,
+            "sprites": { "default": null },
+            "held_by_pokemon": [],
+            "baby_trigger_for": baby_trigger_for,
+            "machines": [],
+        }))
+    }
+
+    async fn fetch_move(&self, move_name: &str) -> Result<Move, ClientError> {
+        let moves = self.table("moves.csv")?;
+        let move_ = moves
+            .find_named(move_name)
+            .ok_or_else(|| Self::not_found("Move", move_name))?;
+        let id = move_.get("id");
+
+        let pokemon_table = self.table("pokemon.csv")?;
+        let pokemon_resources = pokemon_table.resources("pokemon");
+        let learned_by_pokemon = self
+            .table("pokemon_moves.csv")?
+            .rows_where("move_id", id)
+            .map(|row| row.get("pokemon_id"))
+            .unique()
+            .sorted_by_key(|pokemon_id| pokemon_id.parse::<i64>().unwrap_or_default())
+            .map(|pokemon_id| pokemon_resources.get(pokemon_id))
+            .collect_vec();
+
+        let meta = match self.table("move_meta.csv")?.find("move_id", id) {
+            Some(meta) => json!({
+                "ailment": self.resource(
+                    "move_meta_ailments.csv",
+                    "move-ailment",
+                    meta.get("meta_ailment_id"),
+                )?,
+                "category": self.resource(
+                    "move_meta_categories.csv",
+                    "move-category",
+                    meta.get("meta_category_id"),
+                )?,
+                "min_hits": meta.int("min_hits"),
+                "max_hits": meta.int("max_hits"),
+                "min_turns": meta.int("min_turns"),
+                "max_turns": meta.int("max_turns"),
+                "drain": meta.int("drain").unwrap_or_default(),
+                "healing": meta.int("healing").unwrap_or_default(),
+                "crit_rate": meta.int("crit_rate").unwrap_or_default(),
+                "ailment_chance": meta.int("ailment_chance").unwrap_or_default(),
+                "flinch_chance": meta.int("flinch_chance").unwrap_or_default(),
+                "stat_chance": meta.int("stat_chance").unwrap_or_default(),
+            }),
+            None => Value::Null,
+        };
+
+        let stats = self.table("stats.csv")?;
+        let stats = stats.resources("stat");
+        let stat_changes = self
+            .table("move_meta_stat_changes.csv")?
+            .rows_where("move_id", id)
+            .map(
+                |row| json!({ "change": row.int("change"), "stat": stats.get(row.get("stat_id")) }),
+            )
+            .collect_vec();
+
+        Self::build(json!({
+            "id": move_.int("id"),
+            "name": move_.get("identifier"),
+            "accuracy": move_.int("accuracy"),
+            "effect_chance": move_.int("effect_chance"),
+            "pp": move_.int("pp"),
+            "priority": move_.int("priority").unwrap_or_default(),
+            "power": move_.int("power"),
+            "damage_class": self.resource(
+                "move_damage_classes.csv",
+                "move-damage-class",
+                move_.get("damage_class_id"),
+            )?,
+            "effect_entries": self.verbose_effects(
+                "move_effect_prose.csv",
+                "move_effect_id",
+                move_.get("effect_id"),
+            )?,
+            "effect_changes": [],
+            "learned_by_pokemon": learned_by_pokemon,
+            "flavor_text_entries": self.version_group_flavor_text(
+                "move_flavor_text.csv",
+                "move_id",
+                id,
+                "flavor_text",
+            )?,
+            "generation": self.resource("generations.csv", "generation", move_.get("generation_id"))?,
+            "machines": [],
+            "meta": meta,
+            "names": self.names("move_names.csv", "move_id", id)?,
+            "past_values": [],
+            "stat_changes": stat_changes,
+            "target": self.resource("move_targets.csv", "move-target", move_.get("target_id"))?,
+            "type": self.resource("types.csv", "type", move_.get("type_id"))?,
+        }))
+    }
+
+    async fn fetch_move_learn_method(
+        &self,
+        move_learn_method_name: &str,
+    ) -> Result<MoveLearnMethod, ClientError> {
+        let methods = self.table("pokemon_move_methods.csv")?;
+        let method = methods
+            .find_named(move_learn_method_name)
+            .ok_or_else(|| Self::not_found("Move learn method", move_learn_method_name))?;
+        let id = method.get("id");
+
+        let prose = self.table("pokemon_move_method_prose.csv")?;
+        let language = self.english()?;
+        let descriptions = self
+            .english_rows(&prose, "pokemon_move_method_id", id)?
+            .map(|row| json!({ "description": row.get("description"), "language": language }))
+            .collect_vec();
+
+        let version_groups = self.table("version_groups.csv")?;
+        let version_groups = version_groups.resources("version-group");
+        let method_version_groups = self
+            .table("version_group_pokemon_move_methods.csv")?
+            .rows_where("pokemon_move_method_id", id)
+            .map(|row| version_groups.get(row.get("version_group_id")))
+            .collect_vec();
+
+        Self::build(json!({
+            "id": method.int("id"),
+            "name": method.get("identifier"),
+            "descriptions": descriptions,
+            "names": self.names("pokemon_move_method_prose.csv", "pokemon_move_method_id", id)?,
+            "version_groups": method_version_groups,
+        }))
+    }
+
+    async fn fetch_nature(&self, nature_name: &str) -> Result<Nature, ClientError> {
+        let natures = self.table("natures.csv")?;
+        let nature = natures
+            .find_named(nature_name)
+            .ok_or_else(|| Self::not_found("Nature", nature_name))?;
+        let id = nature.get("id");
+
+        let flavor = |column: &str| {
+            let flavor_id = nature.get(column);
+            let flavor_name = flavor_id
+                .parse::<usize>()
+                .ok()
+                .and_then(|flavor_id| BERRY_FLAVORS.get(flavor_id.checked_sub(1)?));
+
+            flavor_name.map(|flavor_name| named_resource("berry-flavor", flavor_name, flavor_id))
+        };
+
+        // Neutral natures raise and lower the same stat, which the API reports as neither
+        let is_neutral = nature.get("increased_stat_id") == nature.get("decreased_stat_id");
+        let (increased_stat, decreased_stat, likes_flavor, hates_flavor) = if is_neutral {
+            (Value::Null, Value::Null, None, None)
+        } else {
+            (
+                self.resource("stats.csv", "stat", nature.get("increased_stat_id"))?,
+                self.resource("stats.csv", "stat", nature.get("decreased_stat_id"))?,
+                flavor("likes_flavor_id"),
+                flavor("hates_flavor_id"),
+            )
+        };
+
+        Self::build(json!({
+            "id": nature.int("id"),
+            "name": nature.get("identifier"),
+            "increased_stat": increased_stat,
+            "decreased_stat": decreased_stat,
+            "likes_flavor": likes_flavor,
+            "hates_flavor": hates_flavor,
+            "pokeathlon_stat_changes": [],
+            "move_battle_style_preferences": [],
+            "names": self.names("nature_names.csv", "nature_id", id)?,
+        }))
+    }
+
+    async fn fetch_pokemon(&self, pokemon_name: &str) -> Result<Pokemon, ClientError> {
+        let pokemon_table = self.table("pokemon.csv")?;
+        let pokemon = pokemon_table
+            .find_named(pokemon_name)
+            .ok_or_else(|| Self::not_found("Pokemon", pokemon_name))?;
+        let id = pokemon.get("id");
+
+        let abilities = self.table("abilities.csv")?;
+        let abilities = abilities.resources("ability");
+        let pokemon_abilities = self
+            .table("pokemon_abilities.csv")?
+            .rows_where("pokemon_id", id)
+            .map(|row| {
+                json!({
+                    "is_hidden": row.flag("is_hidden"),
+                    "slot": row.int("slot"),
+                    "ability": abilities.get(row.get("ability_id")),
+                })
+            })
+            .collect_vec();
+
+        let types = self.table("types.csv")?;
+        let types = types.resources("type");
+        let pokemon_types = self
+            .table("pokemon_types.csv")?
+            .rows_where("pokemon_id", id)
+            .sorted_by_key(|row| row.int("slot"))
+            .map(|row| json!({ "slot": row.int("slot"), "type": types.get(row.get("type_id")) }))
+            .collect_vec();
+
+        let stats = self.table("stats.csv")?;
+        let stats = stats.resources("stat");
+        let pokemon_stats = self
+            .table("pokemon_stats.csv")?
+            .rows_where("pokemon_id", id)
+            .map(|row| {
+                json!({
+                    "stat": stats.get(row.get("stat_id")),
+                    "effort": row.int("effort"),
+                    "base_stat": row.int("base_stat"),
+                })
+            })
+            .collect_vec();
+
+        let moves = self.table("moves.csv")?;
+        let moves = moves.resources("move");
+        let methods = self.table("pokemon_move_methods.csv")?;
+        let methods = methods.resources("move-learn-method");
+        let version_groups = self.table("version_groups.csv")?;
+        let version_groups = version_groups.resources("version-group");
+        let pokemon_moves = self
+            .table("pokemon_moves.csv")?
+            .rows_where("pokemon_id", id)
+            .into_group_map_by(|row| row.get("move_id"))
+            .into_iter()
+            .sorted_by_key(|(move_id, _)| move_id.parse::<i64>().unwrap_or_default())
+            .map(|(move_id, rows)| {
+                let version_group_details = rows
+                    .iter()
+                    .sorted_by_key(|row| row.int("version_group_id"))
+                    .map(|row| {
+                        json!({
+                            "move_learn_method": methods.get(row.get("pokemon_move_method_id")),
+                            "version_group": version_groups.get(row.get("version_group_id")),
+                            "level_learned_at": row.int("level").unwrap_or_default(),
+                        })
+                    })
+                    .collect_vec();
+
+                json!({ "move": moves.get(move_id), "version_group_details": version_group_details })
+            })
+            .collect_vec();
+
+        let forms = self
+            .table("pokemon_forms.csv")?
+            .rows_where("pokemon_id", id)
+            .map(|row| named_resource("pokemon-form", row.get("identifier"), row.get("id")))
+            .collect_vec();
+
+        Self::build(json!({
+            "id": pokemon.int("id"),
+            "name": pokemon.get("identifier"),
+            "base_experience": pokemon.int("base_experience"),
+            "height": pokemon.int("height").unwrap_or_default(),
+            "is_default": pokemon.flag("is_default"),
+            "order": pokemon.int("order").unwrap_or_default(),
+            "weight": pokemon.int("weight").unwrap_or_default(),
+            "abilities": pokemon_abilities,
+            "forms": forms,
+            "game_indices": [],
+            "held_items": [],
+            "location_area_encounters": format!("/api/v2/pokemon/{id}/encounters"),
+            "moves": pokemon_moves,
+            "past_abilities": [],
+            "past_stats": [],
+            "past_types": [],
+            "sprites": empty_sprites(),
+            "cries": {},
+            "species": self.resource("pokemon_species.csv", "pokemon-species", pokemon.get("species_id"))?,
+            "stats": pokemon_stats,
+            "types": pokemon_types,
+        }))
+    }
+
+    async fn fetch_pokemon_encounters(
+        &self,
+        pokemon_name: &str,
+    ) -> Result<Vec<LocationAreaEncounter>, ClientError> {
+        let pokemon_table = self.table("pokemon.csv")?;
+        let id = pokemon_table
+            .find_named(pokemon_name)
+            .ok_or_else(|| Self::not_found("Pokemon", pokemon_name))?
+            .get("id");
+
+        let versions = self.table("versions.csv")?;
+        let versions = versions.resources("version");
+        let methods = self.table("encounter_methods.csv")?;
+        let methods = methods.resources("encounter-method");
+        let condition_values = self.table("encounter_condition_values.csv")?;
+        let condition_values = condition_values.resources("encounter-condition-value");
+        let condition_value_map = self.table("encounter_condition_value_map.csv")?;
+        let slots = self.table("encounter_slots.csv")?;
+        let locations = self.table("locations.csv")?;
+        let location_areas = self.table("location_areas.csv")?;
+
+        let encounter_detail = |row: &Row| {
+            let slot = slots.find("id", row.get("encounter_slot_id"));
+            let method_id = slot.map(|slot| slot.get("encounter_method_id"));
+            let conditions = condition_value_map
+                .rows_where("encounter_id", row.get("id"))
+                .map(|condition| {
+                    condition_values.get(condition.get("encounter_condition_value_id"))
+                })
+                .collect_vec();
+
+            json!({
+                "min_level": row.int("min_level").unwrap_or_default(),
+                "max_level": row.int("max_level").unwrap_or_default(),
+                "condition_values": conditions,
+                "chance": slot.and_then(|slot| slot.int("rarity")).unwrap_or_default(),
+                "method": methods.get(method_id.unwrap_or_default()),
+            })
+        };
+
+        let encounters = self.table("encounters.csv")?;
+        let encounters = encounters
+            .rows_where("pokemon_id", id)
+            .into_group_map_by(|row| row.get("location_area_id"))
+            .into_iter()
+            .sorted_by_key(|(area_id, _)| area_id.parse::<i64>().unwrap_or_default())
+            .map(|(area_id, rows)| {
+                // PokeAPI names areas after their location, with unnamed areas ending in "area"
+                let area = location_areas.find("id", area_id);
+                let location_name = area
+                    .and_then(|area| locations.find("id", area.get("location_id")))
+                    .map(|location| location.get("identifier"))
+                    .unwrap_or_default();
+                let area_name = match area.map(|area| area.get("identifier")) {
+                    Some("") | None => format!("{location_name}-area"),
+                    Some(identifier) => format!("{location_name}-{identifier}"),
+                };
+
+                let version_details = rows
+                    .iter()
+                    .into_group_map_by(|row| row.get("version_id"))
+                    .into_iter()
+                    .sorted_by_key(|(version_id, _)| version_id.parse::<i64>().unwrap_or_default())
+                    .map(|(version_id, rows)| {
+                        let encounter_details =
+                            rows.into_iter().map(encounter_detail).collect_vec();
+                        let max_chance: i64 = encounter_details
+                            .iter()
+                            .filter_map(|detail| detail["chance"].as_i64())
+                            .sum();
+
+                        json!({
+                            "version": versions.get(version_id),
+                            "max_chance": max_chance.min(100),
+                            "encounter_details": encounter_details,
+                        })
+                    })
+                    .collect_vec();
+
+                json!({
+                    "location_area": named_resource("location-area", &area_name, area_id),
+                    "version_details": version_details,
+                })
+            })
+            .collect_vec();
+
+        Self::build(json!(encounters))
+    }
+
+    async fn fetch_pokemon_species(
+        &self,
+        species_name: &str,
+    ) -> Result<PokemonSpecies, ClientError> {
+        let species_table = self.table("pokemon_species.csv")?;
+        let species = species_table
+            .find_named(species_name)
+            .ok_or_else(|| Self::not_found("Pokemon species", species_name))?;
+        let id = species.get("id");
+        let language = self.english()?;
+
+        let egg_groups = self.table("egg_groups.csv")?;
+        let egg_groups = egg_groups.resources("egg-group");
+        let species_egg_groups = self
+            .table("pokemon_egg_groups.csv")?
+            .rows_where("species_id", id)
+            .map(|row| egg_groups.get(row.get("egg_group_id")))
+            .collect_vec();
+
+        let species_names = self.table("pokemon_species_names.csv")?;
+        let (names, genera): (Vec<_>, Vec<_>) = self
+            .english_rows(&species_names, "pokemon_species_id", id)?
+            .map(|row| {
+                (
+                    json!({ "name": row.get("name"), "language": language }),
+                    json!({ "genus": row.get("genus"), "language": language }),
+                )
+            })
+            .unzip();
+
+        let versions = self.table("versions.csv")?;
+        let versions = versions.resources("version");
+        let flavor_text = self.table("pokemon_species_flavor_text.csv")?;
+        let flavor_text_entries = self
+            .english_rows(&flavor_text, "species_id", id)?
+            .map(|row| {
+                json!({
+                    "flavor_text": row.get("flavor_text"),
+                    "language": language,
+                    "version": versions.get(row.get("version_id")),
+                })
+            })
+            .collect_vec();
+
+        let pokemon_table = self.table("pokemon.csv")?;
+        let varieties = pokemon_table
+            .rows_where("species_id", id)
+            .map(|row| {
+                json!({
+                    "is_default": row.flag("is_default"),
+                    "pokemon": named_resource("pokemon", row.get("identifier"), row.get("id")),
+                })
+            })
+            .collect_vec();
+
+        let evolution_chain = match species.get("evolution_chain_id") {
+            "" => Value::Null,
+            chain_id => json!({ "url": resource_url("evolution-chain", chain_id) }),
+        };
+
+        Self::build(json!({
+            "id": species.int("id"),
+            "name": species.get("identifier"),
+            "order": species.int("order").unwrap_or_default(),
+            "gender_rate": species.int("gender_rate").unwrap_or_default(),
+            "capture_rate": species.int("capture_rate").unwrap_or_default(),
+            "base_happiness": species.int("base_happiness"),
+            "is_baby": species.flag("is_baby"),
+            "is_legendary": species.flag("is_legendary"),
+            "is_mythical": species.flag("is_mythical"),
+            "hatch_counter": species.int("hatch_counter"),
+            "has_gender_differences": species.flag("has_gender_differences"),
+            "forms_switchable": species.flag("forms_switchable"),
+            "growth_rate": self.resource("growth_rates.csv", "growth-rate", species.get("growth_rate_id"))?,
+            "pokedex_numbers": [],
+            "egg_groups": species_egg_groups,
+            "color": self.resource("pokemon_colors.csv", "pokemon-color", species.get("color_id"))?,
+            "shape": self.resource("pokemon_shapes.csv", "pokemon-shape", species.get("shape_id"))?,
+            "evolves_from_species": self.resource(
+                "pokemon_species.csv",
+                "pokemon-species",
+                species.get("evolves_from_species_id"),
+            )?,
+            "evolution_chain": evolution_chain,
+            "habitat": self.resource("pokemon_habitats.csv", "pokemon-habitat", species.get("habitat_id"))?,
+            "generation": self.resource("generations.csv", "generation", species.get("generation_id"))?,
+            "names": names,
+            "pal_park_encounters": [],
+            "flavor_text_entries": flavor_text_entries,
+            "form_descriptions": [],
+            "genera": genera,
+            "varieties": varieties,
+        }))
+    }
+
+    async fn fetch_type(&self, type_name: &str) -> Result<Type, ClientError> {
+        let types = self.table("types.csv")?;
+        let type_ = types
+            .find_named(type_name)
+            .ok_or_else(|| Self::not_found("Type", type_name))?;
+        let id = type_.get("id");
+
+        let pokemon_table = self.table("pokemon.csv")?;
+        let pokemon_resources = pokemon_table.resources("pokemon");
+        let pokemon = self
+            .table("pokemon_types.csv")?
+            .rows_where("type_id", id)
+            .map(|row| {
+                json!({
+                    "slot": row.int("slot"),
+                    "pokemon": pokemon_resources.get(row.get("pokemon_id")),
+                })
+            })
+            .collect_vec();
+
+        let moves = self
+            .table("moves.csv")?
+            .rows_where("type_id", id)
+            .map(|row| named_resource("move", row.get("identifier"), row.get("id")))
+            .collect_vec();
+
+        Self::build(json!({
+            "id": type_.int("id"),
+            "name": type_.get("identifier"),
+            "damage_relations": self.type_relations(id, None)?,
+            "past_damage_relations": self.past_type_relations(id)?,
+            "game_indices": [],
+            "generation": self.resource("generations.csv", "generation", type_.get("generation_id"))?,
+            "move_damage_class": self.resource(
+                "move_damage_classes.csv",
+                "move-damage-class",
+                type_.get("damage_class_id"),
+            )?,
+            "names": self.names("type_names.csv", "type_id", id)?,
+            "pokemon": pokemon,
+            "moves": moves,
+        }))
+    }
+
+    async fn fetch_evolution_chain_from_url(
+        &self,
+        evolution_chain: &str,
+    ) -> Result<EvolutionChain, ClientError> {
+        let id = evolution_chain
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or_default();
+
+        let chains = self.table("evolution_chains.csv")?;
+        let chain = chains
+            .find("id", id)
+            .ok_or_else(|| Self::not_found("Evolution chain", evolution_chain))?;
+
+        let species_table = self.table("pokemon_species.csv")?;
+        let chain_species = species_table
+            .rows_where("evolution_chain_id", id)
+            .collect_vec();
+        let root = chain_species
+            .iter()
+            .find(|row| {
+                !chain_species
+                    .iter()
+                    .any(|other| other.get("id") == row.get("evolves_from_species_id"))
+            })
+            .ok_or_else(|| Self::not_found("Evolution chain", evolution_chain))?;
+
+        let evolutions = self.table("pokemon_evolution.csv")?;
+
+        Self::build(json!({
+            "id": chain.int("id"),
+            "baby_trigger_item": self.resource("items.csv", "item", chain.get("baby_trigger_item_id"))?,
+            "chain": self.chain_link(root, &chain_species, &evolutions)?,
+        }))
+    }
+}
+
+// Prose links are written as `[label]{category:identifier}`, with an empty label meaning the
+// identifier should be shown instead
+fn strip_markup(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('[') {
+        let Some((label, target)) = rest[start + 1..]
+            .split_once("]{")
+            .and_then(|(label, after)| after.split_once('}').map(|(target, _)| (label, target)))
+        else {
+            break;
+        };
+
+        output.push_str(&rest[..start]);
+
+        if label.is_empty() {
+            let identifier = target.split_once(':').map_or(target, |(_, id)| id);
+            output.push_str(&identifier.replace('-', " "));
+        } else {
+            output.push_str(label);
+        }
+
+        rest = &rest[start + 1 + label.len() + 2 + target.len() + 1..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+fn empty_sprites() -> Value {
+    json!({
+        "other": {
+            "dream_world": {},
+            "home": {},
+            "official-artwork": {},
+        },
+        "versions": {
+            "generation-i": { "red-blue": {}, "yellow": {} },
+            "generation-ii": { "crystal": {}, "gold": {}, "silver": {} },
+            "generation-iii": { "emerald": {}, "firered-leafgreen": {}, "ruby-sapphire": {} },
+            "generation-iv": { "diamond-pearl": {}, "platinum": {}, "heartgold-soulsilver": {} },
+            "generation-v": { "black-white": { "animated": {} } },
+            "generation-vi": { "omegaruby-alphasapphire": {}, "x-y": {} },
+            "generation-vii": { "icons": {}, "ultra-sun-ultra-moon": {} },
+            "generation-viii": { "icons": {} },
+        },
+    })
+}
@@ -0,0 +1,157 @@
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use csv::StringRecord;
+use serde_json::{Value, json};
+
+use super::API_URL;
+
+// Row positions for each value in a column
+type ColumnIndex = HashMap<String, Vec<usize>>;
+
+// Columns the API looks resources up by, which are indexed as soon as a table is read
+const KEY_COLUMNS: &[&str] = &["id", "identifier"];
+
+pub struct Table {
+    columns: HashMap<String, usize>,
+    records: Vec<StringRecord>,
+    // Other columns are indexed the first time they're searched, as most are never used
+    indexes: Mutex<HashMap<String, Arc<ColumnIndex>>>,
+}
+
+impl Table {
+    pub fn read(path: &Path) -> Result<Self, csv::Error> {
+        let mut reader = csv::Reader::from_path(path)?;
+
+        let columns = reader
+            .headers()?
+            .iter()
+            .enumerate()
+            .map(|(index, column)| (column.to_owned(), index))
+            .collect();
+
+        let records = reader.records().collect::<Result<Vec<_>, _>>()?;
+
+        let table = Self {
+            columns,
+            records,
+            indexes: Mutex::default(),
+        };
+
+        for column in KEY_COLUMNS {
+            table.index(column);
+        }
+
+        Ok(table)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = Row<'_>> {
+        self.records.iter().map(|record| Row {
+            table: self,
+            record,
+        })
+    }
+
+    pub fn rows_where<'a>(&'a self, column: &str, value: &str) -> impl Iterator<Item = Row<'a>> {
+        let positions = self.index(column).get(value).cloned().unwrap_or_default();
+
+        positions.into_iter().map(|position| Row {
+            table: self,
+            record: &self.records[position],
+        })
+    }
+
+    pub fn find<'a>(&'a self, column: &str, value: &str) -> Option<Row<'a>> {
+        self.rows_where(column, value).next()
+    }
+
+    // The API accepts either the identifier or the id of a resource
+    pub fn find_named(&self, name: &str) -> Option<Row<'_>> {
+        self.find("identifier", name)
+            .or_else(|| self.find("id", name))
+    }
+
+    pub fn resources(&self, resource: &'static str) -> Resources<'_> {
+        Resources {
+            resource,
+            table: self,
+        }
+    }
+
+    fn index(&self, column: &str) -> Arc<ColumnIndex> {
+        // A poisoned lock only means another thread panicked while indexing, so the
+        // indexes which were finished are still usable
+        let mut indexes = self
+            .indexes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(index) = indexes.get(column) {
+            return Arc::clone(index);
+        }
+
+        let mut index = ColumnIndex::new();
+
+        for (position, row) in self.rows().enumerate() {
+            index
+                .entry(row.get(column).to_owned())
+                .or_default()
+                .push(position);
+        }
+
+        let index = Arc::new(index);
+        indexes.insert(column.to_owned(), Arc::clone(&index));
+
+        index
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Row<'a> {
+    table: &'a Table,
+    record: &'a StringRecord,
+}
+
+impl<'a> Row<'a> {
+    // Missing columns read as empty so optional columns from newer data revisions can be used
+    pub fn get(&self, column: &str) -> &'a str {
+        self.table
+            .columns
+            .get(column)
+            .and_then(|index| self.record.get(*index))
+            .unwrap_or_default()
+    }
+
+    pub fn int(&self, column: &str) -> Option<i64> {
+        self.get(column).parse().ok()
+    }
+
+    pub fn flag(&self, column: &str) -> bool {
+        self.get(column) == "1"
+    }
+}
+
+pub struct Resources<'a> {
+    resource: &'static str,
+    table: &'a Table,
+}
+
+impl Resources<'_> {
+    pub fn get(&self, id: &str) -> Value {
+        match self.table.find("id", id) {
+            Some(row) => named_resource(self.resource, row.get("identifier"), id),
+            None => Value::Null,
+        }
+    }
+}
+
+pub fn named_resource(resource: &str, name: &str, id: &str) -> Value {
+    json!({ "name": name, "url": resource_url(resource, id) })
+}
+
+pub fn resource_url(resource: &str, id: &str) -> String {
+    format!("{API_URL}/{resource}/{id}/")
+}
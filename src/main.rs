@@ -1,14 +1,26 @@
 use clap::Parser;
-use poke_search::{Cli, Client, run};
+use poke_search::{
+    Cli, Client, ClientImplementation, Config, LocalClient, client::ClientError, run,
+};
 
 #[tokio::main]
 async fn main() {
-    let client = Client::try_build().unwrap_or_else(|e| {
+    let cli = Cli::parse();
+
+    let client = build_client(&cli).unwrap_or_else(|e| {
         eprintln!("Failed to initialise client: {e}");
         std::process::exit(1);
     });
 
-    let cli = Cli::parse();
+    run(client.as_ref(), cli).await.print();
+}
 
-    run(&client, cli).await.print();
+fn build_client(cli: &Cli) -> Result<Box<dyn ClientImplementation>, ClientError> {
+    match &cli.data_dir {
+        Some(data_dir) => Ok(Box::new(LocalClient::try_build(data_dir.clone())?)),
+        None => {
+            let config = Config::load().map_err(ClientError::Config)?;
+            Ok(Box::new(Client::try_build(cli.client_options(config))?))
+        }
+    }
 }
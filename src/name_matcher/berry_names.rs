@@ -0,0 +1,71 @@
+use std::sync::LazyLock;
+
+// The berry endpoint names berries without the "-berry" suffix their items have
+pub static BERRY_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    vec![
+        String::from("aguav"),
+        String::from("apicot"),
+        String::from("aspear"),
+        String::from("babiri"),
+        String::from("belue"),
+        String::from("bluk"),
+        String::from("charti"),
+        String::from("cheri"),
+        String::from("chesto"),
+        String::from("chilan"),
+        String::from("chople"),
+        String::from("coba"),
+        String::from("colbur"),
+        String::from("cornn"),
+        String::from("custap"),
+        String::from("durin"),
+        String::from("enigma"),
+        String::from("figy"),
+        String::from("ganlon"),
+        String::from("grepa"),
+        String::from("haban"),
+        String::from("hondew"),
+        String::from("iapapa"),
+        String::from("jaboca"),
+        String::from("kasib"),
+        String::from("kebia"),
+        String::from("kelpsy"),
+        String::from("lansat"),
+        String::from("leppa"),
+        String::from("liechi"),
+        String::from("lum"),
+        String::from("mago"),
+        String::from("magost"),
+        String::from("micle"),
+        String::from("nanab"),
+        String::from("nomel"),
+        String::from("occa"),
+        String::from("oran"),
+        String::from("pamtre"),
+        String::from("passho"),
+        String::from("payapa"),
+        String::from("pecha"),
+        String::from("persim"),
+        String::from("petaya"),
+        String::from("pinap"),
+        String::from("pomeg"),
+        String::from("qualot"),
+        String::from("rabuta"),
+        String::from("rawst"),
+        String::from("razz"),
+        String::from("rindo"),
+        String::from("rowap"),
+        String::from("salac"),
+        String::from("shuca"),
+        String::from("sitrus"),
+        String::from("spelon"),
+        String::from("starf"),
+        String::from("tamato"),
+        String::from("tanga"),
+        String::from("wacan"),
+        String::from("watmel"),
+        String::from("wepear"),
+        String::from("wiki"),
+        String::from("yache"),
+    ]
+});
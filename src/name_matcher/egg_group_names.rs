@@ -0,0 +1,34 @@
+use std::sync::LazyLock;
+
+pub static EGG_GROUP_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    vec![
+        String::from("bug"),
+        String::from("ditto"),
+        String::from("dragon"),
+        String::from("fairy"),
+        String::from("flying"),
+        String::from("ground"),
+        String::from("humanshape"),
+        String::from("indeterminate"),
+        String::from("mineral"),
+        String::from("monster"),
+        String::from("no-eggs"),
+        String::from("plant"),
+        String::from("water1"),
+        String::from("water2"),
+        String::from("water3"),
+    ]
+});
+
+/// The names the games show for egg groups which PokeAPI names differently, as
+/// `(api_name, game_name)`
+pub const EGG_GROUP_GAME_NAMES: &[(&str, &str)] = &[
+    ("ground", "Field"),
+    ("humanshape", "Human-Like"),
+    ("indeterminate", "Amorphous"),
+    ("no-eggs", "Undiscovered"),
+    ("plant", "Grass"),
+    ("water1", "Water 1"),
+    ("water2", "Water 2"),
+    ("water3", "Water 3"),
+];
@@ -1,9 +1,11 @@
 use crate::{
     formatter::capitalise,
     name_matcher::{
-        ability_names::ABILITY_NAMES, generation_names::GENERATION_NAMES, item_names::ITEM_NAMES,
+        ability_names::ABILITY_NAMES, berry_names::BERRY_NAMES, egg_group_names::EGG_GROUP_NAMES,
+        generation_names::GENERATION_NAMES, item_names::ITEM_NAMES,
         move_damage_class_names::MOVE_DAMAGE_CLASS_NAMES, move_names::MOVE_NAMES,
-        pokemon_names::POKEMON_NAMES, type_names::TYPE_NAMES,
+        nature_names::NATURE_NAMES, pokemon_names::POKEMON_NAMES, type_names::TYPE_NAMES,
+        version_group_names::VERSION_GROUP_NAMES,
     },
 };
 
@@ -15,12 +17,16 @@ static MIN_CERTAIN_SIMILARITY: f32 = 0.71;
 
 enum MatcherType {
     Ability,
+    Berry,
+    EggGroup,
     Generation,
     Item,
     Pokemon,
     Move,
     MoveDamageCategory,
+    Nature,
     Type,
+    VersionGroup,
 }
 
 pub struct SuccessfulMatch {
@@ -110,6 +116,14 @@ pub fn match_ability_name(name: &str) -> Result<SuccessfulMatch, NoMatch> {
     match_name(name, MatcherType::Ability)
 }
 
+pub fn match_berry_name(name: &str) -> Result<SuccessfulMatch, NoMatch> {
+    match_name(name, MatcherType::Berry)
+}
+
+pub fn match_egg_group_name(name: &str) -> Result<SuccessfulMatch, NoMatch> {
+    match_name(name, MatcherType::EggGroup)
+}
+
 pub fn match_generation_name(name: &str) -> Result<SuccessfulMatch, NoMatch> {
     match_name(name, MatcherType::Generation)
 }
@@ -126,6 +140,10 @@ pub fn match_move_damage_category_name(name: &str) -> Result<SuccessfulMatch, No
     match_name(name, MatcherType::MoveDamageCategory)
 }
 
+pub fn match_nature_name(name: &str) -> Result<SuccessfulMatch, NoMatch> {
+    match_name(name, MatcherType::Nature)
+}
+
 pub fn match_pokemon_name(name: &str) -> Result<SuccessfulMatch, NoMatch> {
     match_name(name, MatcherType::Pokemon)
 }
@@ -134,6 +152,10 @@ pub fn match_type_name(name: &str) -> Result<SuccessfulMatch, NoMatch> {
     match_name(name, MatcherType::Type)
 }
 
+pub fn match_version_group_name(name: &str) -> Result<SuccessfulMatch, NoMatch> {
+    match_name(name, MatcherType::VersionGroup)
+}
+
 pub fn build_suggested_name(keyword: &str, name: &str, suggestion: &str) -> String {
     format!("Unknown {keyword} \"{name}\"\nDid you mean \"{suggestion}\"?")
 }
@@ -182,12 +204,16 @@ fn name_is_already_valid(names: &[String], item: &str) -> bool {
 fn matcher_and_keyword<'a>(matcher_type: MatcherType) -> (NameMatcher<'a>, String) {
     let (names, keyword) = match matcher_type {
         MatcherType::Ability => (&ABILITY_NAMES, "ability"),
+        MatcherType::Berry => (&BERRY_NAMES, "berry"),
+        MatcherType::EggGroup => (&EGG_GROUP_NAMES, "egg group"),
         MatcherType::Generation => (&GENERATION_NAMES, "generation"),
         MatcherType::Item => (&ITEM_NAMES, "item"),
         MatcherType::Move => (&MOVE_NAMES, "move"),
         MatcherType::MoveDamageCategory => (&MOVE_DAMAGE_CLASS_NAMES, "move damage category"),
+        MatcherType::Nature => (&NATURE_NAMES, "nature"),
         MatcherType::Pokemon => (&POKEMON_NAMES, "pokemon"),
         MatcherType::Type => (&TYPE_NAMES, "type"),
+        MatcherType::VersionGroup => (&VERSION_GROUP_NAMES, "version group"),
     };
 
     (NameMatcher::new(names), String::from(keyword))
@@ -1,8 +1,13 @@
 pub mod ability_names;
+pub mod berry_names;
+pub mod egg_group_names;
 pub mod generation_names;
 pub mod item_names;
 pub mod matcher;
 pub mod move_damage_class_names;
+pub mod move_learn_method_names;
 pub mod move_names;
+pub mod nature_names;
 pub mod pokemon_names;
 pub mod type_names;
+pub mod version_group_names;
@@ -0,0 +1,17 @@
+use std::sync::LazyLock;
+
+pub static MOVE_LEARN_METHOD_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    vec![
+        String::from("colosseum-purification"),
+        String::from("egg"),
+        String::from("form-change"),
+        String::from("level-up"),
+        String::from("light-ball-egg"),
+        String::from("machine"),
+        String::from("stadium-surfing-pikachu"),
+        String::from("tutor"),
+        String::from("xd-purification"),
+        String::from("xd-shadow"),
+        String::from("zygarde-cube"),
+    ]
+});
@@ -0,0 +1,31 @@
+use std::sync::LazyLock;
+
+pub static NATURE_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    vec![
+        String::from("adamant"),
+        String::from("bashful"),
+        String::from("bold"),
+        String::from("brave"),
+        String::from("calm"),
+        String::from("careful"),
+        String::from("docile"),
+        String::from("gentle"),
+        String::from("hardy"),
+        String::from("hasty"),
+        String::from("impish"),
+        String::from("jolly"),
+        String::from("lax"),
+        String::from("lonely"),
+        String::from("mild"),
+        String::from("modest"),
+        String::from("naive"),
+        String::from("naughty"),
+        String::from("quiet"),
+        String::from("quirky"),
+        String::from("rash"),
+        String::from("relaxed"),
+        String::from("sassy"),
+        String::from("serious"),
+        String::from("timid"),
+    ]
+});
@@ -0,0 +1,35 @@
+use std::sync::LazyLock;
+
+pub static VERSION_GROUP_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    vec![
+        String::from("black-2-white-2"),
+        String::from("black-white"),
+        String::from("blue-japan"),
+        String::from("brilliant-diamond-shining-pearl"),
+        String::from("colosseum"),
+        String::from("crystal"),
+        String::from("diamond-pearl"),
+        String::from("emerald"),
+        String::from("firered-leafgreen"),
+        String::from("gold-silver"),
+        String::from("heartgold-soulsilver"),
+        String::from("legends-arceus"),
+        String::from("lets-go-pikachu-lets-go-eevee"),
+        String::from("omega-ruby-alpha-sapphire"),
+        String::from("platinum"),
+        String::from("red-blue"),
+        String::from("red-green-japan"),
+        String::from("ruby-sapphire"),
+        String::from("scarlet-violet"),
+        String::from("sun-moon"),
+        String::from("sword-shield"),
+        String::from("the-crown-tundra"),
+        String::from("the-indigo-disk"),
+        String::from("the-isle-of-armor"),
+        String::from("the-teal-mask"),
+        String::from("ultra-sun-ultra-moon"),
+        String::from("x-y"),
+        String::from("xd"),
+        String::from("yellow"),
+    ]
+});
@@ -0,0 +1,307 @@
+use crate::{
+    input_parser::{generation_number, parse_generation, parse_name},
+    name_matcher::matcher,
+    stat_calculator::{self, STAT_ABBREVIATIONS, STAT_API_NAMES, STAT_COUNT},
+};
+
+use itertools::Itertools;
+use rustemon::model::pokemon::{Pokemon, PokemonSpecies};
+
+const OPERATORS: &[(&str, Comparison)] = &[
+    (">=", Comparison::GreaterOrEqual),
+    ("<=", Comparison::LessOrEqual),
+    (">", Comparison::Greater),
+    ("<", Comparison::Less),
+    ("=", Comparison::Equal),
+    (":", Comparison::Equal),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    Greater,
+}
+
+impl Comparison {
+    fn matches(&self, value: i64, target: i64) -> bool {
+        match self {
+            Comparison::Less => value < target,
+            Comparison::LessOrEqual => value <= target,
+            Comparison::Equal => value == target,
+            Comparison::GreaterOrEqual => value >= target,
+            Comparison::Greater => value > target,
+        }
+    }
+}
+
+/// A base stat, or the total of every base stat
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchStat {
+    Stat(usize),
+    Total,
+}
+
+impl SearchStat {
+    pub fn find(name: &str) -> Option<SearchStat> {
+        let name = parse_name(name);
+
+        match name.as_str() {
+            "bst" | "total" => Some(SearchStat::Total),
+            "spatk" | "sp-atk" => Some(SearchStat::Stat(stat_calculator::SPECIAL_ATTACK)),
+            "spdef" | "sp-def" => Some(SearchStat::Stat(stat_calculator::SPECIAL_DEFENSE)),
+            _ => (0..STAT_COUNT)
+                .find(|stat| {
+                    STAT_API_NAMES[*stat] == name
+                        || STAT_ABBREVIATIONS[*stat].eq_ignore_ascii_case(&name)
+                })
+                .map(SearchStat::Stat),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SearchStat::Stat(stat) => STAT_API_NAMES[*stat],
+            SearchStat::Total => "total",
+        }
+    }
+
+    pub fn value(&self, pokemon: &Pokemon) -> i64 {
+        let base_stats = stat_calculator::base_stats(pokemon);
+
+        match self {
+            SearchStat::Stat(stat) => base_stats[*stat],
+            SearchStat::Total => base_stats.iter().sum(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Filter {
+    Type(String),
+    Ability(String),
+    Generation(Comparison, i64),
+    Stat(SearchStat, Comparison, i64),
+}
+
+impl Filter {
+    /// Parses a single term such as `type:water`, `speed>=100` or `gen<=4`, resolving names
+    /// through the matcher so they can be misspelt like everywhere else
+    pub fn parse(term: &str) -> Result<Filter, String> {
+        let invalid_term = || {
+            format!(
+                "'{term}' isn't a valid filter. Use type:<type>, ability:<ability>, gen<op><generation> or <stat><op><number> (e.g. speed>=100)"
+            )
+        };
+
+        let (key, comparison, value) = OPERATORS
+            .iter()
+            .filter_map(|(operator, comparison)| {
+                let index = term.find(operator)?;
+                Some((index, operator, comparison))
+            })
+            // `>=` and `>` both match at the same position so prefer the longest operator
+            .min_by_key(|(index, operator, _)| (*index, std::cmp::Reverse(operator.len())))
+            .map(|(index, operator, comparison)| {
+                (
+                    term[..index].trim().to_lowercase(),
+                    *comparison,
+                    term[index + operator.len()..].trim(),
+                )
+            })
+            .ok_or_else(invalid_term)?;
+
+        if value.is_empty() {
+            return Err(invalid_term());
+        }
+
+        match key.as_str() {
+            "type" if comparison == Comparison::Equal => {
+                matcher::match_type_name(&parse_name(value))
+                    .map(|successful_match| Filter::Type(successful_match.suggested_name))
+                    .map_err(|no_match| no_match.0)
+            }
+            "ability" if comparison == Comparison::Equal => {
+                matcher::match_ability_name(&parse_name(value))
+                    .map(|successful_match| Filter::Ability(successful_match.suggested_name))
+                    .map_err(|no_match| no_match.0)
+            }
+            "gen" | "generation" => {
+                let generation = parse_generation(value)?;
+                let number = generation_number(&generation).ok_or_else(invalid_term)?;
+                Ok(Filter::Generation(comparison, number))
+            }
+            _ => {
+                let stat = SearchStat::find(&key).ok_or_else(invalid_term)?;
+                let target = value.parse::<i64>().map_err(|_| invalid_term())?;
+                Ok(Filter::Stat(stat, comparison, target))
+            }
+        }
+    }
+
+    fn matches(&self, pokemon: &Pokemon, species: Option<&PokemonSpecies>) -> bool {
+        match self {
+            Filter::Type(type_name) => pokemon
+                .types
+                .iter()
+                .any(|type_| &type_.type_.name == type_name),
+            Filter::Ability(ability_name) => pokemon.abilities.iter().any(|pokemon_ability| {
+                pokemon_ability
+                    .ability
+                    .as_ref()
+                    .is_some_and(|ability| &ability.name == ability_name)
+            }),
+            Filter::Generation(comparison, target) => species
+                .and_then(|species| generation_number(&species.generation.name))
+                .is_some_and(|number| comparison.matches(number, *target)),
+            Filter::Stat(stat, comparison, target) => {
+                comparison.matches(stat.value(pokemon), *target)
+            }
+        }
+    }
+}
+
+/// Every filter a pokemon has to pass to be included in the results
+#[derive(Debug, Default)]
+pub struct Query {
+    pub filters: Vec<Filter>,
+}
+
+impl Query {
+    pub fn parse(terms: &[String]) -> Result<Query, String> {
+        let filters = terms
+            .iter()
+            .map(|term| Filter::parse(term))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Query { filters })
+    }
+
+    pub fn type_names(&self) -> Vec<&str> {
+        self.filters
+            .iter()
+            .filter_map(|filter| match filter {
+                Filter::Type(type_name) => Some(type_name.as_str()),
+                _ => None,
+            })
+            .collect_vec()
+    }
+
+    /// Generations are checked against a pokemon's species, so this is also whether the species
+    /// has to be fetched
+    pub fn has_generation_filter(&self) -> bool {
+        self.filters
+            .iter()
+            .any(|filter| matches!(filter, Filter::Generation(..)))
+    }
+
+    /// Whether a generation passes every generation filter
+    pub fn includes_generation(&self, number: i64) -> bool {
+        self.filters.iter().all(|filter| match filter {
+            Filter::Generation(comparison, target) => comparison.matches(number, *target),
+            _ => true,
+        })
+    }
+
+    pub fn ability_names(&self) -> Vec<&str> {
+        self.filters
+            .iter()
+            .filter_map(|filter| match filter {
+                Filter::Ability(ability_name) => Some(ability_name.as_str()),
+                _ => None,
+            })
+            .collect_vec()
+    }
+
+    pub fn matches(&self, pokemon: &Pokemon, species: Option<&PokemonSpecies>) -> bool {
+        self.filters
+            .iter()
+            .all(|filter| filter.matches(pokemon, species))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filters() {
+        assert_eq!(
+            Filter::parse("type:water"),
+            Ok(Filter::Type(String::from("water")))
+        );
+        assert_eq!(
+            Filter::parse("ability:swift-swim"),
+            Ok(Filter::Ability(String::from("swift-swim")))
+        );
+        assert_eq!(
+            Filter::parse("gen<=4"),
+            Ok(Filter::Generation(Comparison::LessOrEqual, 4))
+        );
+        assert_eq!(
+            Filter::parse("generation=iv"),
+            Ok(Filter::Generation(Comparison::Equal, 4))
+        );
+        assert_eq!(
+            Filter::parse("speed>=100"),
+            Ok(Filter::Stat(
+                SearchStat::Stat(stat_calculator::SPEED),
+                Comparison::GreaterOrEqual,
+                100
+            ))
+        );
+        assert_eq!(
+            Filter::parse("SpA>120"),
+            Ok(Filter::Stat(
+                SearchStat::Stat(stat_calculator::SPECIAL_ATTACK),
+                Comparison::Greater,
+                120
+            ))
+        );
+        assert_eq!(
+            Filter::parse("bst<500"),
+            Ok(Filter::Stat(SearchStat::Total, Comparison::Less, 500))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_filters() {
+        assert!(Filter::parse("water").is_err());
+        assert!(Filter::parse("speed>=").is_err());
+        assert!(Filter::parse("speed>=fast").is_err());
+        assert!(Filter::parse("luck>=100").is_err());
+        assert!(Filter::parse("type>=water").is_err());
+        assert!(Filter::parse("gen<=42").is_err());
+    }
+
+    #[test]
+    fn test_find_search_stat() {
+        assert_eq!(
+            SearchStat::find("attack"),
+            Some(SearchStat::Stat(stat_calculator::ATTACK))
+        );
+        assert_eq!(
+            SearchStat::find("Special Defense"),
+            Some(SearchStat::Stat(stat_calculator::SPECIAL_DEFENSE))
+        );
+        assert_eq!(SearchStat::find("total"), Some(SearchStat::Total));
+        assert_eq!(SearchStat::find("luck"), None);
+    }
+
+    #[test]
+    fn test_includes_generation() {
+        let terms = [String::from("gen>=3"), String::from("gen<5")];
+        let query = Query::parse(&terms).unwrap();
+
+        assert!(query.has_generation_filter());
+        assert!(!query.includes_generation(2));
+        assert!(query.includes_generation(3));
+        assert!(query.includes_generation(4));
+        assert!(!query.includes_generation(5));
+
+        let query = Query::parse(&[String::from("type:water")]).unwrap();
+        assert!(!query.has_generation_filter());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::client::{ClientError, ClientImplementation};
+
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use rustemon::model::{
+    berries::Berry,
+    evolution::EvolutionChain,
+    games::Generation,
+    items::Item,
+    moves::{Move, MoveLearnMethod},
+    pokemon::{Ability, EggGroup, LocationAreaEncounter, Nature, Pokemon, PokemonSpecies, Type},
+};
+
+type Models<T> = Mutex<HashMap<String, T>>;
+
+/// Wraps another client and keeps every model it returns in memory, so repeated lookups
+/// during a `shell` session don't go back to the cache or the network.
+pub struct SessionClient<'a> {
+    client: &'a dyn ClientImplementation,
+    abilities: Models<Ability>,
+    berries: Models<Berry>,
+    egg_groups: Models<EggGroup>,
+    generations: Models<Generation>,
+    items: Models<Item>,
+    moves: Models<Move>,
+    move_learn_methods: Models<MoveLearnMethod>,
+    natures: Models<Nature>,
+    pokemon: Models<Pokemon>,
+    pokemon_encounters: Models<Vec<LocationAreaEncounter>>,
+    pokemon_species: Models<PokemonSpecies>,
+    types: Models<Type>,
+    evolution_chains: Models<EvolutionChain>,
+}
+
+impl<'a> SessionClient<'a> {
+    pub fn new(client: &'a dyn ClientImplementation) -> Self {
+        SessionClient {
+            client,
+            abilities: Models::default(),
+            berries: Models::default(),
+            egg_groups: Models::default(),
+            generations: Models::default(),
+            items: Models::default(),
+            moves: Models::default(),
+            move_learn_methods: Models::default(),
+            natures: Models::default(),
+            pokemon: Models::default(),
+            pokemon_encounters: Models::default(),
+            pokemon_species: Models::default(),
+            types: Models::default(),
+            evolution_chains: Models::default(),
+        }
+    }
+
+    async fn remember<T: Clone>(
+        models: &Models<T>,
+        key: &str,
+        fetch: impl Future<Output = Result<T, ClientError>>,
+    ) -> Result<T, ClientError> {
+        if let Some(model) = models
+            .lock()
+            .ok()
+            .and_then(|models| models.get(key).cloned())
+        {
+            return Ok(model);
+        }
+
+        let model = fetch.await?;
+
+        if let Ok(mut models) = models.lock() {
+            models.insert(key.to_owned(), model.clone());
+        }
+
+        Ok(model)
+    }
+}
+
+#[async_trait]
+impl ClientImplementation for SessionClient<'_> {
+    async fn fetch_ability(&self, ability_name: &str) -> Result<Ability, ClientError> {
+        Self::remember(
+            &self.abilities,
+            ability_name,
+            self.client.fetch_ability(ability_name),
+        )
+        .await
+    }
+
+    async fn fetch_berry(&self, berry_name: &str) -> Result<Berry, ClientError> {
+        Self::remember(
+            &self.berries,
+            berry_name,
+            self.client.fetch_berry(berry_name),
+        )
+        .await
+    }
+
+    async fn fetch_egg_group(&self, egg_group_name: &str) -> Result<EggGroup, ClientError> {
+        Self::remember(
+            &self.egg_groups,
+            egg_group_name,
+            self.client.fetch_egg_group(egg_group_name),
+        )
+        .await
+    }
+
+    async fn fetch_generation(&self, generation_name: &str) -> Result<Generation, ClientError> {
+        Self::remember(
+            &self.generations,
+            generation_name,
+            self.client.fetch_generation(generation_name),
+        )
+        .await
+    }
+
+    async fn fetch_item(&self, item_name: &str) -> Result<Item, ClientError> {
+        Self::remember(&self.items, item_name, self.client.fetch_item(item_name)).await
+    }
+
+    async fn fetch_move(&self, move_name: &str) -> Result<Move, ClientError> {
+        Self::remember(&self.moves, move_name, self.client.fetch_move(move_name)).await
+    }
+
+    async fn fetch_move_learn_method(
+        &self,
+        move_learn_method_name: &str,
+    ) -> Result<MoveLearnMethod, ClientError> {
+        Self::remember(
+            &self.move_learn_methods,
+            move_learn_method_name,
+            self.client.fetch_move_learn_method(move_learn_method_name),
+        )
+        .await
+    }
+
+    async fn fetch_nature(&self, nature_name: &str) -> Result<Nature, ClientError> {
+        Self::remember(
+            &self.natures,
+            nature_name,
+            self.client.fetch_nature(nature_name),
+        )
+        .await
+    }
+
+    async fn fetch_pokemon(&self, pokemon_name: &str) -> Result<Pokemon, ClientError> {
+        Self::remember(
+            &self.pokemon,
+            pokemon_name,
+            self.client.fetch_pokemon(pokemon_name),
+        )
+        .await
+    }
+
+    async fn fetch_pokemon_encounters(
+        &self,
+        pokemon_name: &str,
+    ) -> Result<Vec<LocationAreaEncounter>, ClientError> {
+        Self::remember(
+            &self.pokemon_encounters,
+            pokemon_name,
+            self.client.fetch_pokemon_encounters(pokemon_name),
+        )
+        .await
+    }
+
+    async fn fetch_pokemon_species(
+        &self,
+        species_name: &str,
+    ) -> Result<PokemonSpecies, ClientError> {
+        Self::remember(
+            &self.pokemon_species,
+            species_name,
+            self.client.fetch_pokemon_species(species_name),
+        )
+        .await
+    }
+
+    async fn fetch_type(&self, type_name: &str) -> Result<Type, ClientError> {
+        Self::remember(&self.types, type_name, self.client.fetch_type(type_name)).await
+    }
+
+    async fn fetch_evolution_chain_from_url(
+        &self,
+        evolution_chain: &str,
+    ) -> Result<EvolutionChain, ClientError> {
+        Self::remember(
+            &self.evolution_chains,
+            evolution_chain,
+            self.client.fetch_evolution_chain_from_url(evolution_chain),
+        )
+        .await
+    }
+}
@@ -0,0 +1,258 @@
+use rustemon::model::{
+    pokemon::{self, Pokemon, Stat},
+    resource::NamedApiResource,
+};
+use serde_json::{Map, json};
+
+pub const STAT_COUNT: usize = 6;
+
+pub const HP: usize = 0;
+pub const ATTACK: usize = 1;
+pub const DEFENSE: usize = 2;
+pub const SPECIAL_ATTACK: usize = 3;
+pub const SPECIAL_DEFENSE: usize = 4;
+pub const SPEED: usize = 5;
+
+pub const MAX_IV: i64 = 31;
+pub const MAX_EV: i64 = 252;
+pub const MAX_TOTAL_EVS: i64 = 510;
+
+// The names PokeAPI uses for each stat, in the order of the indices above
+pub static STAT_API_NAMES: [&str; STAT_COUNT] = [
+    "hp",
+    "attack",
+    "defense",
+    "special-attack",
+    "special-defense",
+    "speed",
+];
+
+pub static STAT_ABBREVIATIONS: [&str; STAT_COUNT] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+/// Six values in HP, Attack, Defense, Special Attack, Special Defense, Speed order
+pub type StatSpread = [i64; STAT_COUNT];
+
+/// The stats a nature raises and lowers by 10%. Neutral natures change neither
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Nature {
+    pub name: String,
+    pub increased: Option<usize>,
+    pub decreased: Option<usize>,
+}
+
+impl Nature {
+    /// The nature multiplier for a stat as a percentage
+    fn modifier(&self, stat: usize) -> i64 {
+        if self.increased == Some(stat) {
+            110
+        } else if self.decreased == Some(stat) {
+            90
+        } else {
+            100
+        }
+    }
+}
+
+impl From<&pokemon::Nature> for Nature {
+    fn from(nature: &pokemon::Nature) -> Self {
+        let stat_index = |stat: &Option<NamedApiResource<Stat>>| {
+            let stat_name = &stat.as_ref()?.name;
+            STAT_API_NAMES.iter().position(|name| name == stat_name)
+        };
+
+        Nature {
+            name: nature.name.clone(),
+            increased: stat_index(&nature.increased_stat),
+            decreased: stat_index(&nature.decreased_stat),
+        }
+    }
+}
+
+/// Each stat keyed by the name PokeAPI uses for it, along with their total
+pub fn stat_spread_json(stats: &StatSpread) -> serde_json::Value {
+    let mut json = STAT_API_NAMES
+        .iter()
+        .zip(stats)
+        .map(|(stat_name, value)| (stat_name.to_string(), json!(value)))
+        .collect::<Map<_, _>>();
+    json.insert(String::from("total"), json!(stats.iter().sum::<i64>()));
+
+    serde_json::Value::Object(json)
+}
+
+pub fn base_stats(pokemon: &Pokemon) -> StatSpread {
+    let mut base_stats = [0; STAT_COUNT];
+
+    for pokemon_stat in &pokemon.stats {
+        if let Some(index) = STAT_API_NAMES
+            .iter()
+            .position(|name| *name == pokemon_stat.stat.name)
+        {
+            base_stats[index] = pokemon_stat.base_stat;
+        }
+    }
+
+    base_stats
+}
+
+/// How a pokemon is built when working out its stats at a level. Anything left out is shown
+/// as the full range it could take. The nature is the name that was asked for, which is
+/// fetched as a `Nature` before any stats are worked out
+#[derive(Clone, Debug)]
+pub struct StatOptions {
+    pub level: i64,
+    pub ivs: Option<StatSpread>,
+    pub evs: Option<StatSpread>,
+    pub nature: Option<String>,
+}
+
+impl StatOptions {
+    pub fn stat_ranges(
+        &self,
+        base_stats: &StatSpread,
+        nature: Option<&Nature>,
+    ) -> [(i64, i64); STAT_COUNT] {
+        std::array::from_fn(|stat| {
+            let (min_iv, max_iv) = self.ivs.map_or((0, MAX_IV), |ivs| (ivs[stat], ivs[stat]));
+            let (min_ev, max_ev) = self.evs.map_or((0, MAX_EV), |evs| (evs[stat], evs[stat]));
+            let (min_modifier, max_modifier) = match nature {
+                Some(nature) => (nature.modifier(stat), nature.modifier(stat)),
+                None => (90, 110),
+            };
+
+            let stat_with = |iv, ev, modifier| {
+                calculate_stat_with_modifier(stat, base_stats[stat], self.level, iv, ev, modifier)
+            };
+
+            (
+                stat_with(min_iv, min_ev, min_modifier),
+                stat_with(max_iv, max_ev, max_modifier),
+            )
+        })
+    }
+}
+
+/// The in-game value of a stat, using the formula from generation III onwards
+pub fn calculate_stat(
+    stat: usize,
+    base: i64,
+    level: i64,
+    iv: i64,
+    ev: i64,
+    nature: Option<&Nature>,
+) -> i64 {
+    let modifier = nature.map_or(100, |nature| nature.modifier(stat));
+
+    calculate_stat_with_modifier(stat, base, level, iv, ev, modifier)
+}
+
+fn calculate_stat_with_modifier(
+    stat: usize,
+    base: i64,
+    level: i64,
+    iv: i64,
+    ev: i64,
+    modifier: i64,
+) -> i64 {
+    let scaled = (2 * base + iv + ev / 4) * level / 100;
+
+    if stat == HP {
+        // Only Shedinja has a base HP of 1, and it always has exactly 1 HP
+        if base == 1 {
+            return 1;
+        }
+
+        return scaled + level + 10;
+    }
+
+    (scaled + 5) * modifier / 100
+}
+
+pub fn calculate_stats(
+    base_stats: &StatSpread,
+    level: i64,
+    ivs: &StatSpread,
+    evs: &StatSpread,
+    nature: Option<&Nature>,
+) -> StatSpread {
+    std::array::from_fn(|stat| {
+        calculate_stat(stat, base_stats[stat], level, ivs[stat], evs[stat], nature)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rustemon::static_resources;
+
+    fn nature(name: &str, increased: usize, decreased: usize) -> Nature {
+        Nature {
+            name: name.to_owned(),
+            increased: Some(increased),
+            decreased: Some(decreased),
+        }
+    }
+
+    #[test]
+    fn test_calculate_hp() {
+        assert_eq!(calculate_stat(HP, 78, 50, 31, 0, None), 153);
+        assert_eq!(calculate_stat(HP, 78, 100, 31, 252, None), 360);
+        assert_eq!(calculate_stat(HP, 1, 100, 31, 252, None), 1);
+    }
+
+    #[test]
+    fn test_calculate_stat_with_nature() {
+        let modest = nature("modest", SPECIAL_ATTACK, ATTACK);
+        let modest = Some(&modest);
+
+        assert_eq!(
+            calculate_stat(SPECIAL_ATTACK, 109, 50, 31, 252, modest),
+            177
+        );
+        assert_eq!(calculate_stat(ATTACK, 84, 50, 31, 0, modest), 93);
+        assert_eq!(calculate_stat(SPEED, 100, 50, 31, 252, modest), 152);
+        assert_eq!(calculate_stat(SPEED, 100, 100, 0, 0, None), 205);
+    }
+
+    #[test]
+    fn test_stat_ranges() {
+        let charizard = [78, 84, 78, 109, 85, 100];
+        let options = StatOptions {
+            level: 100,
+            ivs: None,
+            evs: None,
+            nature: None,
+        };
+
+        let ranges = options.stat_ranges(&charizard, None);
+        assert_eq!(ranges[HP], (266, 360));
+        assert_eq!(ranges[SPEED], (184, 328));
+
+        let options = StatOptions {
+            ivs: Some([MAX_IV; STAT_COUNT]),
+            ..options
+        };
+        let timid = nature("timid", SPEED, ATTACK);
+
+        let ranges = options.stat_ranges(&charizard, Some(&timid));
+        assert_eq!(ranges[HP], (297, 360));
+        assert_eq!(ranges[ATTACK], (183, 240));
+        assert_eq!(ranges[SPEED], (259, 328));
+    }
+
+    #[test]
+    fn test_nature_from_model() {
+        let bold = Nature::from(&static_resources::get_nature());
+        assert_eq!(bold, nature("bold", DEFENSE, ATTACK));
+
+        let mut hardy = static_resources::get_nature();
+        hardy.name = String::from("hardy");
+        hardy.increased_stat = None;
+        hardy.decreased_stat = None;
+
+        let hardy = Nature::from(&hardy);
+        assert_eq!(hardy.increased, None);
+        assert_eq!(hardy.decreased, None);
+    }
+}
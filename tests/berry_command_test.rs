@@ -0,0 +1,152 @@
+mod utils;
+
+use poke_search::{
+    client::{ClientError, MockClientImplementation},
+    formatter::utils as fmt,
+    run, type_badge,
+};
+use rustemon::{model::items::Item, static_resources};
+use utils::parse_args;
+
+fn cheri_berry_item() -> Item {
+    let mut item = static_resources::get_item();
+    item.name = String::from("cheri-berry");
+    item
+}
+
+fn mock_cheri() -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_berry()
+        .with(mockall::predicate::eq("cheri"))
+        .returning(move |_args| Ok(static_resources::get_berry()));
+
+    mock_client
+}
+
+fn expected_berry() -> String {
+    format!(
+        "{}
+  {}: Cheri
+  {}: Soft
+  {}: Spicy 10
+  {}: {}
+  {}: 60
+  {}: 3 hours per growth stage
+  {}: 5",
+        fmt::white("Berry"),
+        fmt::white("Name"),
+        fmt::white("Firmness"),
+        fmt::white("Flavours"),
+        fmt::white("Natural Gift Type"),
+        type_badge::fetch("fire"),
+        fmt::white("Natural Gift Power"),
+        fmt::white("Growth Time"),
+        fmt::white("Max Harvest"),
+    )
+}
+
+#[tokio::test]
+async fn berry_with_item() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = mock_cheri();
+
+    mock_client
+        .expect_fetch_item()
+        .with(mockall::predicate::eq("cheri-berry"))
+        .once()
+        .returning(move |_args| Ok(cheri_berry_item()));
+
+    let cli = parse_args(vec!["berry", "Cheri Berry"]);
+
+    let expected = format!(
+        "{}
+
+{}
+  {}: Cheri Berry
+  {}: Standard Balls
+  {}: Catches a wild Pokémon every time.",
+        expected_berry(),
+        fmt::white("Item"),
+        fmt::white("Name"),
+        fmt::white("Category"),
+        fmt::white("Effect"),
+    );
+
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn berry_typo_is_corrected() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = mock_cheri();
+
+    mock_client
+        .expect_fetch_item()
+        .returning(move |_args| Ok(cheri_berry_item()));
+
+    let cli = parse_args(vec!["berry", "chery"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert!(actual.starts_with(&expected_berry()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn berry_lists_item_that_failed_to_load() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = mock_cheri();
+
+    mock_client
+        .expect_fetch_item()
+        .with(mockall::predicate::eq("cheri-berry"))
+        .once()
+        .returning(move |_args| Err(ClientError::NotCached(String::from("Item \"cheri-berry\""))));
+
+    let cli = parse_args(vec!["berry", "cheri"]);
+
+    let expected = format!(
+        "{}
+
+{}
+  Item \"cheri-berry\": Item \"cheri-berry\" is not cached. Run again without --offline to fetch it",
+        expected_berry(),
+        fmt::white("1 item failed to load"),
+    );
+
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn berry_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = mock_cheri();
+
+    mock_client
+        .expect_fetch_item()
+        .with(mockall::predicate::eq("cheri-berry"))
+        .once()
+        .returning(move |_args| Ok(cheri_berry_item()));
+
+    let cli = parse_args(vec!["berry", "cheri", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!("cheri", actual["berry"]["name"]);
+    assert_eq!("soft", actual["berry"]["firmness"]);
+    assert_eq!(
+        serde_json::json!({ "flavour": "spicy", "potency": 10 }),
+        actual["berry"]["flavours"][0]
+    );
+    assert_eq!("fire", actual["berry"]["natural_gift_type"]);
+    assert_eq!("cheri-berry", actual["item"]["name"]);
+    assert!(actual.get("failed").is_none());
+
+    Ok(())
+}
@@ -0,0 +1,286 @@
+mod utils;
+
+use poke_search::{
+    client::MockClientImplementation, formatter::utils as fmt, name_matcher::matcher, run,
+};
+use rustemon::static_resources;
+use utils::parse_args;
+
+#[tokio::test]
+async fn breed_pokemon_cant_be_found() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkjfsldfjsdkll";
+
+    let mock_client = MockClientImplementation::new();
+    let cli = parse_args(vec!["breed", incorrect_name, incorrect_name]);
+    let expected = matcher::build_unknown_name("pokemon", incorrect_name);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn breed_shared_egg_group() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut blastoise = static_resources::get_pokemon();
+    blastoise.name = String::from("blastoise");
+    blastoise.species.name = String::from("blastoise");
+
+    let mut blastoise_species = static_resources::get_pokemon_species();
+    blastoise_species.name = String::from("blastoise");
+    blastoise_species.gender_rate = 1;
+    blastoise_species.egg_groups = serde_json::from_value(serde_json::json!([
+        { "name": "monster", "url": "https://pokeapi.co/api/v2/egg-group/1/" },
+        { "name": "water1", "url": "https://pokeapi.co/api/v2/egg-group/2/" },
+    ]))?;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("blastoise"))
+        .once()
+        .returning(move |_args| Ok(blastoise.clone()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("blastoise"))
+        .once()
+        .returning(move |_args| Ok(blastoise_species.clone()));
+
+    let cli = parse_args(vec!["breed", "charizard", "blastoise"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let expected = format!(
+        "{}
+  {}: Monster | Dragon
+  {}: 87.5% Male | 12.5% Female
+
+{}
+  {}: Monster | Water 1
+  {}: 87.5% Male | 12.5% Female
+
+Charizard and Blastoise can breed as they share the Monster egg group",
+        fmt::white("Charizard"),
+        fmt::white("Egg Groups"),
+        fmt::white("Gender Ratio"),
+        fmt::white("Blastoise"),
+        fmt::white("Egg Groups"),
+        fmt::white("Gender Ratio"),
+    );
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn breed_genderless_needs_ditto() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut magnemite = static_resources::get_pokemon();
+    magnemite.name = String::from("magnemite");
+    magnemite.species.name = String::from("magnemite");
+
+    let mut magnemite_species = static_resources::get_pokemon_species();
+    magnemite_species.name = String::from("magnemite");
+    magnemite_species.gender_rate = -1;
+    magnemite_species.egg_groups = serde_json::from_value(serde_json::json!([
+        { "name": "mineral", "url": "https://pokeapi.co/api/v2/egg-group/11/" },
+    ]))?;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("magnemite"))
+        .once()
+        .returning(move |_args| Ok(magnemite.clone()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("magnemite"))
+        .once()
+        .returning(move |_args| Ok(magnemite_species.clone()));
+
+    let cli = parse_args(vec!["breed", "charizard", "magnemite"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let expected = format!(
+        "{}
+  {}: Monster | Dragon
+  {}: 87.5% Male | 12.5% Female
+
+{}
+  {}: Mineral
+  {}: Genderless
+
+Charizard and Magnemite can't breed as Magnemite is genderless and only breeds with Ditto",
+        fmt::white("Charizard"),
+        fmt::white("Egg Groups"),
+        fmt::white("Gender Ratio"),
+        fmt::white("Magnemite"),
+        fmt::white("Egg Groups"),
+        fmt::white("Gender Ratio"),
+    );
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn breed_with_ditto_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut ditto = static_resources::get_pokemon();
+    ditto.name = String::from("ditto");
+    ditto.species.name = String::from("ditto");
+
+    let mut ditto_species = static_resources::get_pokemon_species();
+    ditto_species.name = String::from("ditto");
+    ditto_species.gender_rate = -1;
+    ditto_species.egg_groups = serde_json::from_value(serde_json::json!([
+        { "name": "ditto", "url": "https://pokeapi.co/api/v2/egg-group/13/" },
+    ]))?;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("ditto"))
+        .once()
+        .returning(move |_args| Ok(ditto.clone()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("ditto"))
+        .once()
+        .returning(move |_args| Ok(ditto_species.clone()));
+
+    let cli = parse_args(vec!["breed", "charizard", "ditto", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        serde_json::json!({
+            "can_breed": true,
+            "reason": "ditto",
+            "shared_egg_groups": [],
+            "pokemon": [
+                {
+                    "name": "charizard",
+                    "egg_groups": ["monster", "dragon"],
+                    "gender_ratio": { "male": 87.5, "female": 12.5 },
+                },
+                {
+                    "name": "ditto",
+                    "egg_groups": ["ditto"],
+                    "gender_ratio": null,
+                },
+            ],
+        }),
+        actual
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn breed_undiscovered_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut mewtwo = static_resources::get_pokemon();
+    mewtwo.name = String::from("mewtwo");
+    mewtwo.species.name = String::from("mewtwo");
+
+    let mut mewtwo_species = static_resources::get_pokemon_species();
+    mewtwo_species.name = String::from("mewtwo");
+    mewtwo_species.gender_rate = -1;
+    mewtwo_species.egg_groups = serde_json::from_value(serde_json::json!([
+        { "name": "no-eggs", "url": "https://pokeapi.co/api/v2/egg-group/15/" },
+    ]))?;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("mewtwo"))
+        .once()
+        .returning(move |_args| Ok(mewtwo.clone()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("mewtwo"))
+        .once()
+        .returning(move |_args| Ok(mewtwo_species.clone()));
+
+    let cli = parse_args(vec!["breed", "charizard", "mewtwo", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        serde_json::json!({
+            "can_breed": false,
+            "reason": "undiscovered",
+            "shared_egg_groups": [],
+            "pokemon": [
+                {
+                    "name": "charizard",
+                    "egg_groups": ["monster", "dragon"],
+                    "gender_ratio": { "male": 87.5, "female": 12.5 },
+                },
+                {
+                    "name": "mewtwo",
+                    "egg_groups": ["no-eggs"],
+                    "gender_ratio": null,
+                },
+            ],
+        }),
+        actual
+    );
+
+    Ok(())
+}
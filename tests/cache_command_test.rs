@@ -0,0 +1,148 @@
+use poke_search::{
+    commands::{
+        cache_command::{CacheAction, CacheCommand},
+        cache_warm_command,
+    },
+    output_format::OutputFormat,
+};
+
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const DAY_IN_MILLIS: u128 = 24 * 60 * 60 * 1000;
+
+fn build_cache_dir(name: &str) -> PathBuf {
+    let cache_dir = std::env::temp_dir().join(format!("poke_search_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    cache_dir
+}
+
+fn write_entry(
+    cache_dir: &Path,
+    url: &str,
+    days_old: u128,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+    let mut writer = cacache::WriteOpts::new()
+        .time(now - days_old * DAY_IN_MILLIS)
+        .size(url.len())
+        .open_sync(cache_dir, format!("GET:{url}"))?;
+
+    writer.write_all(url.as_bytes())?;
+    writer.commit()?;
+
+    Ok(())
+}
+
+fn execute_json(cache_dir: &Path, action: CacheAction) -> serde_json::Value {
+    let output =
+        CacheCommand::execute(cache_dir.to_path_buf(), action, OutputFormat::Json).to_string();
+    serde_json::from_str(&output).unwrap()
+}
+
+#[test]
+fn cache_stats_reports_entries_and_oldest_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let cache_dir = build_cache_dir("stats");
+    let old_url = "https://pokeapi.co/api/v2/pokemon/charizard";
+    let new_url = "https://pokeapi.co/api/v2/move/fire-blast";
+
+    write_entry(&cache_dir, old_url, 10)?;
+    write_entry(&cache_dir, new_url, 1)?;
+
+    let actual = execute_json(&cache_dir, CacheAction::Stats);
+
+    assert_eq!(2, actual["cache"]["entries"]);
+    assert_eq!(
+        old_url.len() + new_url.len(),
+        actual["cache"]["size"].as_u64().unwrap() as usize
+    );
+    assert_eq!(old_url, actual["cache"]["oldest_entry"]["url"]);
+
+    std::fs::remove_dir_all(&cache_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn cache_stats_on_missing_cache() {
+    let cache_dir = build_cache_dir("missing");
+
+    let actual = execute_json(&cache_dir, CacheAction::Stats);
+
+    assert_eq!(0, actual["cache"]["entries"]);
+    assert_eq!(serde_json::Value::Null, actual["cache"]["oldest_entry"]);
+}
+
+#[test]
+fn cache_prune_removes_old_entries() -> Result<(), Box<dyn std::error::Error>> {
+    let cache_dir = build_cache_dir("prune");
+    let old_url = "https://pokeapi.co/api/v2/pokemon/charizard";
+    let new_url = "https://pokeapi.co/api/v2/move/fire-blast";
+
+    write_entry(&cache_dir, old_url, 10)?;
+    write_entry(&cache_dir, new_url, 1)?;
+
+    let older_than = Duration::from_secs(5 * 24 * 60 * 60);
+    let actual = execute_json(&cache_dir, CacheAction::Prune { older_than });
+
+    assert_eq!(1, actual["removed_entries"]);
+
+    let remaining = execute_json(&cache_dir, CacheAction::Stats);
+    assert_eq!(1, remaining["cache"]["entries"]);
+    assert_eq!(new_url, remaining["cache"]["oldest_entry"]["url"]);
+
+    std::fs::remove_dir_all(&cache_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn cache_prune_resets_warm_progress() -> Result<(), Box<dyn std::error::Error>> {
+    let cache_dir = build_cache_dir("prune_progress");
+    let progress_path = cache_warm_command::progress_path(&cache_dir);
+
+    write_entry(
+        &cache_dir,
+        "https://pokeapi.co/api/v2/pokemon/charizard",
+        10,
+    )?;
+    std::fs::write(&progress_path, "pokemon:charizard\n")?;
+
+    let older_than = Duration::from_secs(5 * 24 * 60 * 60);
+    let actual = execute_json(&cache_dir, CacheAction::Prune { older_than });
+
+    assert_eq!(1, actual["removed_entries"]);
+    assert!(!progress_path.exists());
+
+    std::fs::remove_dir_all(&cache_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn cache_clear_removes_everything() -> Result<(), Box<dyn std::error::Error>> {
+    let cache_dir = build_cache_dir("clear");
+
+    write_entry(
+        &cache_dir,
+        "https://pokeapi.co/api/v2/pokemon/charizard",
+        10,
+    )?;
+    write_entry(&cache_dir, "https://pokeapi.co/api/v2/move/fire-blast", 1)?;
+
+    let actual = CacheCommand::execute(cache_dir.clone(), CacheAction::Clear, OutputFormat::Text)
+        .to_string();
+
+    assert!(actual.starts_with("Removed 2 entries"), "{actual}");
+
+    let remaining = execute_json(&cache_dir, CacheAction::Stats);
+    assert_eq!(0, remaining["cache"]["entries"]);
+
+    std::fs::remove_dir_all(&cache_dir)?;
+
+    Ok(())
+}
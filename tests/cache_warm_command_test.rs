@@ -0,0 +1,200 @@
+use poke_search::{
+    client::{ClientError, MockClientImplementation},
+    commands::{
+        cache_command::{CacheAction, CacheCommand},
+        cache_warm_command::{self, CacheWarmCommand},
+    },
+    output_format::OutputFormat,
+};
+use rustemon::static_resources;
+
+use std::path::{Path, PathBuf};
+
+fn build_cache_dir(name: &str) -> PathBuf {
+    let cache_dir = std::env::temp_dir().join(format!("poke_search_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    cache_dir
+}
+
+fn build_mock_client(failing_item: Option<&'static str>) -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+
+    let ability = static_resources::get_ability();
+    let berry = static_resources::get_berry();
+    let egg_group = static_resources::get_egg_group();
+    let generation = static_resources::get_generation();
+    let item = static_resources::get_item();
+    let move_ = static_resources::get_move();
+    let move_learn_method = static_resources::get_move_learn_method();
+    let nature = static_resources::get_nature();
+    let pokemon = static_resources::get_pokemon();
+    let species = static_resources::get_pokemon_species();
+    let type_ = static_resources::get_type();
+    let evolution_chain = static_resources::get_evolution_chain();
+
+    mock_client
+        .expect_fetch_ability()
+        .returning(move |_| Ok(ability.clone()));
+    mock_client
+        .expect_fetch_berry()
+        .returning(move |_| Ok(berry.clone()));
+    mock_client
+        .expect_fetch_egg_group()
+        .returning(move |_| Ok(egg_group.clone()));
+    mock_client
+        .expect_fetch_pokemon_encounters()
+        .returning(move |_| Ok(Vec::new()));
+    mock_client
+        .expect_fetch_generation()
+        .returning(move |_| Ok(generation.clone()));
+    mock_client
+        .expect_fetch_item()
+        .returning(move |item_name| match failing_item {
+            Some(failing_item) if failing_item == item_name => {
+                Err(ClientError::NotCached(format!("Item \"{item_name}\"")))
+            }
+            _ => Ok(item.clone()),
+        });
+    mock_client
+        .expect_fetch_move()
+        .returning(move |_| Ok(move_.clone()));
+    mock_client
+        .expect_fetch_move_learn_method()
+        .returning(move |_| Ok(move_learn_method.clone()));
+    mock_client
+        .expect_fetch_nature()
+        .returning(move |_| Ok(nature.clone()));
+    mock_client
+        .expect_fetch_pokemon()
+        .returning(move |_| Ok(pokemon.clone()));
+    mock_client
+        .expect_fetch_pokemon_species()
+        .returning(move |_| Ok(species.clone()));
+    mock_client
+        .expect_fetch_type()
+        .returning(move |_| Ok(type_.clone()));
+    mock_client
+        .expect_fetch_evolution_chain_from_url()
+        .returning(move |_| Ok(evolution_chain.clone()));
+
+    mock_client
+}
+
+async fn execute_json(
+    mock_client: &MockClientImplementation,
+    cache_dir: &Path,
+) -> serde_json::Value {
+    let output = CacheWarmCommand::execute(
+        mock_client,
+        cache_dir.to_path_buf(),
+        8,
+        false,
+        OutputFormat::Json,
+    )
+    .await
+    .to_string();
+
+    serde_json::from_str(&output).unwrap()
+}
+
+#[tokio::test]
+async fn cache_warm_fetches_everything() -> Result<(), Box<dyn std::error::Error>> {
+    let cache_dir = build_cache_dir("warm");
+    let mock_client = build_mock_client(None);
+
+    let actual = execute_json(&mock_client, &cache_dir).await;
+
+    assert!(actual["fetched"].as_u64().unwrap() > 0);
+    assert_eq!(0, actual["skipped"]);
+    assert_eq!(serde_json::json!([]), actual["failures"]);
+    assert!(!cache_warm_command::progress_path(&cache_dir).exists());
+
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cache_warm_resumes_after_failures() -> Result<(), Box<dyn std::error::Error>> {
+    let cache_dir = build_cache_dir("warm_resume");
+
+    let failing_client = build_mock_client(Some("master-ball"));
+    let first_run = execute_json(&failing_client, &cache_dir).await;
+
+    assert_eq!(
+        serde_json::json!([{
+            "resource": "item",
+            "name": "master-ball",
+            "error": "Item \"master-ball\" is not cached. Run again without --offline to fetch it",
+        }]),
+        first_run["failures"]
+    );
+    assert!(cache_warm_command::progress_path(&cache_dir).exists());
+
+    let mock_client = build_mock_client(None);
+    let second_run = execute_json(&mock_client, &cache_dir).await;
+
+    assert_eq!(1, second_run["fetched"]);
+    assert_eq!(first_run["fetched"], second_run["skipped"]);
+    assert_eq!(serde_json::json!([]), second_run["failures"]);
+    assert!(!cache_warm_command::progress_path(&cache_dir).exists());
+
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cache_clear_after_interrupted_warm() -> Result<(), Box<dyn std::error::Error>> {
+    let cache_dir = build_cache_dir("warm_clear");
+
+    let failing_client = build_mock_client(Some("master-ball"));
+    execute_json(&failing_client, &cache_dir).await;
+
+    assert!(cache_warm_command::progress_path(&cache_dir).exists());
+
+    let output = CacheCommand::execute(cache_dir.clone(), CacheAction::Clear, OutputFormat::Json)
+        .to_string();
+    let actual: serde_json::Value = serde_json::from_str(&output)?;
+
+    assert_eq!(
+        serde_json::json!({
+            "removed_entries": 0,
+            "removed_size": 0,
+        }),
+        actual
+    );
+    assert!(!cache_warm_command::progress_path(&cache_dir).exists());
+
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cache_warm_lists_failures() -> Result<(), Box<dyn std::error::Error>> {
+    let cache_dir = build_cache_dir("warm_text");
+
+    let failing_client = build_mock_client(Some("master-ball"));
+    let actual = CacheWarmCommand::execute(
+        &failing_client,
+        cache_dir.clone(),
+        8,
+        false,
+        OutputFormat::Text,
+    )
+    .await
+    .to_string();
+
+    assert!(actual.ends_with(
+        "1 item failed to load
+  Item \"master-ball\": Item \"master-ball\" is not cached. Run again without --offline to fetch it
+
+Run `cache warm` again to retry the failures"
+    ));
+
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    Ok(())
+}
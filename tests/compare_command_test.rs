@@ -0,0 +1,219 @@
+mod utils;
+
+use poke_search::{
+    client::MockClientImplementation, formatter::utils as fmt, name_matcher::matcher, run,
+    type_badge,
+};
+use rustemon::static_resources;
+use utils::parse_args;
+
+#[tokio::test]
+async fn compare_pokemon_cant_be_found() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkjfsldfjsdkll";
+
+    let mock_client = MockClientImplementation::new();
+    let cli = parse_args(vec!["compare", incorrect_name, incorrect_name]);
+    let expected = matcher::build_unknown_name("pokemon", incorrect_name);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn compare_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut blastoise = static_resources::get_pokemon();
+    blastoise.name = String::from("blastoise");
+    blastoise.stats[5].base_stat = 120;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("blastoise"))
+        .once()
+        .returning(move |_args| Ok(blastoise.clone()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .times(2)
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    let cli = parse_args(vec![
+        "compare",
+        "charizard",
+        "blastoise",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!("charizard", actual["pokemon"][0]["name"]);
+    assert_eq!("blastoise", actual["pokemon"][1]["name"]);
+    assert_eq!(
+        serde_json::json!({
+            "hp": 78,
+            "attack": 84,
+            "defense": 78,
+            "special-attack": 109,
+            "special-defense": 85,
+            "speed": 100,
+            "total": 534,
+        }),
+        actual["pokemon"][0]["stats"]
+    );
+    assert_eq!(
+        serde_json::json!({
+            "hp": 78,
+            "attack": 84,
+            "defense": 78,
+            "special-attack": 109,
+            "special-defense": 85,
+            "speed": 120,
+            "total": 554,
+        }),
+        actual["pokemon"][1]["stats"]
+    );
+    assert_eq!(
+        serde_json::json!({
+            "hp": ["charizard", "blastoise"],
+            "attack": ["charizard", "blastoise"],
+            "defense": ["charizard", "blastoise"],
+            "special-attack": ["charizard", "blastoise"],
+            "special-defense": ["charizard", "blastoise"],
+            "speed": ["blastoise"],
+            "total": ["blastoise"],
+        }),
+        actual["highest"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn compare_lines_up_each_row() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut blastoise = static_resources::get_pokemon();
+    blastoise.name = String::from("blastoise");
+    blastoise.stats[5].base_stat = 120;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("blastoise"))
+        .once()
+        .returning(move |_args| Ok(blastoise.clone()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .times(2)
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    let cli = parse_args(vec!["compare", "charizard", "blastoise"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let types = format!(
+        "{} {}",
+        type_badge::fetch("fire"),
+        type_badge::fetch("flying")
+    );
+
+    let expected = format!(
+        "{}
+                  | {}
+  {}            | {types}      | {types}
+  {}       | Blaze, Solar Power | Blaze, Solar Power
+  {}      | I                  | I
+  {}   | SpA +3             | SpA +3
+  {}              | 78                 | 78
+  {}          | 84                 | 84
+  {}         | 78                 | 78
+  {}  | 109                | 109
+  {} | 85                 | 85
+  {}           | 100                | {}
+  {}           | 534                | {}",
+        fmt::white("Compare"),
+        fmt::white("Charizard          | Blastoise"),
+        fmt::white("Type"),
+        fmt::white("Abilities"),
+        fmt::white("Generation"),
+        fmt::white("Effort Values"),
+        fmt::white("HP"),
+        fmt::white("Attack"),
+        fmt::white("Defense"),
+        fmt::white("Special Attack"),
+        fmt::white("Special Defense"),
+        fmt::white("Speed"),
+        fmt::highlight(&fmt::green("120")),
+        fmt::white("Total"),
+        fmt::highlight(&fmt::green("554")),
+    );
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+fn compare_requires_two_pokemon() {
+    let args = ["poke_search", "compare", "charizard"];
+
+    assert!(<poke_search::Cli as clap::Parser>::try_parse_from(args).is_err());
+}
+
+#[tokio::test]
+async fn compare_in_game_form_names() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut raichu = static_resources::get_pokemon();
+    raichu.name = String::from("raichu-alola");
+
+    let mut mr_mime = static_resources::get_pokemon();
+    mr_mime.name = String::from("mr-mime");
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("raichu-alola"))
+        .once()
+        .returning(move |_args| Ok(raichu.clone()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("mr-mime"))
+        .once()
+        .returning(move |_args| Ok(mr_mime.clone()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .times(2)
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    let cli = parse_args(vec![
+        "compare",
+        "Alolan Raichu",
+        "Mr. Mime",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!("raichu-alola", actual["pokemon"][0]["name"]);
+    assert_eq!("mr-mime", actual["pokemon"][1]["name"]);
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use poke_search::completion::complete;
+
+#[test]
+fn complete_subcommand() {
+    assert_eq!(vec!["pokemon"], complete(&["pok"]));
+}
+
+#[test]
+fn complete_positional_name() {
+    assert_eq!(
+        vec![
+            "charizard",
+            "charizard-gmax",
+            "charizard-mega-x",
+            "charizard-mega-y"
+        ],
+        complete(&["pokemon", "chariz"])
+    );
+    assert_eq!(vec!["master-ball"], complete(&["item", "master-"]));
+}
+
+#[test]
+fn complete_option_value() {
+    assert_eq!(vec!["water"], complete(&["type", "fire", "-s", "wat"]));
+    assert_eq!(vec!["json"], complete(&["--format", "js"]));
+}
+
+#[test]
+fn complete_multiple_option_values() {
+    assert_eq!(
+        vec!["water"],
+        complete(&["moves", "pikachu", "-t", "fire", "wat"])
+    );
+    assert_eq!(
+        vec!["special", "status"],
+        complete(&["moves", "pikachu", "--categories", "s"])
+    );
+}
+
+#[test]
+fn complete_flag() {
+    assert_eq!(
+        vec!["--verbose"],
+        complete(&["item", "master-ball", "--verb"])
+    );
+}
+
+#[test]
+fn complete_nothing_after_unknown_positional() {
+    assert!(complete(&["item", "master-ball", ""]).is_empty());
+}
@@ -0,0 +1,30 @@
+mod utils;
+
+use poke_search::{client::MockClientImplementation, run};
+use utils::parse_args;
+
+#[tokio::test]
+async fn completions_script_calls_complete() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClientImplementation::new();
+
+    for shell in ["bash", "zsh", "fish"] {
+        let cli = parse_args(vec!["completions", shell]);
+        let actual = run(&mock_client, cli).await.to_string();
+
+        assert!(actual.contains("poke_search __complete --"), "{actual}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn complete_type_option_values() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClientImplementation::new();
+    let cli = parse_args(vec!["__complete", "--", "move", "surf", "-l", "-t", "gr"]);
+
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!("grass\nground", actual);
+
+    Ok(())
+}
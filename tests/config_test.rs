@@ -0,0 +1,60 @@
+mod utils;
+
+use poke_search::{Client, ClientOptions, Config};
+use utils::parse_args;
+
+#[test]
+fn config_with_api_url() {
+    let actual = Config::parse(r#"{ "api_url": "http://localhost:8000/api/v2" }"#);
+
+    assert_eq!(
+        Ok(Config {
+            api_url: Some(String::from("http://localhost:8000/api/v2"))
+        }),
+        actual
+    );
+}
+
+#[test]
+fn config_without_api_url() {
+    assert_eq!(Ok(Config::default()), Config::parse("{}"));
+}
+
+#[test]
+fn config_with_invalid_api_url() {
+    assert_eq!(
+        Err(String::from("\"api_url\" must be a string")),
+        Config::parse(r#"{ "api_url": 8000 }"#)
+    );
+}
+
+#[test]
+fn api_url_flag_takes_precedence_over_config() {
+    let config = Config {
+        api_url: Some(String::from("http://config.example/api/v2")),
+    };
+
+    let cli = parse_args(vec![
+        "--api-url",
+        "http://flag.example/api/v2",
+        "item",
+        "master-ball",
+    ]);
+    assert_eq!(
+        Some(String::from("http://flag.example/api/v2")),
+        cli.client_options(config.clone()).api_url
+    );
+
+    let cli = parse_args(vec!["item", "master-ball"]);
+    assert_eq!(config.api_url, cli.client_options(config.clone()).api_url);
+}
+
+#[test]
+fn client_rejects_invalid_api_url() {
+    let options = ClientOptions {
+        api_url: Some(String::from("not a url")),
+        ..Default::default()
+    };
+
+    assert!(Client::try_build(options).is_err());
+}
@@ -0,0 +1,110 @@
+mod utils;
+
+use poke_search::{client::MockClientImplementation, name_matcher::matcher, run};
+use rustemon::static_resources;
+use utils::parse_args;
+
+fn mock_fire_blast_coverage() -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mock_pokemon = static_resources::get_pokemon();
+    let mock_move = static_resources::get_move();
+    let mock_type = static_resources::get_type();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(mock_pokemon.clone()));
+
+    mock_client
+        .expect_fetch_move()
+        .with(mockall::predicate::eq("fire-blast"))
+        .once()
+        .returning(move |_args| Ok(mock_move.clone()));
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("fire"))
+        .once()
+        .returning(move |_args| Ok(mock_type.clone()));
+
+    mock_client
+}
+
+#[tokio::test]
+async fn coverage_move_cant_be_found() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkjfsldfjsdkll";
+
+    let mut mock_client = MockClientImplementation::new();
+    let mock_pokemon = static_resources::get_pokemon();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .returning(move |_args| Ok(mock_pokemon.clone()));
+
+    let cli = parse_args(vec!["coverage", "charizard", incorrect_name]);
+    let expected = matcher::build_unknown_name("move", incorrect_name);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn coverage_best_multipliers_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_fire_blast_coverage();
+
+    let cli = parse_args(vec![
+        "coverage",
+        "charizard",
+        "fire-blast",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        serde_json::json!([
+            { "name": "fire-blast", "type": "fire", "damage_class": "special", "stab": true },
+        ]),
+        actual["moves"]
+    );
+
+    assert_eq!(serde_json::json!("2x"), actual["single_types"]["grass"]);
+    assert_eq!(serde_json::json!("1x"), actual["single_types"]["normal"]);
+    assert_eq!(serde_json::json!("0.5x"), actual["single_types"]["water"]);
+    assert!(actual["single_types"].get("stellar").is_none());
+
+    assert_eq!(serde_json::json!("4x"), actual["dual_types"]["bug/grass"]);
+    assert_eq!(serde_json::json!("1x"), actual["dual_types"]["grass/water"]);
+    assert_eq!(
+        serde_json::json!("0.25x"),
+        actual["dual_types"]["fire/water"]
+    );
+    assert_eq!(153, actual["dual_types"].as_object().unwrap().len());
+
+    let walled = actual["walled"].as_array().unwrap();
+    assert!(walled.contains(&serde_json::json!("dragon")));
+    assert!(walled.contains(&serde_json::json!("dragon/water")));
+    assert!(!walled.contains(&serde_json::json!("grass")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn coverage_lists_walled_types() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_fire_blast_coverage();
+
+    let cli = parse_args(vec!["coverage", "charizard", "fire-blast"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert!(actual.contains("Single Types"));
+    assert!(actual.contains("Dual Types"));
+    assert!(actual.contains("Walled"));
+    assert!(actual.contains("STAB"));
+
+    Ok(())
+}
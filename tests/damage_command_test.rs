@@ -0,0 +1,148 @@
+mod utils;
+
+use poke_search::{client::MockClientImplementation, name_matcher::matcher, run};
+use rustemon::static_resources;
+use utils::parse_args;
+
+fn mock_fire_blast() -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mock_pokemon = static_resources::get_pokemon();
+    let mock_move = static_resources::get_move();
+    let mock_type = static_resources::get_type();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .times(2)
+        .returning(move |_args| Ok(mock_pokemon.clone()));
+
+    mock_client
+        .expect_fetch_move()
+        .with(mockall::predicate::eq("fire-blast"))
+        .once()
+        .returning(move |_args| Ok(mock_move.clone()));
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("fire"))
+        .once()
+        .returning(move |_args| Ok(mock_type.clone()));
+
+    mock_client
+}
+
+#[tokio::test]
+async fn damage_move_cant_be_found() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkjfsldfjsdkll";
+
+    let mut mock_client = MockClientImplementation::new();
+    let mock_pokemon = static_resources::get_pokemon();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .returning(move |_args| Ok(mock_pokemon.clone()));
+
+    let cli = parse_args(vec!["damage", "charizard", "charizard", incorrect_name]);
+    let expected = matcher::build_unknown_name("move", incorrect_name);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn damage_range_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_fire_blast();
+
+    let cli = parse_args(vec![
+        "damage",
+        "charizard",
+        "charizard",
+        "fire-blast",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(serde_json::json!(129), actual["attacker"]["special-attack"]);
+    assert_eq!(serde_json::json!(153), actual["defender"]["hp"]);
+    assert_eq!(
+        serde_json::json!(105),
+        actual["defender"]["special-defense"]
+    );
+    assert_eq!(serde_json::json!(true), actual["move"]["stab"]);
+    assert_eq!(serde_json::json!("0.5x"), actual["move"]["effectiveness"]);
+
+    assert_eq!(serde_json::json!(38), actual["damage"]["normal"]["min"]);
+    assert_eq!(serde_json::json!(45), actual["damage"]["normal"]["max"]);
+    assert_eq!(
+        16,
+        actual["damage"]["normal"]["rolls"]
+            .as_array()
+            .unwrap()
+            .len()
+    );
+    assert_eq!(serde_json::json!(57), actual["damage"]["critical"]["min"]);
+    assert_eq!(serde_json::json!(68), actual["damage"]["critical"]["max"]);
+    assert_eq!(
+        serde_json::json!("Guaranteed 3HKO"),
+        actual["damage"]["critical"]["knockout"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn damage_uses_evs_and_nature() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = mock_fire_blast();
+
+    let mut modest = static_resources::get_nature();
+    modest.name = String::from("modest");
+    modest.increased_stat = serde_json::from_value(serde_json::json!({
+        "name": "special-attack",
+        "url": "https://pokeapi.co/api/v2/stat/4/",
+    }))?;
+
+    mock_client
+        .expect_fetch_nature()
+        .with(mockall::predicate::eq("modest"))
+        .once()
+        .returning(move |_args| Ok(modest.clone()));
+
+    let cli = parse_args(vec![
+        "damage",
+        "charizard",
+        "charizard",
+        "fire-blast",
+        "--attacker-evs",
+        "0/0/0/252/4/252",
+        "--attacker-nature",
+        "Modst",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(serde_json::json!(177), actual["attacker"]["special-attack"]);
+
+    Ok(())
+}
+
+#[test]
+fn damage_rejects_too_many_evs() {
+    let args = [
+        "poke_search",
+        "damage",
+        "charizard",
+        "venusaur",
+        "fire-blast",
+        "--attacker-evs",
+        "252/252/252/0/0/0",
+    ];
+
+    assert!(<poke_search::Cli as clap::Parser>::try_parse_from(args).is_err());
+}
@@ -0,0 +1,109 @@
+mod utils;
+
+use poke_search::{
+    client::MockClientImplementation, formatter::utils as fmt, name_matcher::matcher, run,
+};
+use rustemon::static_resources;
+use utils::parse_args;
+
+#[tokio::test]
+async fn egg_group_cant_be_found() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkjfsldfjsdkll";
+
+    let mock_client = MockClientImplementation::new();
+    let cli = parse_args(vec!["egg-group", incorrect_name]);
+    let expected = matcher::build_unknown_name("egg group", incorrect_name);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn egg_group_lists_species() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_egg_group()
+        .with(mockall::predicate::eq("dragon"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_egg_group()));
+
+    let cli = parse_args(vec!["egg-group", "Dragon"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let expected_start = format!(
+        "{} Dragon
+
+{}
+  Charmander  ",
+        fmt::white("Egg Group:"),
+        fmt::white("Pokemon (72)"),
+    );
+
+    assert!(actual.starts_with(&expected_start));
+    assert!(actual.contains("Charizard"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn egg_group_accepts_in_game_names() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_egg_group()
+        .with(mockall::predicate::eq("humanshape"))
+        .once()
+        .returning(move |_args| {
+            let mut egg_group = static_resources::get_egg_group();
+            egg_group.name = String::from("humanshape");
+            Ok(egg_group)
+        });
+
+    let cli = parse_args(vec!["egg-group", "human-like", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!("humanshape", actual["egg_group"]);
+    assert_eq!("charmander", actual["pokemon_species"][0]);
+    assert_eq!(72, actual["pokemon_species"].as_array().unwrap().len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn egg_group_lists_species_in_pokedex_order() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut egg_group = static_resources::get_egg_group();
+    egg_group.name = String::from("water1");
+    egg_group.pokemon_species = serde_json::from_value(serde_json::json!([
+        { "name": "psyduck", "url": "https://pokeapi.co/api/v2/pokemon-species/54/" },
+        { "name": "wartortle", "url": "https://pokeapi.co/api/v2/pokemon-species/8/" },
+        { "name": "squirtle", "url": "https://pokeapi.co/api/v2/pokemon-species/7/" },
+    ]))?;
+
+    mock_client
+        .expect_fetch_egg_group()
+        .with(mockall::predicate::eq("water1"))
+        .once()
+        .returning(move |_args| Ok(egg_group.clone()));
+
+    let cli = parse_args(vec!["egg-group", "Water 1"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let expected = format!(
+        "{} Water 1
+
+{}
+  Squirtle     Wartortle    Psyduck",
+        fmt::white("Egg Group:"),
+        fmt::white("Pokemon (3)"),
+    );
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
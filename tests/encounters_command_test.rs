@@ -0,0 +1,174 @@
+mod utils;
+
+use poke_search::{
+    client::MockClientImplementation, formatter::utils as fmt, name_matcher::matcher, run,
+};
+use rustemon::model::pokemon::LocationAreaEncounter;
+use utils::parse_args;
+
+fn encounter(
+    method: &str,
+    min_level: i64,
+    max_level: i64,
+    chance: i64,
+    conditions: &[&str],
+) -> serde_json::Value {
+    let condition_values = conditions
+        .iter()
+        .map(|condition| {
+            serde_json::json!({
+                "name": condition,
+                "url": "https://pokeapi.co/api/v2/encounter-condition-value/1/",
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "min_level": min_level,
+        "max_level": max_level,
+        "condition_values": condition_values,
+        "chance": chance,
+        "method": { "name": method, "url": "https://pokeapi.co/api/v2/encounter-method/1/" },
+    })
+}
+
+fn version_detail(version: &str, id: i64, encounters: Vec<serde_json::Value>) -> serde_json::Value {
+    serde_json::json!({
+        "version": { "name": version, "url": format!("https://pokeapi.co/api/v2/version/{id}/") },
+        "max_chance": 100,
+        "encounter_details": encounters,
+    })
+}
+
+fn pikachu_encounters() -> Vec<LocationAreaEncounter> {
+    let encounters = serde_json::json!([
+        {
+            "location_area": {
+                "name": "viridian-forest-area",
+                "url": "https://pokeapi.co/api/v2/location-area/321/",
+            },
+            "version_details": [
+                version_detail("yellow", 3, vec![
+                    encounter("walk", 3, 3, 5, &[]),
+                    encounter("walk", 5, 5, 5, &[]),
+                ]),
+                version_detail("red", 1, vec![encounter("walk", 3, 5, 5, &[])]),
+            ],
+        },
+        {
+            "location_area": {
+                "name": "power-plant-area",
+                "url": "https://pokeapi.co/api/v2/location-area/330/",
+            },
+            "version_details": [
+                version_detail("red", 1, vec![
+                    encounter("walk", 20, 22, 15, &[]),
+                    encounter("walk", 24, 24, 10, &[]),
+                    encounter("walk", 24, 24, 5, &["time-night"]),
+                ]),
+            ],
+        },
+    ]);
+
+    serde_json::from_value(encounters).unwrap()
+}
+
+fn mock_pikachu() -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon_encounters()
+        .with(mockall::predicate::eq("pikachu"))
+        .returning(move |_args| Ok(pikachu_encounters()));
+
+    mock_client
+}
+
+#[tokio::test]
+async fn encounters_cant_be_found() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkfdjslsdkjfkls";
+
+    let mock_client = MockClientImplementation::new();
+    let cli = parse_args(vec!["encounters", incorrect_name]);
+    let expected = matcher::build_unknown_name("pokemon", incorrect_name);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn encounters_grouped_by_version() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_pikachu();
+
+    let cli = parse_args(vec!["encounters", "pikachu"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let expected = format!(
+        "{} Pikachu
+
+{}
+  Viridian Forest Area  Walk  Lv. 3-5     5%
+  Power Plant Area      Walk  Lv. 20-24  25%
+  Power Plant Area      Walk  Lv. 24      5%  Time Night
+
+{}
+  Viridian Forest Area  Walk  Lv. 3-5    10%",
+        fmt::white("Pokemon:"),
+        fmt::white("Red (3)"),
+        fmt::white("Yellow (1)"),
+    );
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn encounters_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_pikachu();
+
+    let cli = parse_args(vec!["encounters", "pikachu", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!("pikachu", actual["pokemon"]);
+    assert_eq!("red", actual["versions"][0]["version"]);
+    assert_eq!(
+        serde_json::json!({
+            "location_area": "power-plant-area",
+            "method": "walk",
+            "min_level": 20,
+            "max_level": 24,
+            "chance": 25,
+            "conditions": [],
+        }),
+        actual["versions"][0]["encounters"][1]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn encounters_not_in_the_wild() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon_encounters()
+        .with(mockall::predicate::eq("mew"))
+        .returning(move |_args| Ok(Vec::new()));
+
+    let cli = parse_args(vec!["encounters", "mew"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(
+        format!(
+            "{} Mew\nMew can't be found in the wild",
+            fmt::white("Pokemon:")
+        ),
+        actual
+    );
+
+    Ok(())
+}
@@ -1,6 +1,10 @@
 mod utils;
 
-use poke_search::{client::MockClientImplementation, formatter::utils as fmt, run};
+use poke_search::{
+    client::{ClientError, MockClientImplementation},
+    formatter::utils as fmt,
+    run,
+};
 use rustemon::static_resources;
 use utils::parse_args;
 
@@ -65,3 +69,68 @@ async fn item_verbose_description() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn item_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_item()
+        .with(mockall::predicate::eq("master-ball"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_item()));
+
+    let cli = parse_args(vec!["item", "master-ball", "--format", "json"]);
+
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+    let expected = serde_json::json!({
+        "item": {
+            "name": "master-ball",
+            "category": "standard-balls",
+            "effect": "Catches a wild Pokémon every time.",
+        }
+    });
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn item_json_error() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkfdjslsdkjfkls";
+
+    let mock_client = MockClientImplementation::new();
+    let cli = parse_args(vec!["--format", "json", "item", incorrect_name]);
+
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+    let expected = serde_json::json!({
+        "error": poke_search::matcher::build_unknown_name("item", incorrect_name),
+    });
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn item_not_cached_when_offline() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_item()
+        .with(mockall::predicate::eq("master-ball"))
+        .once()
+        .returning(move |_args| Err(ClientError::NotCached(String::from("Item \"master-ball\""))));
+
+    let cli = parse_args(vec!["--offline", "item", "master-ball"]);
+
+    let expected = "Item \"master-ball\" is not cached. Run again without --offline to fetch it";
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+mod utils;
+
+use poke_search::{
+    LocalClient,
+    client::{ClientError, ClientImplementation},
+    formatter::utils as fmt,
+    run,
+};
+use utils::parse_args;
+
+use std::path::PathBuf;
+
+fn build_client() -> Result<LocalClient, ClientError> {
+    let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/pokeapi_csv");
+    LocalClient::try_build(data_dir)
+}
+
+#[tokio::test]
+async fn local_item() -> Result<(), Box<dyn std::error::Error>> {
+    let client = build_client()?;
+    let cli = parse_args(vec!["item", "master-ball"]);
+
+    let expected = format!(
+        "{}
+  {}: Master Ball
+  {}: Standard Balls
+  {}: Catches a wild Pokémon every time.",
+        fmt::white("Item"),
+        fmt::white("Name"),
+        fmt::white("Category"),
+        fmt::white("Effect"),
+    );
+
+    let actual = run(&client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn local_type_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let client = build_client()?;
+    let cli = parse_args(vec!["type", "fire", "--format", "json", "--pokemon"]);
+
+    let actual: serde_json::Value = serde_json::from_str(&run(&client, cli).await.to_string())?;
+
+    assert_eq!(
+        actual["offence"]["fire"]["2x"],
+        serde_json::json!(["grass"])
+    );
+    assert_eq!(
+        actual["offence"]["fire"]["0.5x"],
+        serde_json::json!(["fire", "water"])
+    );
+    assert_eq!(actual["defence"]["2x"], serde_json::json!(["water"]));
+    assert_eq!(
+        actual["pokemon"],
+        serde_json::json!(["charizard", "charmander"])
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn local_nature() -> Result<(), Box<dyn std::error::Error>> {
+    let client = build_client()?;
+
+    let bold = client.fetch_nature("bold").await?;
+    assert_eq!("defense", bold.increased_stat.unwrap().name);
+    assert_eq!("attack", bold.decreased_stat.unwrap().name);
+    assert_eq!("sour", bold.likes_flavor.unwrap().name);
+    assert_eq!("spicy", bold.hates_flavor.unwrap().name);
+
+    let hardy = client.fetch_nature("hardy").await?;
+    assert!(hardy.increased_stat.is_none());
+    assert!(hardy.likes_flavor.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn local_pokemon_encounters() -> Result<(), Box<dyn std::error::Error>> {
+    let client = build_client()?;
+
+    let encounters = client.fetch_pokemon_encounters("charmander").await?;
+
+    assert_eq!(1, encounters.len());
+    assert_eq!("kanto-route-24-area", encounters[0].location_area.name);
+
+    let version_detail = &encounters[0].version_details[0];
+    assert_eq!("yellow", version_detail.version.name);
+    assert_eq!(100, version_detail.max_chance);
+    assert_eq!("gift", version_detail.encounter_details[0].method.name);
+    assert_eq!(10, version_detail.encounter_details[0].min_level);
+
+    assert!(
+        client
+            .fetch_pokemon_encounters("charizard")
+            .await?
+            .is_empty()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn local_egg_group() -> Result<(), Box<dyn std::error::Error>> {
+    let client = build_client()?;
+
+    let egg_group = client.fetch_egg_group("dragon").await?;
+
+    assert_eq!("dragon", egg_group.name);
+    assert_eq!("Dragon", egg_group.names[0].name);
+    assert_eq!(
+        vec!["charmander", "charizard"],
+        egg_group
+            .pokemon_species
+            .iter()
+            .map(|species| species.name.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn local_missing_resource() -> Result<(), Box<dyn std::error::Error>> {
+    let client = build_client()?;
+
+    let actual = client.fetch_item("ultra-ball").await;
+
+    assert!(
+        matches!(&actual, Err(ClientError::NotFound(name)) if name == "Item \"ultra-ball\""),
+        "{actual:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn local_client_requires_csv_directory() {
+    let actual = LocalClient::try_build(PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+
+    assert!(matches!(actual, Err(ClientError::LocalData(_))));
+}
+
+#[tokio::test]
+async fn local_berry() -> Result<(), Box<dyn std::error::Error>> {
+    let client = build_client()?;
+
+    let berry = client.fetch_berry("cheri").await?;
+
+    assert_eq!("cheri", berry.name);
+    assert_eq!("soft", berry.firmness.name);
+    assert_eq!("cheri-berry", berry.item.name);
+    assert_eq!("fire", berry.natural_gift_type.name);
+    assert_eq!(60, berry.natural_gift_power);
+    assert_eq!("spicy", berry.flavors[0].flavor.name);
+    assert_eq!(10, berry.flavors[0].potency);
+
+    Ok(())
+}
@@ -141,3 +141,59 @@ async fn move_learned_by_types_are_aligned_with_names() -> Result<(), Box<dyn st
 
     Ok(())
 }
+
+#[tokio::test]
+async fn move_learned_by_filters_by_version_group() -> Result<(), Box<dyn std::error::Error>> {
+    let pokemon_count = static_resources::get_move().learned_by_pokemon.len();
+
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_move()
+        .returning(move |_| Ok(static_resources::get_move()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .returning(move |_| Ok(static_resources::get_pokemon()));
+
+    let cli = parse_args(vec![
+        "move",
+        "fire-blast",
+        "--learned-by",
+        "--game",
+        "red-blue",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        Some(pokemon_count),
+        actual["learned_by"].as_array().map(Vec::len)
+    );
+
+    // Charizard isn't in Legends: Arceus so can't learn anything there
+    let cli = parse_args(vec![
+        "move",
+        "fire-blast",
+        "--learned-by",
+        "--version-group",
+        "legends-arceus",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(serde_json::json!([]), actual["learned_by"]);
+
+    Ok(())
+}
+
+#[test]
+fn move_version_group_requires_learned_by() {
+    let args = ["poke_search", "move", "fire-blast", "--game", "red-blue"];
+
+    assert!(<poke_search::Cli as clap::Parser>::try_parse_from(args).is_err());
+}
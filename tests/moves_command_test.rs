@@ -0,0 +1,572 @@
+mod utils;
+
+use poke_search::{
+    client::{ClientError, MockClientImplementation},
+    name_matcher::matcher,
+    run,
+};
+use rustemon::static_resources;
+use utils::parse_args;
+
+fn mock_charizard_moves() -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .returning(move |_| Ok(static_resources::get_pokemon()));
+
+    // Longer names hit harder so the moves have something to sort by
+    mock_client.expect_fetch_move().returning(move |move_name| {
+        let mut move_ = static_resources::get_move();
+        move_.name = move_name.to_owned();
+        move_.power = Some(move_name.len() as i64);
+        Ok(move_)
+    });
+
+    mock_client
+        .expect_fetch_move_learn_method()
+        .returning(move |method_name| {
+            let mut move_learn_method = static_resources::get_move_learn_method();
+            move_learn_method.name = method_name.to_owned();
+            Ok(move_learn_method)
+        });
+
+    mock_client
+}
+
+#[tokio::test]
+async fn moves_unknown_version_group() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClientImplementation::new();
+    let cli = parse_args(vec!["moves", "charizard", "--game", "lkjfsldfjsdkll"]);
+    let expected = matcher::build_unknown_name("version group", "lkjfsldfjsdkll");
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_only_includes_the_version_group() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_charizard_moves();
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--version-group",
+        "red-blue",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    let all_moves = static_resources::get_pokemon().moves;
+    let red_blue_moves = all_moves
+        .iter()
+        .filter(|pokemon_move| {
+            pokemon_move
+                .version_group_details
+                .iter()
+                .any(|details| details.version_group.name == "red-blue")
+        })
+        .count();
+
+    assert!(red_blue_moves < all_moves.len());
+    assert_eq!("red-blue", actual["version_group"]);
+    assert_eq!(
+        Some(red_blue_moves),
+        actual["moves"].as_array().map(Vec::len)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_none_in_version_group() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_charizard_moves();
+
+    let cli = parse_args(vec!["moves", "charizard", "--game", "legends-arceus"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(
+        "Charizard doesn't learn any moves in Legends Arceus",
+        actual
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_grouped_by_learn_method() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_charizard_moves();
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--game",
+        "red-blue",
+        "--group-by",
+        "learn-method",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    let level_up = actual["moves"]["level-up"]
+        .as_array()
+        .ok_or("Missing level-up moves")?;
+    let levels = level_up
+        .iter()
+        .filter_map(|move_| move_["learn_level"].as_i64())
+        .collect::<Vec<_>>();
+
+    assert!(!levels.is_empty());
+    assert!(levels.is_sorted());
+    assert!(
+        level_up
+            .iter()
+            .all(|move_| move_["learn_method"] == "level-up")
+    );
+    assert!(
+        actual["moves"]["machine"]
+            .as_array()
+            .is_some_and(|moves| !moves.is_empty())
+    );
+    assert_eq!(serde_json::json!([]), actual["moves"]["egg"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_grouped_by_learn_method_headers() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_charizard_moves();
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--game",
+        "red-blue",
+        "-g",
+        "learn-method",
+    ]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let level_up = actual.find("Level-up (").ok_or("Missing level-up header")?;
+    let machine = actual
+        .find("TM/Machine (")
+        .ok_or("Missing machine header")?;
+
+    assert!(level_up < machine);
+    assert!(!actual.contains("Egg ("));
+
+    Ok(())
+}
+
+fn red_blue_move_names() -> Vec<String> {
+    static_resources::get_pokemon()
+        .moves
+        .into_iter()
+        .filter(|pokemon_move| {
+            pokemon_move
+                .version_group_details
+                .iter()
+                .any(|details| details.version_group.name == "red-blue")
+        })
+        .map(|pokemon_move| pokemon_move.move_.name)
+        .collect()
+}
+
+async fn sorted_move_names(args: Vec<&str>) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mock_client = mock_charizard_moves();
+
+    let mut full_args = vec![
+        "moves",
+        "charizard",
+        "--game",
+        "red-blue",
+        "--format",
+        "json",
+    ];
+    full_args.extend(args);
+
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, parse_args(full_args)).await.to_string())?;
+
+    let names = actual["moves"]
+        .as_array()
+        .ok_or("Missing moves")?
+        .iter()
+        .filter_map(|move_| move_["name"].as_str().map(ToOwned::to_owned))
+        .collect();
+
+    Ok(names)
+}
+
+#[tokio::test]
+async fn moves_sorted_by_name_with_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let mut expected = red_blue_move_names();
+    expected.sort();
+    expected.truncate(3);
+
+    let actual = sorted_move_names(vec!["--sort", "name", "--limit", "3"]).await?;
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_sorted_by_power_reversed() -> Result<(), Box<dyn std::error::Error>> {
+    let default_order = sorted_move_names(vec![]).await?;
+    let reversed = sorted_move_names(vec!["--sort", "power", "--reverse"]).await?;
+
+    let powers = |names: &[String]| names.iter().map(String::len).collect::<Vec<_>>();
+
+    assert!(powers(&default_order).is_sorted_by(|a, b| a >= b));
+    assert!(powers(&reversed).is_sorted());
+    assert_eq!(red_blue_move_names().len(), reversed.len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_lists_moves_that_failed_to_load() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .returning(move |_| Ok(static_resources::get_pokemon()));
+
+    mock_client.expect_fetch_move().returning(move |move_name| {
+        if move_name == "ember" {
+            return Err(ClientError::NotFound(move_name.to_owned()));
+        }
+
+        let mut move_ = static_resources::get_move();
+        move_.name = move_name.to_owned();
+        Ok(move_)
+    });
+
+    mock_client
+        .expect_fetch_move_learn_method()
+        .returning(move |_| Ok(static_resources::get_move_learn_method()));
+
+    let cli = parse_args(vec!["moves", "charizard", "--game", "red-blue"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert!(actual.contains(&format!("Moves: ({})", red_blue_move_names().len() - 1)));
+    assert!(actual.ends_with(
+        "1 item failed to load\n  Move \"ember\": ember doesn't exist in the local data"
+    ));
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--game",
+        "red-blue",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!("ember", actual["failed"][0]["name"]);
+    assert_eq!("move", actual["failed"][0]["resource"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_prefers_the_level_up_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut charizard = static_resources::get_pokemon();
+    charizard.moves = serde_json::from_value(serde_json::json!([{
+        "move": { "name": "flamethrower", "url": "https://pokeapi.co/api/v2/move/53/" },
+        "version_group_details": [
+            {
+                "level_learned_at": 0,
+                "move_learn_method": { "name": "machine", "url": "https://pokeapi.co/api/v2/move-learn-method/4/" },
+                "version_group": { "name": "red-blue", "url": "https://pokeapi.co/api/v2/version-group/1/" },
+            },
+            {
+                "level_learned_at": 46,
+                "move_learn_method": { "name": "level-up", "url": "https://pokeapi.co/api/v2/move-learn-method/1/" },
+                "version_group": { "name": "red-blue", "url": "https://pokeapi.co/api/v2/version-group/1/" },
+            },
+        ],
+    }]))?;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_| Ok(charizard.clone()));
+
+    mock_client
+        .expect_fetch_move()
+        .with(mockall::predicate::eq("flamethrower"))
+        .once()
+        .returning(move |_| {
+            let mut move_ = static_resources::get_move();
+            move_.name = String::from("flamethrower");
+            Ok(move_)
+        });
+
+    mock_client
+        .expect_fetch_move_learn_method()
+        .with(mockall::predicate::eq("level-up"))
+        .once()
+        .returning(move |_| {
+            let mut move_learn_method = static_resources::get_move_learn_method();
+            move_learn_method.name = String::from("level-up");
+            Ok(move_learn_method)
+        });
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--game",
+        "red-blue",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!("flamethrower", actual["moves"][0]["name"]);
+    assert_eq!("level-up", actual["moves"][0]["learn_method"]);
+    assert_eq!(46, actual["moves"][0]["learn_level"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_sorted_by_accuracy_puts_moves_that_cant_miss_first()
+-> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut charizard = static_resources::get_pokemon();
+    charizard.moves = serde_json::from_value(serde_json::json!([
+        {
+            "move": { "name": "slash", "url": "https://pokeapi.co/api/v2/move/163/" },
+            "version_group_details": [],
+        },
+        {
+            "move": { "name": "swift", "url": "https://pokeapi.co/api/v2/move/129/" },
+            "version_group_details": [],
+        },
+    ]))?;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_| Ok(charizard.clone()));
+
+    mock_client
+        .expect_fetch_move()
+        .times(2)
+        .returning(move |move_name| {
+            let mut move_ = static_resources::get_move();
+            move_.name = move_name.to_owned();
+            move_.accuracy = (move_name == "slash").then_some(100);
+            Ok(move_)
+        });
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--sort",
+        "accuracy",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!("swift", actual["moves"][0]["name"]);
+    assert_eq!("slash", actual["moves"][1]["name"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_grouped_limit_applies_to_each_group() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_charizard_moves();
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--game",
+        "red-blue",
+        "--group-by",
+        "learn-method",
+        "--limit",
+        "2",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        Some(2),
+        actual["moves"]["level-up"].as_array().map(Vec::len)
+    );
+    assert_eq!(Some(2), actual["moves"]["machine"].as_array().map(Vec::len));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_grouped_level_up_follows_the_sort() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_charizard_moves();
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--game",
+        "red-blue",
+        "--group-by",
+        "learn-method",
+        "--sort",
+        "name",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    let names = actual["moves"]["level-up"]
+        .as_array()
+        .ok_or("Missing level-up moves")?
+        .iter()
+        .filter_map(|move_| move_["name"].as_str())
+        .collect::<Vec<_>>();
+
+    assert!(names.len() > 1);
+    assert!(names.is_sorted());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_lists_learn_methods_that_failed_to_load() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut charizard = static_resources::get_pokemon();
+    charizard.moves = serde_json::from_value(serde_json::json!([
+        {
+            "move": { "name": "ember", "url": "https://pokeapi.co/api/v2/move/52/" },
+            "version_group_details": [{
+                "level_learned_at": 9,
+                "move_learn_method": { "name": "level-up", "url": "https://pokeapi.co/api/v2/move-learn-method/1/" },
+                "version_group": { "name": "red-blue", "url": "https://pokeapi.co/api/v2/version-group/1/" },
+            }],
+        },
+        {
+            "move": { "name": "slash", "url": "https://pokeapi.co/api/v2/move/163/" },
+            "version_group_details": [{
+                "level_learned_at": 36,
+                "move_learn_method": { "name": "level-up", "url": "https://pokeapi.co/api/v2/move-learn-method/1/" },
+                "version_group": { "name": "red-blue", "url": "https://pokeapi.co/api/v2/version-group/1/" },
+            }],
+        },
+    ]))?;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_| Ok(charizard.clone()));
+
+    mock_client
+        .expect_fetch_move()
+        .times(2)
+        .returning(move |move_name| {
+            let mut move_ = static_resources::get_move();
+            move_.name = move_name.to_owned();
+            Ok(move_)
+        });
+
+    mock_client
+        .expect_fetch_move_learn_method()
+        .with(mockall::predicate::eq("level-up"))
+        .times(2)
+        .returning(move |method_name| Err(ClientError::NotFound(method_name.to_owned())));
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--game",
+        "red-blue",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(Some(2), actual["moves"].as_array().map(Vec::len));
+    assert!(
+        actual["moves"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .all(|move_| move_["learn_method"] == "level-up" && move_["learn_level"].is_i64())
+    );
+    assert_eq!(
+        serde_json::json!([{
+            "resource": "move-learn-method",
+            "name": "level-up",
+            "error": "level-up doesn't exist in the local data",
+        }]),
+        actual["failed"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn moves_sorted_by_level_lists_level_up_moves_first() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mock_client = mock_charizard_moves();
+
+    let cli = parse_args(vec![
+        "moves",
+        "charizard",
+        "--game",
+        "red-blue",
+        "--sort",
+        "level",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    let moves = actual["moves"].as_array().ok_or("Missing moves")?;
+    let level_up_count = moves
+        .iter()
+        .take_while(|move_| move_["learn_method"] == "level-up")
+        .count();
+    let levels = moves[..level_up_count]
+        .iter()
+        .filter_map(|move_| move_["learn_level"].as_i64())
+        .collect::<Vec<_>>();
+
+    assert!(level_up_count > 0);
+    assert!(level_up_count < moves.len());
+    assert!(levels.is_sorted());
+    assert!(
+        moves[level_up_count..]
+            .iter()
+            .all(|move_| move_["learn_method"] != "level-up")
+    );
+
+    Ok(())
+}
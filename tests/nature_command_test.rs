@@ -0,0 +1,84 @@
+mod utils;
+
+use poke_search::{
+    client::MockClientImplementation, formatter::utils as fmt, name_matcher::matcher, run,
+};
+use rustemon::static_resources;
+use utils::parse_args;
+
+fn mock_bold() -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+    let mock_nature = static_resources::get_nature();
+
+    mock_client
+        .expect_fetch_nature()
+        .with(mockall::predicate::eq("bold"))
+        .once()
+        .returning(move |_args| Ok(mock_nature.clone()));
+
+    mock_client
+}
+
+#[tokio::test]
+async fn nature_cant_be_found() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkjfsldfjsdkll";
+    let mock_client = MockClientImplementation::new();
+
+    let cli = parse_args(vec!["nature", incorrect_name]);
+    let expected = matcher::build_unknown_name("nature", incorrect_name);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn nature_output() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_bold();
+
+    let cli = parse_args(vec!["nature", "bold"]);
+    let expected = format!(
+        "{}
+  {}: Bold
+  {}: Defense (+10%)
+  {}: Attack (-10%)
+  {}: Sour
+  {}: Spicy",
+        fmt::white("Nature"),
+        fmt::white("Name"),
+        fmt::white("Increased"),
+        fmt::white("Decreased"),
+        fmt::white("Likes"),
+        fmt::white("Dislikes"),
+    );
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn nature_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_bold();
+
+    let cli = parse_args(vec!["nature", "bold", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        serde_json::json!({
+            "nature": {
+                "name": "bold",
+                "increased_stat": "defense",
+                "decreased_stat": "attack",
+                "likes_flavor": "sour",
+                "hates_flavor": "spicy",
+            }
+        }),
+        actual
+    );
+
+    Ok(())
+}
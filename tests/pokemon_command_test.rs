@@ -1,7 +1,10 @@
 mod utils;
 
 use poke_search::{
-    client::MockClientImplementation, formatter::utils as fmt, name_matcher::matcher, run,
+    client::{ClientError, MockClientImplementation},
+    formatter::utils as fmt,
+    name_matcher::matcher,
+    run,
 };
 use rustemon::static_resources;
 use utils::parse_args;
@@ -59,10 +62,20 @@ async fn pokemon_autocorrect_if_similar_enough() -> Result<(), Box<dyn std::erro
     let expected = format!(
         "{}
   {}: Charizard
+  {}: Flame Pokémon
   {}: {fire} | {flying}
   {}: Blaze | Solar Power
   {}: I
   {}: Special Attack +3
+  {}: Charizard Mega X | Charizard Mega Y | Charizard Gmax
+  {}: Monster | Dragon
+  {}: 87.5% Male | 12.5% Female
+  {}: 45
+  {}: 70
+  {}: Medium Slow
+  {}: 20 egg cycles
+  {}: Its wings can carry this Pokémon close to an altitude of 4,600 feet. It blows
+    out fire at very high temperatures.
 
 {}
   {}: 78
@@ -81,10 +94,19 @@ async fn pokemon_autocorrect_if_similar_enough() -> Result<(), Box<dyn std::erro
   {}: Has a 30% chance of paralyzing attacking Pokémon on contact.",
         fmt::white("Summary"),
         fmt::white("Name"),
+        fmt::white("Genus"),
         fmt::white("Type"),
         fmt::white("Abilities"),
         fmt::white("Generation"),
         fmt::white("Effort Values"),
+        fmt::white("Other Forms"),
+        fmt::white("Egg Groups"),
+        fmt::white("Gender Ratio"),
+        fmt::white("Capture Rate"),
+        fmt::white("Base Happiness"),
+        fmt::white("Growth Rate"),
+        fmt::white("Hatch Counter"),
+        fmt::white("Description"),
         fmt::white("Stats"),
         fmt::white("HP"),
         fmt::white("Attack"),
@@ -232,3 +254,506 @@ async fn pokemon_highlights_matched_name_in_evolution_chain()
 
     Ok(())
 }
+
+#[tokio::test]
+async fn pokemon_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let pokemon_name = "charizard";
+
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq(pokemon_name))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq(pokemon_name))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_ability()
+        .times(2)
+        .returning(move |_args| Ok(static_resources::get_ability()));
+
+    let cli = parse_args(vec!["pokemon", pokemon_name, "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    let expected_summary = serde_json::json!({
+        "name": "charizard",
+        "types": ["fire", "flying"],
+        "abilities": ["blaze", "solar-power"],
+        "generation": "generation-i",
+        "effort_values": { "special-attack": 3 },
+        "genus": "Flame Pokémon",
+        "other_forms": ["charizard-mega-x", "charizard-mega-y", "charizard-gmax"],
+        "description": "Its wings can carry this Pokémon close to an altitude of 4,600 feet. It blows out fire at very high temperatures.",
+        "egg_groups": ["monster", "dragon"],
+        "gender_ratio": { "male": 87.5, "female": 12.5 },
+        "capture_rate": 45,
+        "base_happiness": 70,
+        "growth_rate": "medium-slow",
+        "hatch_counter": 20,
+        "is_legendary": false,
+        "is_mythical": false,
+        "is_baby": false,
+    });
+
+    assert_eq!(expected_summary, actual["summary"]);
+    assert_eq!(serde_json::json!(78), actual["stats"]["hp"]);
+    assert_eq!(serde_json::json!(534), actual["stats"]["total"]);
+    assert_eq!(2, actual["abilities"].as_array().unwrap().len());
+    assert!(actual.get("evolution").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pokemon_lists_abilities_that_failed_to_load() -> Result<(), Box<dyn std::error::Error>> {
+    let pokemon_name = "charizard";
+
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq(pokemon_name))
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq(pokemon_name))
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_ability()
+        .with(mockall::predicate::eq("blaze"))
+        .returning(move |_args| Ok(static_resources::get_ability()));
+
+    mock_client
+        .expect_fetch_ability()
+        .with(mockall::predicate::eq("solar-power"))
+        .returning(move |_args| Err(ClientError::NotFound(String::from("solar-power"))));
+
+    let cli = parse_args(vec!["pokemon", pokemon_name]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert!(actual.contains("Blaze"), "{actual}");
+    assert!(actual.contains(
+        "1 item failed to load\n  Ability \"solar-power\": solar-power doesn't exist in the local data"
+    ));
+
+    let cli = parse_args(vec!["pokemon", pokemon_name, "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(1, actual["abilities"].as_array().map_or(0, Vec::len));
+    assert_eq!(
+        serde_json::json!([{
+            "resource": "ability",
+            "name": "solar-power",
+            "error": "solar-power doesn't exist in the local data",
+        }]),
+        actual["failed"]
+    );
+
+    Ok(())
+}
+
+// Every variety of the static Charizard species, with Mega Charizard X hitting harder
+fn mock_charizard_forms() -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("charizard"))
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_ability()
+        .returning(move |_args| Ok(static_resources::get_ability()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .returning(move |pokemon_name| {
+            let mut pokemon = static_resources::get_pokemon();
+            pokemon.name = pokemon_name.to_owned();
+
+            if pokemon_name == "charizard-mega-x" {
+                pokemon.types[1].type_.name = String::from("dragon");
+                pokemon.stats[1].base_stat += 46;
+                pokemon.stats[2].base_stat += 33;
+            }
+
+            Ok(pokemon)
+        });
+
+    mock_client
+}
+
+#[tokio::test]
+async fn pokemon_shows_forms() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_charizard_forms();
+
+    let cli = parse_args(vec!["pokemon", "Mega Charizard X", "--forms"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let fire = poke_search::type_badge::fetch("fire");
+    let flying = poke_search::type_badge::fetch("flying");
+
+    let expected = format!(
+        "{}
+  {}: Charizard
+  {}: {fire} | {flying}
+  {}: Attack -46 | Defense -33 (Total -79)
+
+  {}: Charizard Mega Y
+  {}: {fire} | {flying}
+  {}: Attack -46 | Defense -33 (Total -79)
+
+  {}: Charizard Gmax
+  {}: {fire} | {flying}
+  {}: Attack -46 | Defense -33 (Total -79)",
+        fmt::white("Forms (3)"),
+        fmt::white("Name"),
+        fmt::white("Type"),
+        fmt::white("Stat Changes"),
+        fmt::white("Name"),
+        fmt::white("Type"),
+        fmt::white("Stat Changes"),
+        fmt::white("Name"),
+        fmt::white("Type"),
+        fmt::white("Stat Changes"),
+    );
+
+    assert!(actual.starts_with("Summary\n  Name: Charizard Mega X"));
+    assert!(actual.ends_with(&expected), "{actual}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pokemon_lists_every_failure_after_the_last_section()
+-> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("charizard"))
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_ability()
+        .with(mockall::predicate::eq("blaze"))
+        .returning(move |_args| Ok(static_resources::get_ability()));
+
+    mock_client
+        .expect_fetch_ability()
+        .with(mockall::predicate::eq("solar-power"))
+        .returning(move |_args| Err(ClientError::NotFound(String::from("solar-power"))));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .returning(move |pokemon_name| {
+            if pokemon_name == "charizard-gmax" {
+                return Err(ClientError::NotFound(pokemon_name.to_owned()));
+            }
+
+            let mut pokemon = static_resources::get_pokemon();
+            pokemon.name = pokemon_name.to_owned();
+            Ok(pokemon)
+        });
+
+    let cli = parse_args(vec!["pokemon", "charizard", "--forms"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let fire = poke_search::type_badge::fetch("fire");
+    let flying = poke_search::type_badge::fetch("flying");
+
+    let expected = format!(
+        "{}
+  {}: Charizard Mega X
+  {}: {fire} | {flying}
+  {}: None
+
+  {}: Charizard Mega Y
+  {}: {fire} | {flying}
+  {}: None
+
+{}
+  Ability \"solar-power\": solar-power doesn't exist in the local data
+  Pokemon \"charizard-gmax\": charizard-gmax doesn't exist in the local data",
+        fmt::white("Forms (2)"),
+        fmt::white("Name"),
+        fmt::white("Type"),
+        fmt::white("Stat Changes"),
+        fmt::white("Name"),
+        fmt::white("Type"),
+        fmt::white("Stat Changes"),
+        fmt::white("2 items failed to load"),
+    );
+
+    assert_eq!(1, actual.matches("failed to load").count());
+    assert!(actual.ends_with(&expected), "{actual}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pokemon_forms_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_charizard_forms();
+
+    let cli = parse_args(vec!["pokemon", "charizard", "--forms", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    let mega_x = &actual["forms"][0];
+    assert_eq!(3, actual["forms"].as_array().map_or(0, Vec::len));
+    assert_eq!("charizard-mega-x", mega_x["name"]);
+    assert_eq!(serde_json::json!(["fire", "dragon"]), mega_x["types"]);
+    assert_eq!(46, mega_x["stat_changes"]["attack"]);
+    assert_eq!(79, mega_x["stat_changes"]["total"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pokemon_actual_stats_json() -> Result<(), Box<dyn std::error::Error>> {
+    let pokemon_name = "charizard";
+
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq(pokemon_name))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq(pokemon_name))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_ability()
+        .times(2)
+        .returning(move |_args| Ok(static_resources::get_ability()));
+
+    let mut timid = static_resources::get_nature();
+    timid.name = String::from("timid");
+    timid.increased_stat = serde_json::from_value(serde_json::json!({
+        "name": "speed",
+        "url": "https://pokeapi.co/api/v2/stat/6/",
+    }))?;
+
+    mock_client
+        .expect_fetch_nature()
+        .with(mockall::predicate::eq("timid"))
+        .once()
+        .returning(move |_args| Ok(timid.clone()));
+
+    let cli = parse_args(vec![
+        "pokemon",
+        pokemon_name,
+        "--level",
+        "100",
+        "--ivs",
+        "31/31/31/31/31/31",
+        "--nature",
+        "timid",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    let actual_stats = &actual["actual_stats"];
+    assert_eq!(serde_json::json!(100), actual_stats["level"]);
+    assert_eq!(serde_json::json!("timid"), actual_stats["nature"]);
+    assert_eq!(
+        serde_json::json!({ "min": 297, "max": 360 }),
+        actual_stats["hp"]
+    );
+    assert_eq!(
+        serde_json::json!({ "min": 259, "max": 328 }),
+        actual_stats["speed"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pokemon_nature_cant_be_found() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkfdjslsdkjfkls";
+
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    let cli = parse_args(vec![
+        "pokemon",
+        "charizard",
+        "--level",
+        "50",
+        "--nature",
+        incorrect_name,
+    ]);
+    let expected = matcher::build_unknown_name("nature", incorrect_name);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pokemon_actual_stats() -> Result<(), Box<dyn std::error::Error>> {
+    let pokemon_name = "charizard";
+
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq(pokemon_name))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq(pokemon_name))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_ability()
+        .times(2)
+        .returning(move |_args| Ok(static_resources::get_ability()));
+
+    let mut timid = static_resources::get_nature();
+    timid.name = String::from("timid");
+    timid.increased_stat = serde_json::from_value(serde_json::json!({
+        "name": "speed",
+        "url": "https://pokeapi.co/api/v2/stat/6/",
+    }))?;
+
+    mock_client
+        .expect_fetch_nature()
+        .with(mockall::predicate::eq("timid"))
+        .once()
+        .returning(move |_args| Ok(timid.clone()));
+
+    let cli = parse_args(vec![
+        "pokemon",
+        pokemon_name,
+        "--level",
+        "100",
+        "--ivs",
+        "31/31/31/31/31/31",
+        "--nature",
+        "timid",
+    ]);
+
+    let expected = format!(
+        "{}
+  {}: 78 → 297 - 360
+  {}: 84 → 183 - 240
+  {}: 78 → 192 - 255
+  {}: 109 → 254 - 317
+  {}: 85 → 206 - 269
+  {}: 100 → 259 - 328
+  {}: 534",
+        fmt::white("Stats (Lv. 100, Timid)"),
+        fmt::white("HP"),
+        fmt::white("Attack"),
+        fmt::white("Defense"),
+        fmt::white("Special Attack"),
+        fmt::white("Special Defense"),
+        fmt::white("Speed"),
+        fmt::white("Total"),
+    );
+
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_contains!(actual, &expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pokemon_types_generation_uses_past_types() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    // Charizard as if it had been pure Normal type up to Generation V, like Clefairy
+    let mut charizard = static_resources::get_pokemon();
+    charizard.past_types = serde_json::from_value(serde_json::json!([{
+        "generation": { "name": "generation-v", "url": "https://pokeapi.co/api/v2/generation/5/" },
+        "types": [{ "slot": 1, "type": { "name": "normal", "url": "https://pokeapi.co/api/v2/type/1/" } }],
+    }]))?;
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(charizard.clone()));
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon_species()));
+
+    mock_client
+        .expect_fetch_ability()
+        .times(2)
+        .returning(move |_args| Ok(static_resources::get_ability()));
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("normal"))
+        .once()
+        .returning(move |_args| {
+            let mut normal = static_resources::get_type();
+            normal.name = String::from("normal");
+            Ok(normal)
+        });
+
+    let cli = parse_args(vec![
+        "pokemon",
+        "charizard",
+        "--types",
+        "--generation",
+        "3",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(serde_json::json!(["normal"]), actual["types"]["types"]);
+    assert_eq!(serde_json::json!(3), actual["types"]["generation"]);
+
+    Ok(())
+}
+
+#[test]
+fn pokemon_generation_requires_types() {
+    let args = ["poke_search", "pokemon", "charizard", "--generation", "1"];
+
+    assert!(<poke_search::Cli as clap::Parser>::try_parse_from(args).is_err());
+}
+
+#[test]
+fn pokemon_stat_options_require_level() {
+    let args = ["poke_search", "pokemon", "charizard", "--nature", "timid"];
+
+    assert!(<poke_search::Cli as clap::Parser>::try_parse_from(args).is_err());
+}
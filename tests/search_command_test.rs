@@ -0,0 +1,336 @@
+mod utils;
+
+use poke_search::{
+    client::{ClientError, MockClientImplementation},
+    formatter::utils as fmt,
+    name_matcher::pokemon_names::POKEMON_NAMES,
+    run, type_badge,
+};
+use rustemon::static_resources;
+use utils::parse_args;
+
+#[tokio::test]
+async fn search_invalid_filter() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClientImplementation::new();
+    let cli = parse_args(vec!["search", "luck>=100"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert!(actual.starts_with("'luck>=100' isn't a valid filter"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_filters_by_stat_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut mock_type = static_resources::get_type();
+    mock_type.pokemon.retain(|type_pokemon| {
+        ["charizard", "charmander"].contains(&type_pokemon.pokemon.name.as_str())
+    });
+
+    let mut charmander = static_resources::get_pokemon();
+    charmander.name = String::from("charmander");
+    for pokemon_stat in &mut charmander.stats {
+        pokemon_stat.base_stat = 50;
+    }
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("fire"))
+        .once()
+        .returning(move |_args| Ok(mock_type.clone()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charmander"))
+        .once()
+        .returning(move |_args| Ok(charmander.clone()));
+
+    let cli = parse_args(vec![
+        "search",
+        "type:fire",
+        "speed>=100",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        serde_json::json!({
+            "query": ["type:fire", "speed>=100"],
+            "sort": "total",
+            "pokemon": [{
+                "name": "charizard",
+                "types": ["fire", "flying"],
+                "stats": {
+                    "hp": 78,
+                    "attack": 84,
+                    "defense": 78,
+                    "special-attack": 109,
+                    "special-defense": 85,
+                    "speed": 100,
+                    "total": 534,
+                },
+            }],
+        }),
+        actual
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_sorts_by_chosen_stat() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut mock_type = static_resources::get_type();
+    mock_type.pokemon.retain(|type_pokemon| {
+        ["charizard", "charmander"].contains(&type_pokemon.pokemon.name.as_str())
+    });
+
+    let mut charmander = static_resources::get_pokemon();
+    charmander.name = String::from("charmander");
+    for pokemon_stat in &mut charmander.stats {
+        pokemon_stat.base_stat = 50;
+    }
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("fire"))
+        .once()
+        .returning(move |_args| Ok(mock_type.clone()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charmander"))
+        .once()
+        .returning(move |_args| Ok(charmander.clone()));
+
+    let cli = parse_args(vec!["search", "type:fire", "--sort", "spe"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let types = format!(
+        "{} {}",
+        type_badge::fetch("fire"),
+        type_badge::fetch("flying")
+    );
+
+    let expected = format!(
+        "{}
+{}
+  Charizard  | {types} |    78    84    78   109    85 {}   534
+  Charmander | {types} |    50    50    50    50    50 {}   300",
+        fmt::white("Results (2)"),
+        fmt::white("  Name       | Type          |    HP   Atk   Def   SpA   SpD   Spe   BST"),
+        fmt::highlight("  100"),
+        fmt::highlight("   50"),
+    );
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_with_no_results() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut mock_type = static_resources::get_type();
+    mock_type.pokemon.retain(|type_pokemon| {
+        ["charizard", "charmander"].contains(&type_pokemon.pokemon.name.as_str())
+    });
+
+    let mut charmander = static_resources::get_pokemon();
+    charmander.name = String::from("charmander");
+    for pokemon_stat in &mut charmander.stats {
+        pokemon_stat.base_stat = 50;
+    }
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("fire"))
+        .once()
+        .returning(move |_args| Ok(mock_type.clone()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charmander"))
+        .once()
+        .returning(move |_args| Ok(charmander.clone()));
+
+    let cli = parse_args(vec!["search", "type:fire", "bst>600"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!("Results (0)\n  No pokemon match the search.", actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_by_stats_alone_checks_every_pokemon() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .times(POKEMON_NAMES.len())
+        .returning(move |pokemon_name| {
+            let mut pokemon = static_resources::get_pokemon();
+            pokemon.name = pokemon_name.to_owned();
+            if pokemon_name == "electrode" {
+                pokemon.stats[5].base_stat = 150;
+            }
+            Ok(pokemon)
+        });
+
+    let cli = parse_args(vec!["search", "speed>=130", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(Some(1), actual["pokemon"].as_array().map(Vec::len));
+    assert_eq!("electrode", actual["pokemon"][0]["name"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_by_generation_only_fetches_its_species() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut generation = static_resources::get_generation();
+    generation.pokemon_species = serde_json::from_value(serde_json::json!([
+        { "name": "deoxys", "url": "https://pokeapi.co/api/v2/pokemon-species/386/" },
+    ]))?;
+
+    mock_client
+        .expect_fetch_generation()
+        .with(mockall::predicate::eq("generation-iii"))
+        .once()
+        .returning(move |_args| Ok(generation.clone()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::function(|name: &str| {
+            name.starts_with("deoxys-")
+        }))
+        .times(4)
+        .returning(move |name| {
+            let mut pokemon = static_resources::get_pokemon();
+            pokemon.name = name.to_owned();
+            Ok(pokemon)
+        });
+
+    mock_client
+        .expect_fetch_pokemon_species()
+        .returning(move |_args| {
+            let mut species = static_resources::get_pokemon_species();
+            species.generation.name = String::from("generation-iii");
+            Ok(species)
+        });
+
+    let cli = parse_args(vec!["search", "gen=3", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    let names = actual["pokemon"]
+        .as_array()
+        .ok_or("pokemon is missing")?
+        .iter()
+        .map(|pokemon| pokemon["name"].as_str().unwrap_or_default())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            "deoxys-attack",
+            "deoxys-defense",
+            "deoxys-normal",
+            "deoxys-speed"
+        ],
+        names
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_lists_pokemon_which_failed_to_load() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut mock_type = static_resources::get_type();
+    mock_type.pokemon.retain(|type_pokemon| {
+        ["charizard", "charmander"].contains(&type_pokemon.pokemon.name.as_str())
+    });
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("fire"))
+        .once()
+        .returning(move |_args| Ok(mock_type.clone()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charizard"))
+        .once()
+        .returning(move |_args| Ok(static_resources::get_pokemon()));
+
+    mock_client
+        .expect_fetch_pokemon()
+        .with(mockall::predicate::eq("charmander"))
+        .once()
+        .returning(move |_args| {
+            Err(ClientError::NotCached(String::from(
+                "Pokemon \"charmander\"",
+            )))
+        });
+
+    let cli = parse_args(vec!["search", "type:fire", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        serde_json::json!({
+            "query": ["type:fire"],
+            "sort": "total",
+            "pokemon": [{
+                "name": "charizard",
+                "types": ["fire", "flying"],
+                "stats": {
+                    "hp": 78,
+                    "attack": 84,
+                    "defense": 78,
+                    "special-attack": 109,
+                    "special-defense": 85,
+                    "speed": 100,
+                    "total": 534,
+                },
+            }],
+            "failed": [{
+                "resource": "pokemon",
+                "name": "charmander",
+                "error": "Pokemon \"charmander\" is not cached. Run again without --offline to fetch it",
+            }],
+        }),
+        actual
+    );
+
+    Ok(())
+}
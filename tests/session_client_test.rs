@@ -0,0 +1,25 @@
+use poke_search::{
+    client::{ClientImplementation, MockClientImplementation},
+    session_client::SessionClient,
+};
+use rustemon::static_resources;
+
+#[tokio::test]
+async fn session_client_reuses_fetched_models() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_item()
+        .with(mockall::predicate::eq("master-ball"))
+        .once()
+        .returning(|_args| Ok(static_resources::get_item()));
+
+    let session_client = SessionClient::new(&mock_client);
+
+    let first = session_client.fetch_item("master-ball").await?;
+    let second = session_client.fetch_item("master-ball").await?;
+
+    assert_eq!(first, second);
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+mod utils;
+
+use poke_search::{client::MockClientImplementation, name_matcher::matcher, run};
+use rustemon::static_resources;
+use utils::parse_args;
+
+fn mock_fire_only_team() -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut mock_pokemon = static_resources::get_pokemon();
+    mock_pokemon
+        .types
+        .retain(|type_| type_.type_.name == "fire");
+    let mock_type = static_resources::get_type();
+
+    mock_client
+        .expect_fetch_pokemon()
+        .returning(move |_args| Ok(mock_pokemon.clone()));
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("fire"))
+        .once()
+        .returning(move |_args| Ok(mock_type.clone()));
+
+    mock_client
+}
+
+#[tokio::test]
+async fn team_member_cant_be_found() -> Result<(), Box<dyn std::error::Error>> {
+    let incorrect_name = "lkjfsldfjsdkll";
+
+    let mock_client = MockClientImplementation::new();
+    let cli = parse_args(vec!["team", incorrect_name]);
+    let expected = matcher::build_unknown_name("pokemon", incorrect_name);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn team_shared_weaknesses_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_fire_only_team();
+
+    let cli = parse_args(vec!["team", "charizard", "charizard", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        serde_json::json!([
+            { "name": "charizard", "types": ["fire"] },
+            { "name": "charizard", "types": ["fire"] },
+        ]),
+        actual["team"]
+    );
+    assert_eq!(
+        serde_json::json!({ "multipliers": ["2x", "2x"], "weaknesses": 2, "resistances": 0 }),
+        actual["defence"]["water"]
+    );
+    assert_eq!(
+        serde_json::json!({ "multipliers": ["0.5x", "0.5x"], "weaknesses": 0, "resistances": 2 }),
+        actual["defence"]["grass"]
+    );
+    assert_eq!(
+        serde_json::json!(["ground", "rock", "water"]),
+        actual["shared_weaknesses"]
+    );
+    assert!(actual["defence"].get("stellar").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn team_lists_shared_weaknesses() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_fire_only_team();
+
+    let cli = parse_args(vec!["team", "charizard", "charizard"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert!(actual.contains("Shared Weaknesses"));
+    assert!(actual.contains("Charizard"));
+
+    Ok(())
+}
+
+#[test]
+fn team_rejects_more_than_six_pokemon() {
+    let args = ["poke_search", "team", "a", "b", "c", "d", "e", "f", "g"];
+
+    assert!(<poke_search::Cli as clap::Parser>::try_parse_from(args).is_err());
+}
@@ -396,3 +396,183 @@ async fn list_pokemon_flag() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn single_type_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let mut mock_client = MockClientImplementation::new();
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("fire"))
+        .once()
+        .returning(|_args| Ok(static_resources::get_type()));
+
+    let cli = parse_args(vec!["type", "fire", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(actual["types"], serde_json::json!(["fire"]));
+    assert_eq!(
+        actual["offence"]["fire"]["2x"],
+        serde_json::json!(["bug", "grass", "ice", "steel"])
+    );
+    assert_eq!(
+        actual["defence"]["2x"],
+        serde_json::json!(["ground", "rock", "water"])
+    );
+    assert_eq!(actual["defence"]["0x"], serde_json::json!([]));
+    assert!(actual.get("pokemon").is_none());
+
+    Ok(())
+}
+
+fn type_resources(type_names: &[&str]) -> serde_json::Value {
+    serde_json::json!(
+        type_names
+            .iter()
+            .map(|type_name| serde_json::json!({
+                "name": type_name,
+                "url": "https://pokeapi.co/api/v2/type/1/",
+            }))
+            .collect::<Vec<_>>()
+    )
+}
+
+fn type_relations(
+    no_damage_to: &[&str],
+    half_damage_to: &[&str],
+    double_damage_to: &[&str],
+) -> serde_json::Value {
+    serde_json::json!({
+        "no_damage_to": type_resources(no_damage_to),
+        "half_damage_to": type_resources(half_damage_to),
+        "double_damage_to": type_resources(double_damage_to),
+        "no_damage_from": type_resources(&["normal", "fighting"]),
+        "half_damage_from": type_resources(&["poison", "bug"]),
+        "double_damage_from": type_resources(&["ghost", "dark"]),
+    })
+}
+
+// Ghost's offensive matchups now, up to Generation V and in Generation I
+fn mock_ghost() -> MockClientImplementation {
+    let mut mock_client = MockClientImplementation::new();
+
+    let mut ghost = static_resources::get_type();
+    ghost.name = String::from("ghost");
+    ghost.damage_relations = serde_json::from_value(type_relations(
+        &["normal"],
+        &["dark"],
+        &["ghost", "psychic"],
+    ))
+    .unwrap();
+    ghost.past_damage_relations = serde_json::from_value(serde_json::json!([
+        {
+            "generation": { "name": "generation-i", "url": "https://pokeapi.co/api/v2/generation/1/" },
+            "damage_relations": type_relations(&["normal", "psychic"], &[], &["ghost"]),
+        },
+        {
+            "generation": { "name": "generation-v", "url": "https://pokeapi.co/api/v2/generation/5/" },
+            "damage_relations": type_relations(&["normal"], &["dark", "steel"], &["ghost", "psychic"]),
+        },
+    ]))
+    .unwrap();
+
+    mock_client
+        .expect_fetch_type()
+        .with(mockall::predicate::eq("ghost"))
+        .returning(move |_args| Ok(ghost.clone()));
+
+    mock_client
+}
+
+#[tokio::test]
+async fn type_generation_uses_past_matchups() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_ghost();
+
+    let cli = parse_args(vec![
+        "type",
+        "ghost",
+        "--generation",
+        "1",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(actual["generation"], 1);
+    assert_eq!(
+        actual["offence"]["ghost"]["0x"],
+        serde_json::json!(["normal", "psychic"])
+    );
+    assert_eq!(
+        actual["offence"]["ghost"]["2x"],
+        serde_json::json!(["ghost"])
+    );
+    // Dark, Steel and Fairy didn't exist yet
+    assert_eq!(actual["defence"]["2x"], serde_json::json!(["ghost"]));
+    assert!(
+        !actual["offence"]["ghost"]["1x"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|type_name| ["dark", "steel", "fairy"].contains(&type_name.as_str().unwrap()))
+    );
+
+    let cli = parse_args(vec![
+        "type",
+        "ghost",
+        "--generation",
+        "gen-iv",
+        "--format",
+        "json",
+    ]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        actual["offence"]["ghost"]["0.5x"],
+        serde_json::json!(["dark", "steel"])
+    );
+
+    let cli = parse_args(vec!["type", "ghost", "--format", "json"]);
+    let actual: serde_json::Value =
+        serde_json::from_str(&run(&mock_client, cli).await.to_string())?;
+
+    assert_eq!(
+        actual["offence"]["ghost"]["0.5x"],
+        serde_json::json!(["dark"])
+    );
+    assert!(actual.get("generation").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn type_generation_header() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = mock_ghost();
+
+    let cli = parse_args(vec!["type", "ghost", "--generation", "1"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    let ghost = type_badge::fetch("ghost");
+    assert!(actual.starts_with(&format!(
+        "{}\n\n{ghost}\n\n{}",
+        fmt::white("Generation I"),
+        fmt::white("Offence")
+    )));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn type_generation_before_type_existed() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClientImplementation::new();
+
+    let cli = parse_args(vec!["type", "fairy", "--generation", "5"]);
+    let actual = run(&mock_client, cli).await.to_string();
+
+    assert_eq!("Fairy type doesn't exist in Generation V", actual);
+
+    Ok(())
+}